@@ -12,7 +12,7 @@ cfg_if::cfg_if! {
         };
 
         criterion_main!(benches);
-        criterion_group!(benches, default, subsplits_layout);
+        criterion_group!(benches, default, running_timer, subsplits_layout);
 
         fn default(c: &mut Criterion) {
             let mut run = create_run(&["A", "B", "C", "D"]);
@@ -39,6 +39,37 @@ cfg_if::cfg_if! {
             });
         }
 
+        fn running_timer(c: &mut Criterion) {
+            let mut run = create_run(&["A", "B", "C", "D"]);
+            run.set_game_name("Some Game Name");
+            run.set_category_name("Some Category Name");
+            run.set_attempt_count(1337);
+            let mut timer = Timer::new(run).unwrap();
+            let mut layout = Layout::default_layout();
+
+            start_run(&mut timer);
+            make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+            let mut renderer = Renderer::new();
+
+            // The timer changes in between the two states, so only the parts
+            // of the image showing the current time need to be redrawn.
+            timer.set_game_time(TimeSpan::from_seconds(12.0));
+            let mut first = layout.state(&timer.snapshot());
+            renderer.render(&first, [1200, 2000]);
+            layout.update_state(&mut first, &timer.snapshot());
+
+            timer.set_game_time(TimeSpan::from_seconds(13.0));
+            let second = layout.state(&timer.snapshot());
+
+            c.bench_function("Software Rendering (Running Timer)", move |b| {
+                b.iter(|| {
+                    renderer.render(&first, [1200, 2000]);
+                    renderer.render(&second, [1200, 2000])
+                })
+            });
+        }
+
         fn subsplits_layout(c: &mut Criterion) {
             let run = lss("tests/run_files/Celeste - Any% (1.2.1.5).lss");
             let mut timer = Timer::new(run).unwrap();
@@ -1,12 +1,19 @@
 /** The state object for one of the components available. */
 export type ComponentStateJson =
     { BlankSpace: BlankSpaceComponentStateJson } |
+    { Clock: ClockComponentStateJson } |
+    { Counters: CountersComponentStateJson } |
+    { CustomImage: CustomImageComponentStateJson } |
     { DetailedTimer: DetailedTimerComponentStateJson } |
     { Graph: GraphComponentStateJson } |
     { KeyValue: KeyValueComponentStateJson } |
+    { PbHistory: PbHistoryComponentStateJson } |
+    { ProgressBar: ProgressBarComponentStateJson } |
+    { SegmentHistory: SegmentHistoryComponentStateJson } |
     { Separator: null } |
     { Splits: SplitsComponentStateJson } |
     { Text: TextComponentStateJson } |
+    { Timeline: TimelineComponentStateJson } |
     { Timer: TimerComponentStateJson } |
     { Title: TitleComponentStateJson };
 
@@ -42,8 +49,17 @@ export type Alignment = "Auto" | "Left" | "Center";
 export interface LayoutStateJson {
     /** The state objects for all of the components in the layout. */
     components: ComponentStateJson[],
-    /** The direction which the components are laid out in. */
+    /**
+     * The direction which the components are laid out in. This only applies if
+     * the components aren't arranged in a grid.
+     */
     direction: LayoutDirection,
+    /**
+     * The grid the components are arranged in. Each cell of the grid lays out
+     * its components in its own direction. If there is no grid, the components
+     * are laid out in the direction specified above.
+     */
+    grid: LayoutGrid | null,
     /**
      * The font to use for the timer text. `null` means a default font should be
      * used.
@@ -67,8 +83,62 @@ export interface LayoutStateJson {
     separators_color: Color,
     /** The text color to use for text that doesn't specify its own color. */
     text_color: Color,
+    /**
+     * Specifies whether changes in the layout, such as the splits scrolling or
+     * a delta changing its color, are supposed to be animated.
+     */
+    animations: boolean,
+}
+
+/** A node of the accessibility tree of a layout. */
+export interface AccessibilityNodeJson {
+    /** The kind of element the node describes. */
+    role: AccessibilityRole,
+    /**
+     * The name of the element, such as the label of a value or the name of a
+     * segment. This may be empty if the element has no name.
+     */
+    name: string,
+    /** The value shown by the element, if it shows any. */
+    value: string | null,
+    /**
+     * Describes what the color of the value means, such as the runner being
+     * ahead of the comparison. Screen reader users can't see the color, so
+     * this should be read out together with the value.
+     */
+    description: string | null,
+    /** Describes how changes to the value are supposed to be announced. */
+    live: AccessibilityLiveRegion,
+    /**
+     * Specifies whether the element is the current one within its parent,
+     * such as the row of the segment the active attempt is on.
+     */
+    is_current: boolean,
+    /** The nodes of the elements contained within this element. */
+    children: AccessibilityNodeJson[],
 }
 
+/** The kind of element an accessibility node describes. */
+export type AccessibilityRole = "Layout" |
+    "Group" |
+    "Heading" |
+    "Table" |
+    "Row" |
+    "Cell" |
+    "Timer" |
+    "Text" |
+    "Image" |
+    "Chart" |
+    "ProgressBar" |
+    "Separator" |
+    "Presentation";
+
+/**
+ * Describes how changes to the value of an accessibility node are supposed to
+ * be announced by a screen reader.
+ */
+export type AccessibilityLiveRegion = "Off" | "Polite";
+
 /**
  * Describes a Font to visualize text with. Depending on the platform a font
  * that matches the settings most closely is chosen. The settings may be ignored
@@ -182,6 +252,145 @@ export interface BlankSpaceComponentStateJson {
     size: number,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface ClockComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken
+     * from the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the values. If `null` is specified, the color is taken
+     * from the layout.
+     */
+    value_color: Color | null,
+    /**
+     * The rows to visualize. Each row is visualized like a key value based
+     * component.
+     */
+    rows: ClockComponentRowJson[],
+    /**
+     * Specifies whether to display the names of the rows and their values in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+}
+
+/** The state object describing a single row of the Clock Component. */
+export interface ClockComponentRowJson {
+    /** The name of the row. */
+    key: string,
+    /** The value of the row. */
+    value: string,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface CountersComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken
+     * from the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the values. If `null` is specified, the color is taken
+     * from the layout.
+     */
+    value_color: Color | null,
+    /** The amount of counters shown next to each other in a single row. */
+    columns: number,
+    /**
+     * The counters to visualize, laid out from left to right and top to
+     * bottom.
+     */
+    counters: CounterStateJson[],
+}
+
+/** The state object describing a single counter of the Counters Component. */
+export interface CounterStateJson {
+    /** The label of the counter. */
+    label: string,
+    /** The value of the variable. */
+    value: string,
+    /**
+     * The difference to the value the variable had in the Personal Best. This
+     * is empty if there is nothing to compare against.
+     */
+    delta: string,
+    /** The semantic coloring information the delta carries. */
+    delta_semantic_color: SemanticColor,
+    /** The color of the delta. */
+    delta_color: Color,
+    /**
+     * The counter's icon encoded as a Data URL. This value is only specified
+     * whenever the icon changes. If you explicitly want to query this value,
+     * remount the component. The String itself may be empty. This indicates
+     * that there is no icon.
+     */
+    icon_change: string | null,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface CustomImageComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The image encoded as a Data URL. This value is only specified whenever
+     * the image changes. If you explicitly want to query this value, remount
+     * the component. The String itself may be empty. This indicates that there
+     * is no image.
+     */
+    image_change: string | null,
+    /** How the image is supposed to be scaled to fit the component. */
+    scaling: ImageScaling,
+    /** The height of the component. */
+    height: number,
+}
+
+/**
+ * The state object describes the information to visualize for this component.
+ * All widths are in the range 0..1, where 1 is the width of the whole
+ * component.
+ */
+export interface TimelineComponentStateJson {
+    /**
+     * The widths of the comparison's segments, laid out from left to right.
+     * Segments without a time in the comparison are combined with the
+     * following segment.
+     */
+    comparison_segments: number[],
+    /**
+     * The segments completed in the current attempt, laid out from left to
+     * right. Skipped segments are combined with the following segment.
+     */
+    attempt_segments: TimelineSegmentStateJson[],
+    /**
+     * The segment that is currently being run. It is placed directly after
+     * the completed segments. This is null if no attempt is in progress or the
+     * live segment is not supposed to be shown.
+     */
+    live_segment: TimelineSegmentStateJson | null,
+    /** The background color of the bars. */
+    background_color: Color,
+    /** The color of the comparison's segments. */
+    comparison_color: Color,
+    /** The height of the component. */
+    height: number,
+}
+
+/** The state object describing a single segment of the current attempt. */
+export interface TimelineSegmentStateJson {
+    /** The width of the segment. */
+    width: number,
+    /** The semantic coloring information the segment carries. */
+    semantic_color: SemanticColor,
+    /** The color of the segment. */
+    color: Color,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface TimerComponentStateJson {
     /** The background shown behind the component. */
@@ -343,6 +552,13 @@ export interface SplitStateJson {
      * showing only a subset of segments. Each index is guaranteed to be unique.
      */
     index: number,
+    /**
+     * Describes whether this row is a summary of a collapsed section rather
+     * than an individual segment. The name is the name of the section and the
+     * columns show the values of the segment ending the section, which is also
+     * the segment the index refers to.
+     */
+    is_collapsed_section: boolean,
 }
 
 /** Describes the state of a single segment's column to visualize. */
@@ -476,6 +692,133 @@ export interface GraphComponentStatePointJson {
     is_best_segment: boolean,
 }
 
+/**
+ * The state object describes the information to visualize for this component.
+ * All the coordinates are in the range 0..1.
+ */
+export interface ProgressBarComponentStateJson {
+    /**
+     * How far through the run the runner is based on the current time compared
+     * to the final time of the comparison. If the comparison doesn't have a
+     * final time, this is the same as the segment progress.
+     */
+    time_progress: number,
+    /** The ratio of completed segments to all the segments. */
+    segment_progress: number,
+    /**
+     * The x coordinates of the ticks marking the points in time the comparison
+     * reaches each of the splits. The final split is not included.
+     */
+    ticks: number[],
+    /**
+     * Describes whether the current position, which is at the end of the filled
+     * region of the bar, should be marked.
+     */
+    show_current_position: boolean,
+    /**
+     * The color of the filled region of the bar. This is based on whether the
+     * runner is currently ahead or behind the comparison.
+     */
+    fill_color: Color,
+    /** The semantic coloring information the fill color is based on. */
+    semantic_color: SemanticColor,
+    /** The background color of the bars. */
+    background_color: Color,
+    /** The color of the segment ticks. */
+    tick_color: Color,
+    /**
+     * The color of the bar visualizing the amount of segments that are already
+     * completed. This is null if the bar is not supposed to be shown.
+     */
+    segment_progress_color: Color | null,
+    /** The height of the progress bar. */
+    height: number,
+}
+
+/**
+ * The state object describes the information to visualize for this component.
+ * All the coordinates are in the range 0..1. The horizontal coordinates go from
+ * the first to the last attempt shown, while the vertical coordinates go from
+ * the slowest time at the top to the fastest time at the bottom.
+ */
+export interface PbHistoryComponentStateJson {
+    /**
+     * The points of the line showing the Personal Best over time. The line is
+     * a step line, so each improvement is a vertical drop and the line extends
+     * to the right edge of the chart. This is empty if no attempt was ever
+     * finished.
+     */
+    personal_best_points: PbHistoryComponentStatePointJson[],
+    /**
+     * The points of the line showing the Sum of Best Segments over time, which
+     * is shaped just like the line of the Personal Best. This is empty if the
+     * Sum of Best Segments is not supposed to be shown or there never were
+     * segment times for all the segments.
+     */
+    sum_of_best_points: PbHistoryComponentStatePointJson[],
+    /** The background color of the chart. */
+    background_color: Color,
+    /** The color of the line showing the Personal Best. */
+    personal_best_color: Color,
+    /** The color of the line showing the Sum of Best Segments. */
+    sum_of_best_color: Color,
+    /** The height of the chart. */
+    height: number,
+}
+
+/** Describes a point on one of the lines of the PB History Component's chart. */
+export interface PbHistoryComponentStatePointJson {
+    /** The horizontal coordinate of the point. */
+    x: number,
+    /** The vertical coordinate of the point. */
+    y: number,
+}
+
+/**
+ * The state object describes the information to visualize for this component.
+ * All the coordinates are in the range 0..1. The positions of the markers are
+ * along the time axis, which is horizontal for a histogram and vertical for a
+ * sparkline.
+ */
+export interface SegmentHistoryComponentStateJson {
+    /** How the segment times are supposed to be visualized. */
+    style: HistoryStyle,
+    /**
+     * For a histogram, these are the heights of the bars, which all have the
+     * same width and are laid out from the fastest to the slowest bucket. For
+     * a sparkline, these are the positions of the segment times along the time
+     * axis in chronological order, with the points evenly spaced out
+     * horizontally.
+     */
+    values: number[],
+    /** The position of the best segment time along the time axis. */
+    best_segment: number | null,
+    /**
+     * The position of the segment time of the Personal Best along the time
+     * axis.
+     */
+    personal_best: number | null,
+    /**
+     * The position of the live segment time along the time axis. This is null
+     * if the segment is not the one currently being run.
+     */
+    live_segment_time: number | null,
+    /** The semantic coloring information the live segment time carries. */
+    live_segment_semantic_color: SemanticColor,
+    /** The background color of the chart. */
+    background_color: Color,
+    /** The color of the histogram's bars or the sparkline. */
+    history_color: Color,
+    /** The color of the best segment time's marker. */
+    best_segment_color: Color,
+    /** The color of the Personal Best's segment time marker. */
+    personal_best_color: Color,
+    /** The color of the live segment time's marker. */
+    live_segment_color: Color,
+    /** The height of the chart. */
+    height: number,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface TextComponentStateJson {
     /** The background shown behind the component. */
@@ -561,6 +904,23 @@ export interface LayoutEditorStateJson {
      * and their current values.
      */
     general_settings: SettingsDescriptionJson,
+    /**
+     * The grid the components are arranged in. If there is no grid, the
+     * components are laid out in the direction specified by the general
+     * settings.
+     */
+    grid: LayoutGrid | null,
+    /**
+     * A generic description of the settings available for the grid cell the
+     * selected component is in and their current values. This is `null` if the
+     * components are not arranged in a grid.
+     */
+    cell_settings: SettingsDescriptionJson | null,
+    /**
+     * The name of the theme the layout references. This is `null` if the
+     * layout doesn't reference a theme.
+     */
+    theme: string | null,
 }
 
 /**
@@ -584,6 +944,17 @@ export interface LayoutEditorButtonsJson {
      * the last component is selected, it can't be moved.
      */
     can_move_down: boolean,
+    /**
+     * Describes whether the currently selected component can be moved into a
+     * new column. If it is the only component in its column, it can't be
+     * moved.
+     */
+    can_move_into_new_column: boolean,
+    /**
+     * Describes whether the currently selected component can be moved into a
+     * new row. If it is the only component in its cell, it can't be moved.
+     */
+    can_move_into_new_row: boolean,
 }
 
 /** A generic description of the settings available and their current values. */
@@ -629,6 +1000,12 @@ export type SettingsDescriptionValueJson =
     { LayoutDirection: LayoutDirection } |
     { Font: Font | null } |
     { DeltaGradient: DeltaGradient } |
+    { HistoryStyle: HistoryStyle } |
+    { Image: string } |
+    { ImageScaling: ImageScaling } |
+    { ScrollMode: ScrollMode } |
+    { HistoryAxis: HistoryAxis } |
+    { TimeSpan: string } |
     { CustomCombobox: CustomCombobox };
 
 /** Describes the kind of a column. */
@@ -637,9 +1014,58 @@ export type ColumnKind = "Time" | "Variable";
 /** Represents the possible backgrounds for a timer. */
 export type DeltaGradient = Gradient | "DeltaPlain" | "DeltaVertical" | "DeltaHorizontal";
 
+/** Describes how the Segment History Component visualizes the segment times. */
+export type HistoryStyle = "Histogram" | "Sparkline";
+
+/**
+ * Describes how the Custom Image Component scales its image to fit the space
+ * available to the component.
+ */
+export type ImageScaling = "Fit" | "Stretch";
+
+/**
+ * Describes how the Splits Component's window showing the segments
+ * automatically scrolls when the current segment changes.
+ */
+export type ScrollMode = "Preview" | "Centered";
+
+/** Describes what the horizontal axis of the PB History Component represents. */
+export type HistoryAxis = "Attempts" | "Date";
+
 /** Describes the direction the components of a layout are laid out in. */
 export type LayoutDirection = "Vertical" | "Horizontal";
 
+/**
+ * A grid arranges the components of a layout in columns that are placed next
+ * to each other. Each column is split into cells placed on top of each other
+ * and each cell holds its own list of components. The components of the layout
+ * are assigned to the cells in order, going through the cells of the first
+ * column from top to bottom, then through the ones of the second column and so
+ * on.
+ */
+export interface LayoutGrid {
+    /** The columns of the grid from left to right. */
+    columns: GridColumn[],
+}
+
+/** A column of a grid. */
+export interface GridColumn {
+    /** The width of the column relative to the other columns. */
+    size: number,
+    /** The cells of the column from top to bottom. */
+    cells: GridCell[],
+}
+
+/** A cell of a grid's column that holds a list of components. */
+export interface GridCell {
+    /** The height of the cell relative to the other cells in the column. */
+    size: number,
+    /** The direction which the components of the cell are laid out in. */
+    direction: LayoutDirection,
+    /** The amount of components the cell holds. */
+    components: number,
+}
+
 /**
  * A custom Combobox containing its current value and a list of possible
  * values.
@@ -672,7 +1098,10 @@ export type ColumnUpdateWith =
     "DeltaWithFallback" |
     "SegmentTime" |
     "SegmentDelta" |
-    "SegmentDeltaWithFallback";
+    "SegmentDeltaWithFallback" |
+    "DeltaChange" |
+    "PossibleTimeSave" |
+    "BestPossibleTime";
 
 /** Specifies when a column's value gets updated. */
 export type ColumnUpdateTrigger =
@@ -738,6 +1167,11 @@ export interface RunEditorStateJson {
     segments: RunEditorRowJson[],
     /** The names of all the custom comparisons that exist for this Run. */
     comparison_names: string[],
+    /**
+     * The names of all the comparisons that show a past attempt of this Run.
+     * These can be renamed and removed just like custom comparisons.
+     */
+    attempt_comparison_names: string[],
     /** Describes which actions are currently available. */
     buttons: RunEditorButtonsJson,
     /**
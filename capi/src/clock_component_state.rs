@@ -0,0 +1,47 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::clock::State as ClockComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedClockComponentState = Box<ClockComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ClockComponentState_drop(this: OwnedClockComponentState) {
+    drop(this);
+}
+
+/// The amount of rows to visualize.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_len(this: &ClockComponentState) -> usize {
+    this.rows.len()
+}
+
+/// The name of the row with the specified index. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_key(
+    this: &ClockComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].key)
+}
+
+/// The value of the row with the specified index. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_value(
+    this: &ClockComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].value)
+}
+
+/// Specifies whether to display the names of the rows and their values in two
+/// separate rows.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_display_two_rows(this: &ClockComponentState) -> bool {
+    this.display_two_rows
+}
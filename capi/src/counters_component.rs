@@ -0,0 +1,56 @@
+//! The Counters Component shows the values of multiple custom variables in a
+//! grid. Each counter can optionally show how the value compares to the value
+//! the variable had in the Personal Best.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::counters_component_state::OwnedCountersComponentState;
+use livesplit_core::component::counters::Component as CountersComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedCountersComponent = Box<CountersComponent>;
+
+/// Creates a new Counters Component.
+#[no_mangle]
+pub extern "C" fn CountersComponent_new() -> OwnedCountersComponent {
+    Box::new(CountersComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CountersComponent_drop(this: OwnedCountersComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn CountersComponent_into_generic(this: OwnedCountersComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn CountersComponent_state_as_json(
+    this: &mut CountersComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and layout settings
+/// provided.
+#[no_mangle]
+pub extern "C" fn CountersComponent_state(
+    this: &mut CountersComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedCountersComponentState {
+    Box::new(this.state(&timer.snapshot(), layout_settings))
+}
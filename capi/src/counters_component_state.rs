@@ -0,0 +1,98 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, output_vec};
+use livesplit_core::component::counters::State as CountersComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// type
+pub type OwnedCountersComponentState = Box<CountersComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CountersComponentState_drop(this: OwnedCountersComponentState) {
+    drop(this);
+}
+
+/// The amount of counters shown next to each other in a single row.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_columns(this: &CountersComponentState) -> u32 {
+    this.columns
+}
+
+/// The amount of counters to visualize.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_len(this: &CountersComponentState) -> usize {
+    this.counters.len()
+}
+
+/// The label of the counter with the specified index. You may not provide an
+/// out of bounds index.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_label(
+    this: &CountersComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.counters[index].label)
+}
+
+/// The value of the counter with the specified index. You may not provide an
+/// out of bounds index.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_value(
+    this: &CountersComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.counters[index].value)
+}
+
+/// The difference to the value the variable had in the Personal Best for the
+/// counter with the specified index. This is empty if there is nothing to
+/// compare against. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_delta(
+    this: &CountersComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.counters[index].delta)
+}
+
+/// The semantic coloring information the delta of the counter with the
+/// specified index carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_delta_semantic_color(
+    this: &CountersComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.counters[index].delta_semantic_color).unwrap())
+}
+
+/// The data of the icon of the counter with the specified index. This value is
+/// only specified whenever the icon changes. If you explicitly want to query
+/// this value, remount the component. The buffer may be empty. This indicates
+/// that there is no icon. If no change occurred, <NULL> is returned instead.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_icon_change_ptr(
+    this: &CountersComponentState,
+    index: usize,
+) -> *const u8 {
+    this.counters[index]
+        .icon_change
+        .as_ref()
+        .map_or_else(ptr::null, |i| i.as_ptr())
+}
+
+/// The length of the icon data of the counter with the specified index. You
+/// may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn CountersComponentState_icon_change_len(
+    this: &CountersComponentState,
+    index: usize,
+) -> usize {
+    this.counters[index]
+        .icon_change
+        .as_ref()
+        .map_or(0, |i| i.len())
+}
@@ -0,0 +1,53 @@
+//! The Custom Image Component shows an arbitrary image. The image can switch
+//! to a different image for each of the segments as the runner progresses
+//! through the run.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::custom_image_component_state::OwnedCustomImageComponentState;
+use livesplit_core::component::custom_image::Component as CustomImageComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedCustomImageComponent = Box<CustomImageComponent>;
+
+/// Creates a new Custom Image Component.
+#[no_mangle]
+pub extern "C" fn CustomImageComponent_new() -> OwnedCustomImageComponent {
+    Box::new(CustomImageComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CustomImageComponent_drop(this: OwnedCustomImageComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn CustomImageComponent_into_generic(
+    this: OwnedCustomImageComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn CustomImageComponent_state_as_json(
+    this: &mut CustomImageComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot()).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn CustomImageComponent_state(
+    this: &mut CustomImageComponent,
+    timer: &Timer,
+) -> OwnedCustomImageComponentState {
+    Box::new(this.state(&timer.snapshot()))
+}
@@ -0,0 +1,51 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_vec;
+use livesplit_core::component::custom_image::State as CustomImageComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// type
+pub type OwnedCustomImageComponentState = Box<CustomImageComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CustomImageComponentState_drop(this: OwnedCustomImageComponentState) {
+    drop(this);
+}
+
+/// The data of the image. This value is only specified whenever the image
+/// changes. If you explicitly want to query this value, remount the component.
+/// The buffer may be empty. This indicates that there is no image. If no change
+/// occurred, <NULL> is returned instead.
+#[no_mangle]
+pub extern "C" fn CustomImageComponentState_image_change_ptr(
+    this: &CustomImageComponentState,
+) -> *const u8 {
+    this.image_change
+        .as_ref()
+        .map_or_else(ptr::null, |i| i.as_ptr())
+}
+
+/// The length of the image's data.
+#[no_mangle]
+pub extern "C" fn CustomImageComponentState_image_change_len(
+    this: &CustomImageComponentState,
+) -> usize {
+    this.image_change.as_ref().map_or(0, |i| i.len())
+}
+
+/// How the image is supposed to be scaled to fit the component.
+#[no_mangle]
+pub extern "C" fn CustomImageComponentState_scaling(
+    this: &CustomImageComponentState,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.scaling).unwrap())
+}
+
+/// The height of the component.
+#[no_mangle]
+pub extern "C" fn CustomImageComponentState_height(this: &CustomImageComponentState) -> u32 {
+    this.height
+}
@@ -8,7 +8,10 @@ use crate::{
     component::OwnedComponent, layout::OwnedLayout, layout_editor_state::OwnedLayoutEditorState,
     setting_value::OwnedSettingValue,
 };
-use livesplit_core::{layout::LayoutState, LayoutEditor, Timer};
+use livesplit_core::{
+    layout::{LayoutState, Theme},
+    LayoutEditor, Timer,
+};
 
 /// type
 pub type OwnedLayoutEditor = Box<LayoutEditor>;
@@ -107,20 +110,24 @@ pub extern "C" fn LayoutEditor_remove_component(this: &mut LayoutEditor) {
 }
 
 /// Moves the selected component up, unless the first component is selected.
+/// If the components are arranged in a grid and the component is the first one
+/// in its cell, it moves to the end of the previous cell instead.
 #[no_mangle]
 pub extern "C" fn LayoutEditor_move_component_up(this: &mut LayoutEditor) {
     this.move_component_up();
 }
 
 /// Moves the selected component down, unless the last component is
-/// selected.
+/// selected. If the components are arranged in a grid and the component is the
+/// last one in its cell, it moves to the start of the next cell instead.
 #[no_mangle]
 pub extern "C" fn LayoutEditor_move_component_down(this: &mut LayoutEditor) {
     this.move_component_down();
 }
 
 /// Moves the selected component to the index provided. You may not provide
-/// an invalid index.
+/// an invalid index. If the components are arranged in a grid, the component
+/// ends up in the cell of the component that was at that index before.
 #[no_mangle]
 pub extern "C" fn LayoutEditor_move_component(this: &mut LayoutEditor, dst_index: usize) {
     this.move_component(dst_index);
@@ -162,3 +169,53 @@ pub extern "C" fn LayoutEditor_set_general_settings_value(
 ) {
     this.set_general_settings_value(index, *value);
 }
+
+/// Moves the selected component into a new column that is placed right after
+/// the column it is currently in. If the components are not arranged in a grid
+/// yet, a grid is created that places all the other components in the first
+/// column.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_move_component_into_new_column(this: &mut LayoutEditor) {
+    this.move_component_into_new_column();
+}
+
+/// Moves the selected component into a new cell that is placed right below the
+/// cell it is currently in. If the components are not arranged in a grid yet,
+/// a grid is created that places all the other components in the first cell.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_move_component_into_new_row(this: &mut LayoutEditor) {
+    this.move_component_into_new_row();
+}
+
+/// Sets a setting's value of the grid cell the selected component is in by its
+/// setting index to the given value. This does nothing if the components are
+/// not arranged in a grid.
+///
+/// This panics if the type of the value to be set is not compatible with
+/// the type of the setting's value. A panic can also occur if the index of
+/// the setting provided is out of bounds.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_set_cell_settings_value(
+    this: &mut LayoutEditor,
+    index: usize,
+    value: OwnedSettingValue,
+) {
+    this.set_cell_settings_value(index, *value);
+}
+
+/// Applies the theme provided to the layout and references it by its name from
+/// now on. All the general settings the layout overrides are kept, while all
+/// the others are replaced by the theme's settings. Changing one of the
+/// theme's settings in the general settings while a theme is referenced
+/// overrides it.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_apply_theme(this: &mut LayoutEditor, theme: &Theme) {
+    this.apply_theme(theme);
+}
+
+/// Stops the layout from referencing a theme and drops all the overrides. The
+/// general settings of the layout keep their current values.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_detach_theme(this: &mut LayoutEditor) {
+    this.detach_theme();
+}
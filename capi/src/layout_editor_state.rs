@@ -32,12 +32,16 @@ pub extern "C" fn LayoutEditorState_component_text(
 ///
 /// The bits are as follows:
 ///
+/// * `0x10` - Can move the current component into a new column
+/// * `0x08` - Can move the current component into a new row
 /// * `0x04` - Can remove the current component
 /// * `0x02` - Can move the current component up
 /// * `0x01` - Can move the current component down
 #[no_mangle]
 pub extern "C" fn LayoutEditorState_buttons(this: &LayoutEditorState) -> u8 {
-    (this.buttons.can_remove as u8) << 2
+    (this.buttons.can_move_into_new_column as u8) << 4
+        | (this.buttons.can_move_into_new_row as u8) << 3
+        | (this.buttons.can_remove as u8) << 2
         | (this.buttons.can_move_up as u8) << 1
         | this.buttons.can_move_down as u8
 }
@@ -94,3 +98,28 @@ pub extern "C" fn LayoutEditorState_field_value(
         &this.general_settings.fields[index].value
     }
 }
+
+/// Returns the number of fields in the settings of the grid cell the selected
+/// component is in. This is 0 if the components are not arranged in a grid.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_cell_field_len(this: &LayoutEditorState) -> usize {
+    this.cell_settings.as_ref().map_or(0, |s| s.fields.len())
+}
+
+/// Returns the name of the grid cell's setting at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_cell_field_text(
+    this: &LayoutEditorState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.cell_settings.as_ref().unwrap().fields[index].text)
+}
+
+/// Returns the value of the grid cell's setting at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_cell_field_value(
+    this: &LayoutEditorState,
+    index: usize,
+) -> &SettingValue {
+    &this.cell_settings.as_ref().unwrap().fields[index].value
+}
@@ -8,10 +8,14 @@
 
 use crate::{output_vec, Json};
 use livesplit_core::component::{
-    blank_space::State as BlankSpaceComponentState,
+    blank_space::State as BlankSpaceComponentState, clock::State as ClockComponentState,
+    counters::State as CountersComponentState, custom_image::State as CustomImageComponentState,
     detailed_timer::State as DetailedTimerComponentState, graph::State as GraphComponentState,
-    key_value::State as KeyValueComponentState, separator::State as SeparatorComponentState,
-    splits::State as SplitsComponentState, text::State as TextComponentState,
+    key_value::State as KeyValueComponentState, pb_history::State as PbHistoryComponentState,
+    progress_bar::State as ProgressBarComponentState,
+    segment_history::State as SegmentHistoryComponentState,
+    separator::State as SeparatorComponentState, splits::State as SplitsComponentState,
+    text::State as TextComponentState, timeline::State as TimelineComponentState,
     timer::State as TimerComponentState, title::State as TitleComponentState,
 };
 use livesplit_core::layout::{ComponentState, LayoutState};
@@ -42,6 +46,16 @@ pub extern "C" fn LayoutState_as_json(this: &LayoutState) -> Json {
     })
 }
 
+/// Encodes the accessibility tree of the layout state as JSON. Frontends can
+/// hand its nodes to the accessibility APIs of their platform, so that screen
+/// readers can read out the information shown by the layout.
+#[no_mangle]
+pub extern "C" fn LayoutState_accessibility_tree_as_json(this: &LayoutState) -> Json {
+    output_vec(|o| {
+        serde_json::to_writer(o, &this.accessibility_tree()).unwrap();
+    })
+}
+
 /// Gets the number of Components in the Layout State.
 #[no_mangle]
 pub extern "C" fn LayoutState_len(this: &LayoutState) -> usize {
@@ -54,12 +68,19 @@ pub extern "C" fn LayoutState_len(this: &LayoutState) -> usize {
 pub extern "C" fn LayoutState_component_type(this: &LayoutState, index: usize) -> *const c_char {
     (match this.components[index] {
         ComponentState::BlankSpace(_) => "BlankSpace\0",
+        ComponentState::Clock(_) => "Clock\0",
+        ComponentState::Counters(_) => "Counters\0",
+        ComponentState::CustomImage(_) => "CustomImage\0",
         ComponentState::DetailedTimer(_) => "DetailedTimer\0",
         ComponentState::Graph(_) => "Graph\0",
         ComponentState::KeyValue(_) => "KeyValue\0",
+        ComponentState::PbHistory(_) => "PbHistory\0",
+        ComponentState::ProgressBar(_) => "ProgressBar\0",
+        ComponentState::SegmentHistory(_) => "SegmentHistory\0",
         ComponentState::Separator(_) => "Separator\0",
         ComponentState::Splits(_) => "Splits\0",
         ComponentState::Text(_) => "Text\0",
+        ComponentState::Timeline(_) => "Timeline\0",
         ComponentState::Timer(_) => "Timer\0",
         ComponentState::Title(_) => "Title\0",
     })
@@ -79,6 +100,42 @@ pub extern "C" fn LayoutState_component_as_blank_space(
     }
 }
 
+/// Gets the Clock component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_clock(
+    this: &LayoutState,
+    index: usize,
+) -> &ClockComponentState {
+    match &this.components[index] {
+        ComponentState::Clock(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Counters component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_counters(
+    this: &LayoutState,
+    index: usize,
+) -> &CountersComponentState {
+    match &this.components[index] {
+        ComponentState::Counters(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Custom Image component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_custom_image(
+    this: &LayoutState,
+    index: usize,
+) -> &CustomImageComponentState {
+    match &this.components[index] {
+        ComponentState::CustomImage(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
 /// Gets the Detailed Timer component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_detailed_timer(
@@ -115,6 +172,42 @@ pub extern "C" fn LayoutState_component_as_key_value(
     }
 }
 
+/// Gets the PB History component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_pb_history(
+    this: &LayoutState,
+    index: usize,
+) -> &PbHistoryComponentState {
+    match &this.components[index] {
+        ComponentState::PbHistory(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Progress Bar component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_progress_bar(
+    this: &LayoutState,
+    index: usize,
+) -> &ProgressBarComponentState {
+    match &this.components[index] {
+        ComponentState::ProgressBar(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Segment History component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_segment_history(
+    this: &LayoutState,
+    index: usize,
+) -> &SegmentHistoryComponentState {
+    match &this.components[index] {
+        ComponentState::SegmentHistory(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
 /// Gets the Separator component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_separator(
@@ -151,6 +244,18 @@ pub extern "C" fn LayoutState_component_as_text(
     }
 }
 
+/// Gets the Timeline component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_timeline(
+    this: &LayoutState,
+    index: usize,
+) -> &TimelineComponentState {
+    match &this.components[index] {
+        ComponentState::Timeline(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
 /// Gets the Timer component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_timer(
@@ -18,9 +18,15 @@ pub mod attempt;
 pub mod auto_splitting_runtime;
 pub mod blank_space_component;
 pub mod blank_space_component_state;
+pub mod clock_component;
+pub mod clock_component_state;
 pub mod component;
+pub mod counters_component;
+pub mod counters_component_state;
 pub mod current_comparison_component;
 pub mod current_pace_component;
+pub mod custom_image_component;
+pub mod custom_image_component_state;
 pub mod delta_component;
 pub mod detailed_timer_component;
 pub mod detailed_timer_component_state;
@@ -37,9 +43,13 @@ pub mod layout_editor_state;
 pub mod layout_state;
 pub mod parse_run_result;
 pub mod pb_chance_component;
+pub mod pb_history_component;
+pub mod pb_history_component_state;
 pub mod possible_time_save_component;
 pub mod potential_clean_up;
 pub mod previous_segment_component;
+pub mod progress_bar_component;
+pub mod progress_bar_component_state;
 pub mod run;
 pub mod run_editor;
 pub mod run_metadata;
@@ -49,6 +59,8 @@ pub mod run_metadata_speedrun_com_variable;
 pub mod run_metadata_speedrun_com_variables_iter;
 pub mod segment;
 pub mod segment_history;
+pub mod segment_history_component;
+pub mod segment_history_component_state;
 pub mod segment_history_element;
 pub mod segment_history_iter;
 pub mod segment_time_component;
@@ -63,8 +75,11 @@ pub mod sum_of_best_cleaner;
 pub mod sum_of_best_component;
 pub mod text_component;
 pub mod text_component_state;
+pub mod theme;
 pub mod time;
 pub mod time_span;
+pub mod timeline_component;
+pub mod timeline_component_state;
 pub mod timer;
 pub mod timer_component;
 pub mod timer_component_state;
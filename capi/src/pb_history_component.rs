@@ -0,0 +1,56 @@
+//! The PB History Component shows a chart of how the Personal Best improved
+//! over the course of the Attempt History. Optionally the Sum of Best Segments
+//! is shown over time as well.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::pb_history_component_state::OwnedPbHistoryComponentState;
+use livesplit_core::component::pb_history::Component as PbHistoryComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedPbHistoryComponent = Box<PbHistoryComponent>;
+
+/// Creates a new PB History Component.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponent_new() -> OwnedPbHistoryComponent {
+    Box::new(PbHistoryComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn PbHistoryComponent_drop(this: OwnedPbHistoryComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponent_into_generic(this: OwnedPbHistoryComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponent_state_as_json(
+    this: &PbHistoryComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and layout settings
+/// provided.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponent_state(
+    this: &PbHistoryComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedPbHistoryComponentState {
+    Box::new(this.state(&timer.snapshot(), layout_settings))
+}
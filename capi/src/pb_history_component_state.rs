@@ -0,0 +1,79 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates are in the range 0..1. The horizontal coordinates go
+//! from the first to the last attempt shown, while the vertical coordinates go
+//! from the slowest time at the top to the fastest time at the bottom.
+
+use livesplit_core::component::pb_history::State as PbHistoryComponentState;
+
+/// type
+pub type OwnedPbHistoryComponentState = Box<PbHistoryComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_drop(this: OwnedPbHistoryComponentState) {
+    drop(this);
+}
+
+/// Returns the amount of points of the line showing the Personal Best over
+/// time.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_personal_best_points_len(
+    this: &PbHistoryComponentState,
+) -> usize {
+    this.personal_best_points.len()
+}
+
+/// Accesses the horizontal coordinate of the point of the Personal Best's line
+/// specified. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_personal_best_point_x(
+    this: &PbHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.personal_best_points[index].x
+}
+
+/// Accesses the vertical coordinate of the point of the Personal Best's line
+/// specified. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_personal_best_point_y(
+    this: &PbHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.personal_best_points[index].y
+}
+
+/// Returns the amount of points of the line showing the Sum of Best Segments
+/// over time.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_sum_of_best_points_len(
+    this: &PbHistoryComponentState,
+) -> usize {
+    this.sum_of_best_points.len()
+}
+
+/// Accesses the horizontal coordinate of the point of the Sum of Best
+/// Segments' line specified. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_sum_of_best_point_x(
+    this: &PbHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.sum_of_best_points[index].x
+}
+
+/// Accesses the vertical coordinate of the point of the Sum of Best Segments'
+/// line specified. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_sum_of_best_point_y(
+    this: &PbHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.sum_of_best_points[index].y
+}
+
+/// The height of the chart.
+#[no_mangle]
+pub extern "C" fn PbHistoryComponentState_height(this: &PbHistoryComponentState) -> u32 {
+    this.height
+}
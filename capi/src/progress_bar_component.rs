@@ -0,0 +1,59 @@
+//! The Progress Bar Component visualizes how far through the run the runner
+//! currently is, both by the expected time and by the amount of segments
+//! completed. The bar is colored based on whether the runner is currently ahead
+//! or behind the chosen comparison.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::progress_bar_component_state::OwnedProgressBarComponentState;
+use livesplit_core::component::progress_bar::Component as ProgressBarComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedProgressBarComponent = Box<ProgressBarComponent>;
+
+/// Creates a new Progress Bar Component.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponent_new() -> OwnedProgressBarComponent {
+    Box::new(ProgressBarComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ProgressBarComponent_drop(this: OwnedProgressBarComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponent_into_generic(
+    this: OwnedProgressBarComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponent_state_as_json(
+    this: &ProgressBarComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and layout settings
+/// provided.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponent_state(
+    this: &ProgressBarComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedProgressBarComponentState {
+    Box::new(this.state(&timer.snapshot(), layout_settings))
+}
@@ -0,0 +1,70 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates are in the range 0..1.
+
+use super::output_vec;
+use livesplit_core::component::progress_bar::State as ProgressBarComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedProgressBarComponentState = Box<ProgressBarComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_drop(this: OwnedProgressBarComponentState) {
+    drop(this);
+}
+
+/// How far through the run the runner is based on the current time compared
+/// to the final time of the comparison.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_time_progress(this: &ProgressBarComponentState) -> f32 {
+    this.time_progress
+}
+
+/// The ratio of completed segments to all the segments.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_segment_progress(
+    this: &ProgressBarComponentState,
+) -> f32 {
+    this.segment_progress
+}
+
+/// Returns the amount of segment ticks to visualize.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_ticks_len(this: &ProgressBarComponentState) -> usize {
+    this.ticks.len()
+}
+
+/// Accesses the x coordinate of the segment tick specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_tick(
+    this: &ProgressBarComponentState,
+    index: usize,
+) -> f32 {
+    this.ticks[index]
+}
+
+/// Describes whether the current position, which is at the end of the filled
+/// region of the bar, should be marked.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_show_current_position(
+    this: &ProgressBarComponentState,
+) -> bool {
+    this.show_current_position
+}
+
+/// The semantic coloring information the filled region of the bar carries.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_semantic_color(
+    this: &ProgressBarComponentState,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
+}
+
+/// The height of the progress bar.
+#[no_mangle]
+pub extern "C" fn ProgressBarComponentState_height(this: &ProgressBarComponentState) -> u32 {
+    this.height
+}
@@ -352,8 +352,24 @@ pub unsafe extern "C" fn RunEditor_import_comparison(
     this.import_comparison(run, str(comparison)).is_ok()
 }
 
-/// Removes the chosen custom comparison. You can't remove a Comparison
-/// Generator's Comparison or the Personal Best.
+/// Adds a comparison that reconstructs the splits of the attempt with the
+/// given index in the Attempt History, so that the runner can race against any
+/// previous attempt. The comparison is named after the index of the attempt
+/// and is kept up to date by a Comparison Generator that is stored in the
+/// splits file. The comparison can't be added if there is no attempt with the
+/// index provided or if it already exists.
+#[no_mangle]
+pub extern "C" fn RunEditor_add_attempt_comparison(
+    this: &mut RunEditor,
+    attempt_index: i32,
+) -> bool {
+    this.add_attempt_comparison(attempt_index).is_ok()
+}
+
+/// Removes the chosen custom comparison. Comparisons of Comparison Generators
+/// that were added to the Run, like the ones added by
+/// RunEditor_add_attempt_comparison, are removed as well. You can't remove the
+/// comparison of a default Comparison Generator or the Personal Best.
 #[no_mangle]
 pub unsafe extern "C" fn RunEditor_remove_comparison(
     this: &mut RunEditor,
@@ -0,0 +1,58 @@
+//! The Segment History Component visualizes the distribution of the segment
+//! times achieved in the past for a segment, which gives the runner immediate
+//! feedback on whether the segment is going well.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::segment_history_component_state::OwnedSegmentHistoryComponentState;
+use livesplit_core::component::segment_history::Component as SegmentHistoryComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedSegmentHistoryComponent = Box<SegmentHistoryComponent>;
+
+/// Creates a new Segment History Component.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponent_new() -> OwnedSegmentHistoryComponent {
+    Box::new(SegmentHistoryComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponent_drop(this: OwnedSegmentHistoryComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponent_into_generic(
+    this: OwnedSegmentHistoryComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponent_state_as_json(
+    this: &SegmentHistoryComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and layout settings
+/// provided.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponent_state(
+    this: &SegmentHistoryComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedSegmentHistoryComponentState {
+    Box::new(this.state(&timer.snapshot(), layout_settings))
+}
@@ -0,0 +1,86 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates are in the range 0..1.
+
+use super::output_vec;
+use livesplit_core::component::segment_history::State as SegmentHistoryComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedSegmentHistoryComponentState = Box<SegmentHistoryComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_drop(this: OwnedSegmentHistoryComponentState) {
+    drop(this);
+}
+
+/// Describes how the segment times are supposed to be visualized. This is
+/// either `Histogram` or `Sparkline`.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_style(
+    this: &SegmentHistoryComponentState,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.style).unwrap())
+}
+
+/// Returns the amount of values to visualize. For a histogram, these are the
+/// bars. For a sparkline, these are the points of the line.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_values_len(
+    this: &SegmentHistoryComponentState,
+) -> usize {
+    this.values.len()
+}
+
+/// Accesses the value specified. For a histogram, this is the height of the
+/// bar. For a sparkline, this is the position of the segment time along the
+/// time axis. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_value(
+    this: &SegmentHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.values[index]
+}
+
+/// The position of the best segment time along the time axis. This is
+/// negative if there is no best segment time.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_best_segment(
+    this: &SegmentHistoryComponentState,
+) -> f32 {
+    this.best_segment.unwrap_or(-1.0)
+}
+
+/// The position of the segment time of the Personal Best along the time axis.
+/// This is negative if the Personal Best doesn't have a segment time.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_personal_best(
+    this: &SegmentHistoryComponentState,
+) -> f32 {
+    this.personal_best.unwrap_or(-1.0)
+}
+
+/// The position of the live segment time along the time axis. This is
+/// negative if the segment is not the one currently being run.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_live_segment_time(
+    this: &SegmentHistoryComponentState,
+) -> f32 {
+    this.live_segment_time.unwrap_or(-1.0)
+}
+
+/// The semantic coloring information the live segment time carries.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_live_segment_semantic_color(
+    this: &SegmentHistoryComponentState,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.live_segment_semantic_color).unwrap())
+}
+
+/// The height of the chart.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_height(this: &SegmentHistoryComponentState) -> u32 {
+    this.height
+}
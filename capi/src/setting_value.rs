@@ -4,18 +4,22 @@
 use crate::{output_vec, str, Json};
 use livesplit_core::{
     component::{
-        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+        custom_image::ImageScaling,
+        pb_history::HistoryAxis,
+        segment_history::HistoryStyle,
+        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, ScrollMode},
         timer::DeltaGradient,
     },
     layout::LayoutDirection,
     settings::{
-        Alignment, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight, Gradient,
+        Alignment, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight, Gradient, Image,
         ListGradient, Value as SettingValue,
     },
     timing::formatter::{Accuracy, DigitsFormat},
-    TimingMethod,
+    TimeSpan, TimingMethod,
 };
 use std::os::raw::c_char;
+use std::slice;
 
 /// type
 pub type OwnedSettingValue = Box<SettingValue>;
@@ -281,6 +285,9 @@ pub unsafe extern "C" fn SettingValue_from_column_update_with(
         "SegmentTime" => ColumnUpdateWith::SegmentTime,
         "SegmentDelta" => ColumnUpdateWith::SegmentDelta,
         "SegmentDeltaWithFallback" => ColumnUpdateWith::SegmentDeltaWithFallback,
+        "DeltaChange" => ColumnUpdateWith::DeltaChange,
+        "PossibleTimeSave" => ColumnUpdateWith::PossibleTimeSave,
+        "BestPossibleTime" => ColumnUpdateWith::BestPossibleTime,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -317,6 +324,92 @@ pub unsafe extern "C" fn SettingValue_from_layout_direction(
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from the history style. If it doesn't match a
+/// known history style, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_history_style(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Histogram" => HistoryStyle::Histogram,
+        "Sparkline" => HistoryStyle::Sparkline,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the image scaling. If it doesn't match a
+/// known image scaling, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image_scaling(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Fit" => ImageScaling::Fit,
+        "Stretch" => ImageScaling::Stretch,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the scroll mode name. If it doesn't match
+/// a known scroll mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_scroll_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Preview" => ScrollMode::Preview,
+        "Centered" => ScrollMode::Centered,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the history axis name. If it doesn't match
+/// a known history axis, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_history_axis(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Attempts" => HistoryAxis::Attempts,
+        "Date" => HistoryAxis::Date,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from a time span that is parsed from the
+/// string provided, such as `-5` or `1:30.5`. If the string doesn't contain a
+/// valid time, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_time_span(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value: TimeSpan = str(value).parse().ok()?;
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the data of an image. The image may be
+/// empty, which indicates that there is no image.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image(
+    data: *const u8,
+    length: usize,
+) -> OwnedSettingValue {
+    let data = if length == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, length)
+    };
+    Box::new(Image::new(data).into())
+}
+
 /// Creates a new setting value with the type `font`.
 #[no_mangle]
 pub unsafe extern "C" fn SettingValue_from_font(
@@ -4,18 +4,47 @@
 use livesplit_core::layout::LayoutState;
 
 #[cfg(feature = "software-rendering")]
-use livesplit_core::rendering::software::BorrowedRenderer as SoftwareRenderer;
+use livesplit_core::rendering::{software::BorrowedRenderer as SoftwareRenderer, HitElement};
 
 #[cfg(not(feature = "software-rendering"))]
 /// dummy
 pub struct SoftwareRenderer;
 #[cfg(not(feature = "software-rendering"))]
+struct DirtyRegion {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+#[cfg(not(feature = "software-rendering"))]
+struct Hit {
+    component_index: usize,
+    element: HitElement,
+}
+#[cfg(not(feature = "software-rendering"))]
+#[allow(dead_code)]
+enum HitElement {
+    Split { segment_index: usize },
+}
+#[cfg(not(feature = "software-rendering"))]
 impl SoftwareRenderer {
     fn new() -> Self {
         panic!("The software renderer is not compiled in.")
     }
 
     fn render(&mut self, _: &LayoutState, _: &mut [u8], _: [u32; 2], _: u32, _: bool) {}
+
+    fn dirty_regions(&self) -> &[DirtyRegion] {
+        &[]
+    }
+
+    fn needs_next_frame(&self) -> bool {
+        false
+    }
+
+    fn hit_test(&self, _: f32, _: f32) -> Option<Hit> {
+        None
+    }
 }
 
 /// type
@@ -40,8 +69,9 @@ pub extern "C" fn SoftwareRenderer_drop(this: OwnedSoftwareRenderer) {
 /// 128x64. In that case you provide the real dimensions of 100x50 as the width
 /// and height, but a stride of 128 pixels as that correlates with the real
 /// width of the underlying buffer. By default the renderer will try not to
-/// redraw parts of the image that haven't changed. You can force a redraw in
-/// case the image provided or its contents have changed.
+/// redraw parts of the image that haven't changed. The regions that got
+/// redrawn can be queried afterwards. You can force a redraw in case the image
+/// provided or its contents have changed.
 #[no_mangle]
 pub unsafe extern "C" fn SoftwareRenderer_render(
     this: &mut SoftwareRenderer,
@@ -60,3 +90,80 @@ pub unsafe extern "C" fn SoftwareRenderer_render(
         force_redraw,
     );
 }
+
+/// Returns the amount of regions of the image that changed when rendering the
+/// last frame. If nothing changed, this is 0.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_dirty_regions_len(this: &SoftwareRenderer) -> usize {
+    this.dirty_regions().len()
+}
+
+/// Accesses the x coordinate of the left edge of the dirty region specified in
+/// pixels. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_dirty_region_x(this: &SoftwareRenderer, index: usize) -> u32 {
+    this.dirty_regions()[index].x
+}
+
+/// Accesses the y coordinate of the top edge of the dirty region specified in
+/// pixels. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_dirty_region_y(this: &SoftwareRenderer, index: usize) -> u32 {
+    this.dirty_regions()[index].y
+}
+
+/// Accesses the width of the dirty region specified in pixels. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_dirty_region_width(
+    this: &SoftwareRenderer,
+    index: usize,
+) -> u32 {
+    this.dirty_regions()[index].width
+}
+
+/// Accesses the height of the dirty region specified in pixels. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_dirty_region_height(
+    this: &SoftwareRenderer,
+    index: usize,
+) -> u32 {
+    this.dirty_regions()[index].height
+}
+
+/// Returns whether the layout is in the middle of an animation. In that case
+/// another frame should be rendered as soon as possible, even if the state of
+/// the layout doesn't change.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_needs_next_frame(this: &SoftwareRenderer) -> bool {
+    this.needs_next_frame()
+}
+
+/// Returns the index of the component that is located at the pixel provided.
+/// This is based on the last frame that got rendered. If there is no component
+/// at the pixel, `-1` is returned instead.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_hit_test_component(
+    this: &SoftwareRenderer,
+    x: f32,
+    y: f32,
+) -> isize {
+    this.hit_test(x, y)
+        .map_or(-1, |hit| hit.component_index as isize)
+}
+
+/// Returns the index of the segment shown by the split row that is located at
+/// the pixel provided. This is based on the last frame that got rendered. If
+/// there is no split row at the pixel, `-1` is returned instead.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_hit_test_segment(
+    this: &SoftwareRenderer,
+    x: f32,
+    y: f32,
+) -> isize {
+    match this.hit_test(x, y).map(|hit| hit.element) {
+        Some(HitElement::Split { segment_index, .. }) => segment_index as isize,
+        _ => -1,
+    }
+}
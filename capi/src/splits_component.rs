@@ -4,11 +4,12 @@
 //! time. The list provides scrolling functionality, so not every segment needs
 //! to be shown all the time.
 
-use super::{output_vec, Json};
+use super::{output_vec, str, Json};
 use crate::component::OwnedComponent;
 use crate::splits_component_state::OwnedSplitsComponentState;
-use livesplit_core::component::splits::Component as SplitsComponent;
+use livesplit_core::component::splits::{Component as SplitsComponent, ScrollMode};
 use livesplit_core::{GeneralLayoutSettings, Timer};
+use std::os::raw::c_char;
 
 /// type
 pub type OwnedSplitsComponent = Box<SplitsComponent>;
@@ -93,6 +94,33 @@ pub extern "C" fn SplitsComponent_set_split_preview_count(
     this.settings_mut().split_preview_count = count;
 }
 
+/// Specifies how the window showing the segments automatically scrolls when
+/// the current segment changes. The scroll mode is either `Preview` or
+/// `Centered`. Returns <FALSE> if the scroll mode is not known.
+#[no_mangle]
+pub unsafe extern "C" fn SplitsComponent_set_scroll_mode(
+    this: &mut SplitsComponent,
+    scroll_mode: *const c_char,
+) -> bool {
+    this.settings_mut().scroll_mode = match str(scroll_mode) {
+        "Preview" => ScrollMode::Preview,
+        "Centered" => ScrollMode::Centered,
+        _ => return false,
+    };
+    true
+}
+
+/// Specifies whether the segments should be grouped into sections, where only
+/// the section containing the current segment is expanded and every other
+/// section is collapsed into a single row.
+#[no_mangle]
+pub extern "C" fn SplitsComponent_set_collapse_sections(
+    this: &mut SplitsComponent,
+    collapse_sections: bool,
+) {
+    this.settings_mut().collapse_sections = collapse_sections;
+}
+
 /// If not every segment is shown in the scrolling window of segments, then
 /// this determines whether the final segment is always to be shown, as it
 /// contains valuable information about the total duration of the chosen
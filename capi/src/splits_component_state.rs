@@ -129,6 +129,16 @@ pub extern "C" fn SplitsComponentState_is_current_split(
     this.splits[index].is_current_split
 }
 
+/// Describes if the row with the specified index is a summary of a collapsed
+/// section rather than an individual segment.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_is_collapsed_section(
+    this: &SplitsComponentState,
+    index: usize,
+) -> bool {
+    this.splits[index].is_collapsed_section
+}
+
 /// Describes if the columns have labels that are meant to be shown. If this is
 /// `false`, no labels are supposed to be visualized.
 #[no_mangle]
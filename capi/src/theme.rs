@@ -0,0 +1,50 @@
+//! A theme describes the look of a layout, independent of the components it
+//! consists of. Layouts reference the theme that is applied to them and may
+//! override any of the theme's settings.
+
+use super::{output_vec, str, Json};
+use livesplit_core::{layout::Theme, Layout};
+use std::{io::Cursor, os::raw::c_char};
+
+/// type
+pub type OwnedTheme = Box<Theme>;
+/// type
+pub type NullableOwnedTheme = Option<OwnedTheme>;
+
+/// Creates a new theme with the name provided out of the look of the layout
+/// provided.
+#[no_mangle]
+pub unsafe extern "C" fn Theme_from_layout(layout: &Layout, name: *const c_char) -> OwnedTheme {
+    Box::new(Theme::from_settings(
+        str(name).to_owned(),
+        layout.general_settings(),
+    ))
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn Theme_drop(this: OwnedTheme) {
+    drop(this);
+}
+
+/// Clones the theme.
+#[no_mangle]
+pub extern "C" fn Theme_clone(this: &Theme) -> OwnedTheme {
+    Box::new(this.clone())
+}
+
+/// Parses a theme from the given JSON description. <NULL> is returned if it
+/// couldn't be parsed.
+#[no_mangle]
+pub unsafe extern "C" fn Theme_parse_json(settings: Json) -> NullableOwnedTheme {
+    let settings = Cursor::new(str(settings).as_bytes());
+    Theme::from_json(settings).ok().map(Box::new)
+}
+
+/// Encodes the theme as JSON. You can use this to save the theme.
+#[no_mangle]
+pub extern "C" fn Theme_as_json(this: &Theme) -> Json {
+    output_vec(|o| {
+        this.write_json(o).unwrap();
+    })
+}
@@ -0,0 +1,57 @@
+//! The Timeline Component shows the run as two bars of stacked segments. The
+//! top bar shows the segment durations of the chosen comparison, while the
+//! bottom bar shows the durations of the segments already completed in the
+//! current attempt.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::timeline_component_state::OwnedTimelineComponentState;
+use livesplit_core::component::timeline::Component as TimelineComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedTimelineComponent = Box<TimelineComponent>;
+
+/// Creates a new Timeline Component.
+#[no_mangle]
+pub extern "C" fn TimelineComponent_new() -> OwnedTimelineComponent {
+    Box::new(TimelineComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn TimelineComponent_drop(this: OwnedTimelineComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn TimelineComponent_into_generic(this: OwnedTimelineComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn TimelineComponent_state_as_json(
+    this: &TimelineComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and layout settings
+/// provided.
+#[no_mangle]
+pub extern "C" fn TimelineComponent_state(
+    this: &TimelineComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedTimelineComponentState {
+    Box::new(this.state(&timer.snapshot(), layout_settings))
+}
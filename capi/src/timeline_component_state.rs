@@ -0,0 +1,98 @@
+//! The state object describes the information to visualize for this component.
+//! All the widths are in the range 0..1, where 1 is the width of the whole
+//! component.
+
+use super::output_vec;
+use livesplit_core::component::timeline::State as TimelineComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedTimelineComponentState = Box<TimelineComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_drop(this: OwnedTimelineComponentState) {
+    drop(this);
+}
+
+/// Returns the amount of segments of the comparison to visualize.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_comparison_segments_len(
+    this: &TimelineComponentState,
+) -> usize {
+    this.comparison_segments.len()
+}
+
+/// Accesses the width of the comparison's segment specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_comparison_segment_width(
+    this: &TimelineComponentState,
+    index: usize,
+) -> f32 {
+    this.comparison_segments[index]
+}
+
+/// Returns the amount of completed segments of the current attempt to
+/// visualize.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_attempt_segments_len(
+    this: &TimelineComponentState,
+) -> usize {
+    this.attempt_segments.len()
+}
+
+/// Accesses the width of the current attempt's segment specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_attempt_segment_width(
+    this: &TimelineComponentState,
+    index: usize,
+) -> f32 {
+    this.attempt_segments[index].width
+}
+
+/// The semantic coloring information the current attempt's segment specified
+/// carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_attempt_segment_semantic_color(
+    this: &TimelineComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.attempt_segments[index].semantic_color).unwrap())
+}
+
+/// Describes whether the segment that is currently being run is supposed to
+/// be visualized after the completed segments.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_has_live_segment(this: &TimelineComponentState) -> bool {
+    this.live_segment.is_some()
+}
+
+/// Accesses the width of the segment that is currently being run. This is 0
+/// if there is no such segment.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_live_segment_width(this: &TimelineComponentState) -> f32 {
+    this.live_segment.as_ref().map_or(0.0, |s| s.width)
+}
+
+/// The semantic coloring information the segment that is currently being run
+/// carries. This is `Default` if there is no such segment.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_live_segment_semantic_color(
+    this: &TimelineComponentState,
+) -> *const c_char {
+    let semantic_color = this
+        .live_segment
+        .as_ref()
+        .map(|s| s.semantic_color)
+        .unwrap_or_default();
+    output_vec(|f| write!(f, "{semantic_color:?}").unwrap())
+}
+
+/// The height of the component.
+#[no_mangle]
+pub extern "C" fn TimelineComponentState_height(this: &TimelineComponentState) -> u32 {
+    this.height
+}
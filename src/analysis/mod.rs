@@ -5,6 +5,7 @@ pub mod current_pace;
 pub mod delta;
 pub mod pb_chance;
 pub mod possible_time_save;
+pub mod sessions;
 mod skill_curve;
 pub mod state_helper;
 pub mod sum_of_segments;
@@ -0,0 +1,236 @@
+//! Provides functionality to group the Attempt History of a Run into sessions
+//! and calendar periods. A session is a sequence of attempts where the gap
+//! between one attempt ending and the next one starting is never larger than a
+//! certain amount of time. For each session, day and week, statistics like the
+//! amount of attempts, the playtime, the completion rate and the best time are
+//! calculated. Attempts that don't store when they were started, which is the
+//! case for attempts of splits files from before LiveSplit 1.6, can't be
+//! grouped and are ignored.
+
+use super::total_playtime::attempt_playtime;
+use crate::{
+    platform::{prelude::*, to_local, utc_now},
+    AtomicDateTime, Attempt, Run, TimeSpan, TimingMethod,
+};
+use time::{Date, Duration};
+
+/// The default maximum gap between two attempts of the same session. If the
+/// runner doesn't start a new attempt within an hour, a new session begins.
+pub const DEFAULT_MAX_GAP: Duration = Duration::hours(1);
+
+/// The statistics of a group of attempts, like all the attempts of a session or
+/// a day.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Statistics {
+    /// The amount of attempts.
+    pub attempts: u32,
+    /// The amount of attempts that were completed.
+    pub completed: u32,
+    /// The total playtime of all the attempts, excluding the time the attempts
+    /// were paused for.
+    pub playtime: TimeSpan,
+    /// The best final time of all the completed attempts. This is `None` if no
+    /// attempt was completed.
+    pub best_time: Option<TimeSpan>,
+}
+
+impl Statistics {
+    /// Returns the ratio of completed attempts to all attempts as a value
+    /// between 0 and 1. If there are no attempts, 0 is returned.
+    pub fn completion_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.completed as f64 / self.attempts as f64
+        }
+    }
+
+    fn add(&mut self, run: &Run, attempt: &Attempt, method: TimingMethod) {
+        let time = attempt.time()[method];
+        self.merge(&Statistics {
+            attempts: 1,
+            completed: time.is_some() as u32,
+            playtime: attempt_playtime(run, attempt),
+            best_time: time,
+        });
+    }
+
+    fn merge(&mut self, other: &Statistics) {
+        self.attempts += other.attempts;
+        self.completed += other.completed;
+        self.playtime += other.playtime;
+        if let Some(time) = other.best_time {
+            if self.best_time.map_or(true, |best| time < best) {
+                self.best_time = Some(time);
+            }
+        }
+    }
+}
+
+/// A session is a sequence of attempts where the gap between one attempt
+/// ending and the next one starting is never larger than a certain amount of
+/// time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Session {
+    /// The point in time the first attempt of the session was started at.
+    pub started: AtomicDateTime,
+    /// The point in time the last attempt of the session ended at.
+    pub ended: AtomicDateTime,
+    /// The statistics of all the attempts of the session.
+    pub statistics: Statistics,
+}
+
+impl Session {
+    /// Returns the duration of the session from the start of its first attempt
+    /// to the end of its last attempt. Unlike the playtime, this includes the
+    /// time spent between the attempts.
+    pub fn duration(&self) -> TimeSpan {
+        self.ended - self.started
+    }
+}
+
+/// The statistics of all the attempts that were started on a certain day in
+/// the local time zone.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Day {
+    /// The date of the day.
+    pub date: Date,
+    /// The statistics of all the attempts of the day.
+    pub statistics: Statistics,
+}
+
+/// The statistics of all the attempts that were started in a certain week in
+/// the local time zone. Weeks start on Monday.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Week {
+    /// The date of the Monday the week starts with.
+    pub first_day: Date,
+    /// The statistics of all the attempts of the week.
+    pub statistics: Statistics,
+}
+
+/// Returns the attempts that store when they were started, sorted by when they
+/// were started.
+fn started_attempts(run: &Run) -> Vec<(AtomicDateTime, &Attempt)> {
+    let mut attempts: Vec<_> = run
+        .attempt_history()
+        .iter()
+        .filter_map(|a| Some((a.started()?, a)))
+        .collect();
+    attempts.sort_by_key(|(started, _)| started.time);
+    attempts
+}
+
+fn ended(started: AtomicDateTime, attempt: &Attempt) -> AtomicDateTime {
+    attempt.ended().unwrap_or_else(|| {
+        let duration = attempt.duration().unwrap_or_default();
+        AtomicDateTime::new(
+            started.time + duration.to_duration(),
+            started.synced_with_atomic_clock,
+        )
+    })
+}
+
+/// Groups the Attempt History of the Run into sessions. A new session begins
+/// whenever more than `max_gap` passed between one attempt ending and the next
+/// one starting. The timing method is used for determining the best time of
+/// each session. The sessions are returned in chronological order.
+pub fn sessions(run: &Run, method: TimingMethod, max_gap: Duration) -> Vec<Session> {
+    let mut sessions: Vec<Session> = Vec::new();
+
+    for (started, attempt) in started_attempts(run) {
+        let ended = ended(started, attempt);
+
+        let session = match sessions.last_mut() {
+            Some(session) if started.time - session.ended.time <= max_gap => session,
+            _ => {
+                sessions.push(Session {
+                    started,
+                    ended,
+                    statistics: Statistics::default(),
+                });
+                sessions.last_mut().unwrap()
+            }
+        };
+
+        if ended.time > session.ended.time {
+            session.ended = ended;
+        }
+        session.statistics.add(run, attempt, method);
+    }
+
+    sessions
+}
+
+/// Groups the Attempt History of the Run by the local calendar day the
+/// attempts were started on. Only days with at least one attempt are returned,
+/// in chronological order. This is suitable for visualizing the attempts as a
+/// calendar heatmap.
+pub fn days(run: &Run, method: TimingMethod) -> Vec<Day> {
+    let mut days: Vec<Day> = Vec::new();
+
+    for (started, attempt) in started_attempts(run) {
+        let date = to_local(started.time).date();
+
+        let day = match days.last_mut() {
+            Some(day) if day.date == date => day,
+            _ => {
+                days.push(Day {
+                    date,
+                    statistics: Statistics::default(),
+                });
+                days.last_mut().unwrap()
+            }
+        };
+
+        day.statistics.add(run, attempt, method);
+    }
+
+    days
+}
+
+/// Groups the Attempt History of the Run by the local calendar week the
+/// attempts were started in. Weeks start on Monday. Only weeks with at least
+/// one attempt are returned, in chronological order.
+pub fn weeks(run: &Run, method: TimingMethod) -> Vec<Week> {
+    let mut weeks: Vec<Week> = Vec::new();
+
+    for day in days(run, method) {
+        let first_day =
+            day.date - Duration::days(day.date.weekday().number_days_from_monday() as i64);
+
+        let week = match weeks.last_mut() {
+            Some(week) if week.first_day == first_day => week,
+            _ => {
+                weeks.push(Week {
+                    first_day,
+                    statistics: Statistics::default(),
+                });
+                weeks.last_mut().unwrap()
+            }
+        };
+
+        week.statistics.merge(&day.statistics);
+    }
+
+    weeks
+}
+
+/// Calculates the statistics of all the attempts that were started on the
+/// given local calendar day.
+pub fn day(run: &Run, method: TimingMethod, date: Date) -> Statistics {
+    let mut statistics = Statistics::default();
+    for (started, attempt) in started_attempts(run) {
+        if to_local(started.time).date() == date {
+            statistics.add(run, attempt, method);
+        }
+    }
+    statistics
+}
+
+/// Calculates the statistics of all the attempts that were started today in
+/// the local time zone. The attempt that is currently in progress is not
+/// included, as it is not part of the Attempt History yet.
+pub fn today(run: &Run, method: TimingMethod) -> Statistics {
+    day(run, method, to_local(utc_now()).date())
+}
@@ -1 +1,2 @@
 mod empty_run;
+mod sessions;
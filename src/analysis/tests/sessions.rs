@@ -0,0 +1,101 @@
+use super::super::sessions::{self, DEFAULT_MAX_GAP};
+use crate::{platform::to_local, AtomicDateTime, Run, Time, TimeSpan, TimingMethod};
+use time::{Date, Duration, Month, Weekday};
+
+// The attempts are grouped by the local calendar, so the fixtures are placed
+// relative to Monday, 2022-01-03 12:00:00 in the local time zone, regardless
+// of what that time zone is.
+fn at(seconds: i64) -> Option<AtomicDateTime> {
+    let local = Date::from_calendar_date(2022, Month::January, 3)
+        .unwrap()
+        .with_hms(12, 0, 0)
+        .unwrap()
+        + Duration::seconds(seconds);
+    let offset = to_local(local.assume_utc()).offset();
+    Some(AtomicDateTime::new(local.assume_offset(offset), false))
+}
+
+fn run() -> Run {
+    let s = TimeSpan::from_seconds;
+    let mut run = Run::new();
+
+    // First session, two resets and a completed run.
+    run.add_attempt(Time::new(), at(0), at(60), None);
+    run.add_attempt(Time::new(), at(120), at(240), Some(s(20.0)));
+    run.add_attempt(
+        Time::new().with_real_time(Some(s(600.0))),
+        at(300),
+        at(900),
+        None,
+    );
+
+    // Second session, two hours later on the same day.
+    run.add_attempt(
+        Time::new().with_real_time(Some(s(500.0))),
+        at(8100),
+        at(8600),
+        None,
+    );
+
+    // A week later.
+    run.add_attempt(Time::new(), at(7 * 86400), at(7 * 86400 + 30), None);
+
+    // Attempts without a start can't be grouped.
+    run.add_attempt(Time::new(), None, None, None);
+
+    run
+}
+
+#[test]
+fn sessions_are_split_by_gaps() {
+    let s = TimeSpan::from_seconds;
+    let sessions = sessions::sessions(&run(), TimingMethod::RealTime, DEFAULT_MAX_GAP);
+
+    assert_eq!(sessions.len(), 3);
+
+    let first = &sessions[0];
+    assert_eq!(first.started, at(0).unwrap());
+    assert_eq!(first.ended, at(900).unwrap());
+    assert_eq!(first.duration(), s(900.0));
+    assert_eq!(first.statistics.attempts, 3);
+    assert_eq!(first.statistics.completed, 1);
+    assert_eq!(first.statistics.playtime, s(60.0 + 100.0 + 600.0));
+    assert_eq!(first.statistics.best_time, Some(s(600.0)));
+    assert!((first.statistics.completion_rate() - 1.0 / 3.0).abs() < 1e-9);
+
+    assert_eq!(sessions[1].statistics.attempts, 1);
+    assert_eq!(sessions[1].statistics.best_time, Some(s(500.0)));
+
+    assert_eq!(sessions[2].statistics.completed, 0);
+    assert_eq!(sessions[2].statistics.best_time, None);
+}
+
+#[test]
+fn days_and_weeks_group_by_calendar() {
+    let s = TimeSpan::from_seconds;
+    let run = run();
+
+    let days = sessions::days(&run, TimingMethod::RealTime);
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0].statistics.attempts, 4);
+    assert_eq!(days[0].statistics.best_time, Some(s(500.0)));
+    assert_eq!(days[1].statistics.attempts, 1);
+
+    let weeks = sessions::weeks(&run, TimingMethod::RealTime);
+    assert_eq!(weeks.len(), 2);
+    assert_eq!(weeks[0].first_day.weekday(), Weekday::Monday);
+    assert_eq!(weeks[0].statistics, days[0].statistics);
+    assert_eq!(weeks[1].statistics, days[1].statistics);
+
+    assert_eq!(
+        sessions::day(&run, TimingMethod::RealTime, days[0].date),
+        days[0].statistics
+    );
+}
+
+#[test]
+fn empty_run_has_no_sessions() {
+    let run = Run::new();
+    assert!(sessions::sessions(&run, TimingMethod::RealTime, DEFAULT_MAX_GAP).is_empty());
+    assert_eq!(sessions::today(&run, TimingMethod::RealTime).attempts, 0);
+}
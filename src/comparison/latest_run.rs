@@ -33,30 +33,41 @@ fn generate(segments: &mut [Segment], method: TimingMethod) {
     }
 
     if let Some(attempt_id) = attempt_id {
-        let mut remaining_segments = segments.iter_mut();
+        generate_for_attempt(segments, NAME, attempt_id, method);
+    }
+}
 
-        let mut total_time = TimeSpan::zero();
-        for segment in remaining_segments.by_ref() {
-            let segment_time = segment.segment_history().get(attempt_id).map(|t| t[method]);
+/// Reconstructs the split times of the attempt with the given index from the
+/// Segment History and stores them in the comparison with the name provided.
+pub(super) fn generate_for_attempt(
+    segments: &mut [Segment],
+    comparison: &str,
+    attempt_id: i32,
+    method: TimingMethod,
+) {
+    let mut remaining_segments = segments.iter_mut();
 
-            let split_time = match segment_time {
-                Some(Some(segment_time)) => {
-                    total_time += segment_time;
-                    Some(total_time)
-                }
-                Some(None) => None,
-                None => {
-                    segment.comparison_mut(NAME)[method] = None;
-                    break;
-                }
-            };
+    let mut total_time = TimeSpan::zero();
+    for segment in remaining_segments.by_ref() {
+        let segment_time = segment.segment_history().get(attempt_id).map(|t| t[method]);
 
-            segment.comparison_mut(NAME)[method] = split_time;
-        }
+        let split_time = match segment_time {
+            Some(Some(segment_time)) => {
+                total_time += segment_time;
+                Some(total_time)
+            }
+            Some(None) => None,
+            None => {
+                segment.comparison_mut(comparison)[method] = None;
+                break;
+            }
+        };
 
-        for segment in remaining_segments {
-            segment.comparison_mut(NAME)[method] = None;
-        }
+        segment.comparison_mut(comparison)[method] = split_time;
+    }
+
+    for segment in remaining_segments {
+        segment.comparison_mut(comparison)[method] = None;
     }
 }
 
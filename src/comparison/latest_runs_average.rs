@@ -0,0 +1,121 @@
+//! Defines the Comparison Generator for calculating the average of the split
+//! times of the most recent completed attempts. Unlike the Latest Run, which
+//! only reflects a single attempt, this comparison smooths out the mistakes of
+//! the individual attempts, while still being a moving target that follows the
+//! runner's current performance.
+
+use super::{ComparisonGenerator, GeneratorSettings};
+use crate::{platform::prelude::*, Attempt, Segment, TimeSpan, TimingMethod};
+
+/// The Comparison Generator for calculating the average of the split times of
+/// the most recent completed attempts. The name of the comparison is derived
+/// from the amount of completed attempts that are considered, so considering
+/// the last 10 completed attempts results in a comparison called `Latest
+/// Average 10`.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::comparison::{ComparisonGenerator, LatestRunsAverage};
+///
+/// let generator = LatestRunsAverage::new(10);
+/// assert_eq!(generator.name(), "Latest Average 10");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LatestRunsAverage {
+    runs: u32,
+    name: String,
+}
+
+/// The prefix of the name of this comparison. The name is followed by the
+/// amount of completed attempts that are considered.
+pub const NAME: &str = "Latest Average";
+/// The prefix of the short name of this comparison. Suitable for situations
+/// where not a lot of space for text is available. The short name is followed
+/// by the amount of completed attempts that are considered.
+pub const SHORT_NAME: &str = "Avg";
+
+impl LatestRunsAverage {
+    /// Creates a new Latest Runs Average Comparison Generator that considers
+    /// the given amount of most recent completed attempts.
+    pub fn new(runs: u32) -> Self {
+        Self {
+            runs,
+            name: format!("{NAME} {runs}"),
+        }
+    }
+
+    /// Accesses the amount of most recent completed attempts that are
+    /// considered.
+    pub const fn runs(&self) -> u32 {
+        self.runs
+    }
+}
+
+/// Tries to shorten the name of a Latest Runs Average comparison. `Latest
+/// Average 10` is shortened to `Avg10`. If the name is not the name of a Latest
+/// Runs Average comparison, `None` is returned.
+pub(super) fn try_shorten(comparison: &str) -> Option<String> {
+    super::try_shorten_numbered(comparison, NAME, SHORT_NAME)
+}
+
+fn generate(
+    segments: &mut [Segment],
+    name: &str,
+    attempts: &[Attempt],
+    indices: &mut Vec<i32>,
+    split_times: &mut Vec<(f64, u32)>,
+    runs: u32,
+    method: TimingMethod,
+) {
+    super::collect_last_attempts(attempts, runs, |a| a.time()[method].is_some(), indices);
+
+    split_times.clear();
+    split_times.resize(segments.len(), (0.0, 0));
+
+    for &id in indices.iter() {
+        let mut total_time = TimeSpan::zero();
+        for (segment, (sum, count)) in segments.iter().zip(split_times.iter_mut()) {
+            // Skipped splits don't have a split time, but their segment time is
+            // included in the segment time of the next split.
+            if let Some(segment_time) = segment.segment_history().get(id).and_then(|t| t[method]) {
+                total_time += segment_time;
+                *sum += total_time.total_seconds();
+                *count += 1;
+            }
+        }
+    }
+
+    for (segment, &(sum, count)) in segments.iter_mut().zip(split_times.iter()) {
+        segment.comparison_mut(name)[method] = if count != 0 {
+            Some(TimeSpan::from_seconds(sum / count as f64))
+        } else {
+            None
+        };
+    }
+}
+
+impl ComparisonGenerator for LatestRunsAverage {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let (indices, split_times) = (&mut Vec::new(), &mut Vec::new());
+        for method in TimingMethod::all() {
+            generate(
+                segments,
+                &self.name,
+                attempts,
+                indices,
+                split_times,
+                self.runs,
+                method,
+            );
+        }
+    }
+
+    fn settings(&self) -> Option<GeneratorSettings> {
+        Some(GeneratorSettings::LatestRunsAverage(self.clone()))
+    }
+}
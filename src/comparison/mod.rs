@@ -12,17 +12,26 @@ pub mod best_segments;
 pub mod best_split_times;
 pub mod goal;
 pub mod latest_run;
+pub mod latest_runs_average;
 pub mod median_segments;
 pub mod none;
+pub mod past_attempt;
+pub mod percentile_segments;
+pub mod recent_best_segments;
+pub mod rolling;
 pub mod worst_segments;
 
 pub use self::{
     average_segments::AverageSegments, balanced_pb::BalancedPB, best_segments::BestSegments,
-    best_split_times::BestSplitTimes, latest_run::LatestRun, median_segments::MedianSegments,
-    none::None, worst_segments::WorstSegments,
+    best_split_times::BestSplitTimes, latest_run::LatestRun,
+    latest_runs_average::LatestRunsAverage, median_segments::MedianSegments, none::None,
+    past_attempt::PastAttempt, percentile_segments::PercentileSegments,
+    recent_best_segments::RecentBestSegments, rolling::RollingComparison,
+    worst_segments::WorstSegments,
 };
 
 use crate::{platform::prelude::*, Attempt, Segment, Timer};
+use alloc::borrow::Cow;
 use core::fmt::Debug;
 
 /// Race comparisons are prefixed by `[Race]`.
@@ -61,6 +70,43 @@ pub trait ComparisonGenerator: Debug + Sync + Send + ComparisonGeneratorClone {
     /// provided, in case the comparison generator requires information from the
     /// previous attempts.
     fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]);
+    /// Returns the settings of the comparison generator if it is configured by
+    /// the user. These settings are stored in the splits file, so that the
+    /// comparison generator can be recreated when the splits file is loaded
+    /// again. Comparison generators that don't have any settings return `None`.
+    fn settings(&self) -> Option<GeneratorSettings> {
+        Option::None
+    }
+}
+
+/// The settings of a configurable Comparison Generator. Unlike the default
+/// Comparison Generators, configurable Comparison Generators are registered per
+/// Run and are stored in its splits file, so they survive reloading the splits.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeneratorSettings {
+    /// The settings of a [`RollingComparison`].
+    Rolling(RollingComparison),
+    /// The settings of a [`PercentileSegments`] comparison.
+    Percentile(PercentileSegments),
+    /// The settings of a [`RecentBestSegments`] comparison.
+    RecentBestSegments(RecentBestSegments),
+    /// The settings of a [`LatestRunsAverage`] comparison.
+    LatestRunsAverage(LatestRunsAverage),
+    /// The settings of a [`PastAttempt`] comparison.
+    PastAttempt(PastAttempt),
+}
+
+impl GeneratorSettings {
+    /// Creates the Comparison Generator described by these settings.
+    pub fn into_generator(self) -> Box<dyn ComparisonGenerator> {
+        match self {
+            GeneratorSettings::Rolling(generator) => Box::new(generator),
+            GeneratorSettings::Percentile(generator) => Box::new(generator),
+            GeneratorSettings::RecentBestSegments(generator) => Box::new(generator),
+            GeneratorSettings::LatestRunsAverage(generator) => Box::new(generator),
+            GeneratorSettings::PastAttempt(generator) => Box::new(generator),
+        }
+    }
 }
 
 /// Provides the ability to clone a Comparison Generator, even when it is stored
@@ -103,7 +149,18 @@ pub fn default_generators() -> Vec<Box<dyn ComparisonGenerator>> {
 /// Tries to shorten a comparison name. If the name of the comparison matches one
 /// of the comparison generators, the short name of that comparison generator is
 /// returned.
+///
+/// This can only return short names that are known ahead of time, so it can't
+/// shorten the names of comparisons that are derived from their settings, such
+/// as `Percentile 90`. Use [`try_shorten_dynamic`] instead, which can.
+#[deprecated(note = "Use `try_shorten_dynamic`, which also shortens the names of \
+    comparisons that are derived from their settings.")]
 pub fn try_shorten(comparison: &str) -> Option<&'static str> {
+    try_shorten_static(comparison)
+}
+
+/// Shortens the names that are known ahead of time.
+fn try_shorten_static(comparison: &str) -> Option<&'static str> {
     Some(match comparison {
         personal_best::NAME => personal_best::SHORT_NAME,
         world_record::NAME => world_record::SHORT_NAME,
@@ -122,10 +179,68 @@ pub fn try_shorten(comparison: &str) -> Option<&'static str> {
 /// Shortens a comparison name. If the name of the comparison matches one of the
 /// comparison generators, the short name of that comparison generator is
 /// returned. Otherwise, the comparison name is returned without being
-/// shortened. Additional shortening logic for other comparison names may happen
-/// in the future.
+/// shortened.
+///
+/// This can only return short names that are known ahead of time, as the
+/// shortened name borrows from the name provided. So it can't shorten the names
+/// of comparisons that are derived from their settings, such as
+/// `Percentile 90`. Use [`shorten_dynamic`] instead, which can.
+#[deprecated(note = "Use `shorten_dynamic`, which also shortens the names of \
+    comparisons that are derived from their settings.")]
 pub fn shorten(comparison: &str) -> &str {
-    try_shorten(comparison).unwrap_or(comparison)
+    try_shorten_static(comparison).unwrap_or(comparison)
+}
+
+/// Tries to shorten a comparison name. If the name of the comparison matches
+/// one of the comparison generators, the short name of that comparison
+/// generator is returned. This includes the names of comparisons that are
+/// derived from their settings, such as `Percentile 90`, which is shortened to
+/// `P90`.
+pub fn try_shorten_dynamic(comparison: &str) -> Option<Cow<'static, str>> {
+    try_shorten_static(comparison)
+        .map(Cow::Borrowed)
+        .or_else(|| {
+            percentile_segments::try_shorten(comparison)
+                .or_else(|| latest_runs_average::try_shorten(comparison))
+            .or_else(|| recent_best_segments::try_shorten(comparison))
+                .map(Cow::Owned)
+        })
+}
+
+/// Shortens a comparison name. If the name of the comparison matches one of the
+/// comparison generators, the short name of that comparison generator is
+/// returned. This includes the names of comparisons that are derived from
+/// their settings, such as `Percentile 90`, which is shortened to `P90`.
+/// Otherwise, the comparison name is returned without being shortened.
+pub fn shorten_dynamic(comparison: &str) -> Cow<'_, str> {
+    try_shorten_dynamic(comparison).unwrap_or(Cow::Borrowed(comparison))
+}
+
+/// Shortens the name of a comparison that consists of the name provided,
+/// followed by a number, such as `Percentile 10`, which is shortened to `P10`
+/// with the short name `P`.
+fn try_shorten_numbered(comparison: &str, name: &str, short_name: &str) -> Option<String> {
+    let number = comparison.strip_prefix(name)?.strip_prefix(' ')?;
+    if !number.parse::<f64>().ok()?.is_finite() {
+        return Option::None;
+    }
+    Some(format!("{short_name}{number}"))
+}
+
+/// Collects the indices of the most recent attempts that match the filter
+/// provided into the buffer, sorted in ascending order. At most `count`
+/// indices are collected.
+fn collect_last_attempts(
+    attempts: &[Attempt],
+    count: u32,
+    filter: impl Fn(&Attempt) -> bool,
+    indices: &mut Vec<i32>,
+) {
+    indices.clear();
+    indices.extend(attempts.iter().filter(|a| filter(a)).map(Attempt::index));
+    indices.sort_unstable();
+    let skip = indices.len().saturating_sub(count as usize);
+    indices.drain(..skip);
 }
 
 /// Helper function for accessing either the given comparison or a Timer's
@@ -0,0 +1,69 @@
+//! Defines the Comparison Generator for racing against an arbitrary attempt
+//! from the Attempt History. Using the Segment History, this comparison
+//! reconstructs the splits of the chosen attempt, just like the Latest Run
+//! does for the most recent attempt. This allows racing against any previous
+//! attempt, including completed attempts that weren't a Personal Best.
+
+use super::{latest_run::generate_for_attempt, ComparisonGenerator, GeneratorSettings};
+use crate::{platform::prelude::*, Attempt, Segment, TimingMethod};
+
+/// The Comparison Generator for racing against an arbitrary attempt from the
+/// Attempt History. The attempt is chosen by its index in the Attempt History.
+/// If the Segment History doesn't contain any segment times for that attempt
+/// anymore, the comparison is empty.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::comparison::{ComparisonGenerator, PastAttempt};
+///
+/// let generator = PastAttempt::new(42);
+/// assert_eq!(generator.name(), "Attempt #42");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PastAttempt {
+    attempt_index: i32,
+    name: String,
+}
+
+/// The prefix of the default name of this comparison. The name is followed by
+/// the index of the attempt.
+pub const NAME: &str = "Attempt #";
+
+impl PastAttempt {
+    /// Creates a new Past Attempt Comparison Generator for the attempt with the
+    /// given index. The comparison is named after the index of the attempt.
+    pub fn new(attempt_index: i32) -> Self {
+        Self::with_name(attempt_index, format!("{NAME}{attempt_index}"))
+    }
+
+    /// Creates a new Past Attempt Comparison Generator for the attempt with the
+    /// given index and the given name for the comparison.
+    pub fn with_name<S: Into<String>>(attempt_index: i32, name: S) -> Self {
+        Self {
+            attempt_index,
+            name: name.into(),
+        }
+    }
+
+    /// Accesses the index of the attempt in the Attempt History.
+    pub const fn attempt_index(&self) -> i32 {
+        self.attempt_index
+    }
+}
+
+impl ComparisonGenerator for PastAttempt {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        for method in TimingMethod::all() {
+            generate_for_attempt(segments, &self.name, self.attempt_index, method);
+        }
+    }
+
+    fn settings(&self) -> Option<GeneratorSettings> {
+        Some(GeneratorSettings::PastAttempt(self.clone()))
+    }
+}
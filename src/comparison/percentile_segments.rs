@@ -0,0 +1,122 @@
+//! Defines the Comparison Generator for calculating a comparison that samples
+//! the runner's skill curve at a fixed percentile. A low percentile results in
+//! an optimistic comparison that is close to the Best Segments, while a high
+//! percentile results in a pessimistic comparison that is close to the Worst
+//! Segments. A percentile of 50% roughly corresponds to the Median Segments.
+//!
+//! The algorithm is the same one the Balanced PB uses, except that the
+//! percentile is chosen by the runner instead of being determined by the
+//! Personal Best.
+
+use super::{ComparisonGenerator, GeneratorSettings};
+use crate::{
+    analysis::SkillCurve,
+    platform::{math::f64::floor, prelude::*},
+    Attempt, Segment, TimeSpan, TimingMethod,
+};
+
+/// The Comparison Generator for calculating a comparison that samples the
+/// runner's skill curve at a fixed percentile. A low percentile results in an
+/// optimistic comparison that is close to the Best Segments, while a high
+/// percentile results in a pessimistic comparison that is close to the Worst
+/// Segments. The name of the comparison is derived from the percentile, so a
+/// percentile of 0.1 results in a comparison called `Percentile 10`.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::comparison::{ComparisonGenerator, PercentileSegments};
+///
+/// let generator = PercentileSegments::new(0.1);
+/// assert_eq!(generator.name(), "Percentile 10");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PercentileSegments {
+    percentile: f64,
+    name: String,
+}
+
+/// The prefix of the name of this comparison. The name is followed by the
+/// percentile.
+pub const NAME: &str = "Percentile";
+/// The prefix of the short name of this comparison. Suitable for situations
+/// where not a lot of space for text is available. The short name is followed
+/// by the percentile.
+pub const SHORT_NAME: &str = "P";
+
+impl PercentileSegments {
+    /// Creates a new Percentile Segments Comparison Generator for the given
+    /// percentile. The percentile is specified as a value between 0 and 1 and
+    /// is clamped to that range. A percentile that is not a number is treated
+    /// as the median, so a percentile of 0.5.
+    pub fn new(percentile: f64) -> Self {
+        let percentile = if percentile.is_nan() {
+            0.5
+        } else {
+            percentile.clamp(0.0, 1.0)
+        };
+        let percent = floor(percentile * 10_000.0 + 0.5) / 100.0;
+        Self {
+            percentile,
+            name: format!("{NAME} {percent}"),
+        }
+    }
+
+    /// Accesses the percentile the skill curve is sampled at. This is a value
+    /// between 0 and 1.
+    pub const fn percentile(&self) -> f64 {
+        self.percentile
+    }
+}
+
+/// Tries to shorten the name of a Percentile Segments comparison. `Percentile
+/// 10` is shortened to `P10`. If the name is not the name of a Percentile
+/// Segments comparison, `None` is returned.
+pub(super) fn try_shorten(comparison: &str) -> Option<String> {
+    super::try_shorten_numbered(comparison, NAME, SHORT_NAME)
+}
+
+fn generate(
+    segments: &mut [Segment],
+    percentile: f64,
+    comparison: &str,
+    skill_curve: &mut SkillCurve,
+    method: TimingMethod,
+) {
+    skill_curve.for_segments(segments, method);
+
+    let mut segments = segments.iter_mut();
+    for (segment, val) in segments
+        .by_ref()
+        .zip(skill_curve.iter_split_times_at_percentile(percentile, TimeSpan::zero()))
+    {
+        segment.comparison_mut(comparison)[method] = Some(val);
+    }
+    for segment in segments {
+        segment.comparison_mut(comparison)[method] = None;
+    }
+}
+
+impl ComparisonGenerator for PercentileSegments {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let mut skill_curve = SkillCurve::new();
+
+        for method in TimingMethod::all() {
+            generate(
+                segments,
+                self.percentile,
+                &self.name,
+                &mut skill_curve,
+                method,
+            );
+        }
+    }
+
+    fn settings(&self) -> Option<GeneratorSettings> {
+        Some(GeneratorSettings::Percentile(self.clone()))
+    }
+}
@@ -0,0 +1,114 @@
+//! Defines the Comparison Generator for calculating the best segments among
+//! the most recent attempts, also known as the Recent Golds. Unlike the Best
+//! Segments, which may have been achieved a long time ago and may be outdated,
+//! this comparison only considers the last few attempts. This results in a
+//! realistic, yet ambitious comparison that moves along with the runner's
+//! current performance.
+
+use super::{ComparisonGenerator, GeneratorSettings};
+use crate::{platform::prelude::*, Attempt, Segment, TimeSpan, TimingMethod};
+
+/// The Comparison Generator for calculating the best segments among the most
+/// recent attempts, also known as the Recent Golds. The name of the comparison
+/// is derived from the amount of attempts that are considered, so considering
+/// the last 20 attempts results in a comparison called `Best of Last 20`.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::comparison::{ComparisonGenerator, RecentBestSegments};
+///
+/// let generator = RecentBestSegments::new(20);
+/// assert_eq!(generator.name(), "Best of Last 20");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecentBestSegments {
+    attempts: u32,
+    name: String,
+}
+
+/// The prefix of the name of this comparison. The name is followed by the
+/// amount of attempts that are considered.
+pub const NAME: &str = "Best of Last";
+/// The prefix of the short name of this comparison. Suitable for situations
+/// where not a lot of space for text is available. The short name is followed
+/// by the amount of attempts that are considered.
+pub const SHORT_NAME: &str = "BoL";
+
+impl RecentBestSegments {
+    /// Creates a new Recent Best Segments Comparison Generator that considers
+    /// the given amount of most recent attempts.
+    pub fn new(attempts: u32) -> Self {
+        Self {
+            attempts,
+            name: format!("{NAME} {attempts}"),
+        }
+    }
+
+    /// Accesses the amount of most recent attempts that are considered.
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
+
+/// Tries to shorten the name of a Recent Best Segments comparison. `Best of
+/// Last 20` is shortened to `BoL20`. If the name is not the name of a Recent
+/// Best Segments comparison, `None` is returned.
+pub(super) fn try_shorten(comparison: &str) -> Option<String> {
+    super::try_shorten_numbered(comparison, NAME, SHORT_NAME)
+}
+
+fn generate(segments: &mut [Segment], name: &str, window: &[i32], method: TimingMethod) {
+    let mut accumulated = Some(TimeSpan::zero());
+
+    let mut previous_segment: Option<&Segment> = None;
+    for segment in segments {
+        if let Some(accumulated_val) = &mut accumulated {
+            let mut best = None;
+
+            for &(id, time) in segment.segment_history().iter_actual_runs() {
+                if let Some(time) = time[method] {
+                    if window.binary_search(&id).is_err() {
+                        continue;
+                    }
+
+                    // Skip all the combined segments
+                    let skip = catch! {
+                        previous_segment?.segment_history().get(id)?[method].is_none()
+                    }
+                    .unwrap_or(false);
+
+                    if !skip && best.map_or(true, |best| time < best) {
+                        best = Some(time);
+                    }
+                }
+            }
+
+            if let Some(best) = best {
+                *accumulated_val += best;
+            } else {
+                accumulated = None;
+            }
+        }
+        segment.comparison_mut(name)[method] = accumulated;
+        previous_segment = Some(&*segment);
+    }
+}
+
+impl ComparisonGenerator for RecentBestSegments {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let indices = &mut Vec::new();
+        super::collect_last_attempts(attempts, self.attempts, |_| true, indices);
+
+        generate(segments, &self.name, indices, TimingMethod::RealTime);
+        generate(segments, &self.name, indices, TimingMethod::GameTime);
+    }
+
+    fn settings(&self) -> Option<GeneratorSettings> {
+        Some(GeneratorSettings::RecentBestSegments(self.clone()))
+    }
+}
@@ -0,0 +1,286 @@
+//! Defines the Comparison Generator for calculating a comparison based on a
+//! rolling window of the most relevant attempts. Unlike the Average Segments
+//! and the Median Segments, which always consider the whole history with a
+//! fixed weighting, this comparison only considers the attempts within a
+//! configurable window, such as the last 20 attempts or all the attempts since
+//! a certain date, and combines their segment times with a configurable
+//! statistic.
+
+use super::{ComparisonGenerator, GeneratorSettings};
+use crate::{platform::prelude::*, Attempt, DateTime, Segment, TimeSpan, TimingMethod};
+
+/// The Comparison Generator for calculating a comparison based on a rolling
+/// window of attempts. The window determines which attempts are considered,
+/// while the statistic determines how the segment times of those attempts are
+/// combined into the comparison's segment times. Each Rolling Comparison has
+/// its own name, so multiple of them can be used by the same Run. They are
+/// stored in the splits file, so they survive reloading the splits.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::comparison::rolling::{RollingComparison, Statistic, Window};
+/// use livesplit_core::Run;
+///
+/// let mut run = Run::new();
+/// run.comparison_generators_mut().push(Box::new(RollingComparison::new(
+///     "Last 20 Median",
+///     Window::LastAttempts(20),
+///     Statistic::Median,
+/// )));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingComparison {
+    name: String,
+    window: Window,
+    completed_runs_only: bool,
+    statistic: Statistic,
+}
+
+/// Determines which attempts are considered by a Rolling Comparison.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Window {
+    /// All the attempts in the history are considered.
+    AllAttempts,
+    /// Only the given amount of most recent attempts is considered.
+    LastAttempts(u32),
+    /// Only the attempts that were started at or after the given point in time
+    /// are considered.
+    Since(DateTime),
+}
+
+/// Determines how the segment times of the attempts within the window are
+/// combined into a single segment time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Statistic {
+    /// The arithmetic mean of the segment times.
+    Mean,
+    /// The median of the segment times.
+    Median,
+    /// The segment time at the given percentile, which is specified as a value
+    /// between 0 and 1. A low percentile results in an optimistic comparison,
+    /// while a high percentile results in a pessimistic comparison.
+    Percentile(f64),
+    /// The arithmetic mean of the segment times after discarding the given
+    /// fraction of the fastest and the same fraction of the slowest segment
+    /// times. The fraction is specified as a value between 0 and 0.5.
+    TrimmedMean(f64),
+}
+
+/// The name of the comparison if no name was chosen for it.
+pub const NAME: &str = "Rolling Window";
+
+impl RollingComparison {
+    /// Creates a new Rolling Comparison with the given name, which considers
+    /// the attempts within the window provided and combines their segment
+    /// times with the statistic provided.
+    pub fn new<S: Into<String>>(name: S, window: Window, statistic: Statistic) -> Self {
+        Self {
+            name: name.into(),
+            window,
+            completed_runs_only: false,
+            statistic,
+        }
+    }
+
+    /// Sets the name of the comparison.
+    pub fn set_name<S: Into<String>>(&mut self, name: S) {
+        self.name = name.into();
+    }
+
+    /// Accesses the window that determines which attempts are considered.
+    pub const fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Sets the window that determines which attempts are considered.
+    pub fn set_window(&mut self, window: Window) {
+        self.window = window;
+    }
+
+    /// Returns whether only the attempts that were completed are considered.
+    pub const fn completed_runs_only(&self) -> bool {
+        self.completed_runs_only
+    }
+
+    /// Sets whether only the attempts that were completed are considered. The
+    /// window is then applied to the completed attempts only, so the last 20
+    /// attempts would be the last 20 completed attempts.
+    pub fn set_completed_runs_only(&mut self, completed_runs_only: bool) {
+        self.completed_runs_only = completed_runs_only;
+    }
+
+    /// Builder-style variant of
+    /// [`set_completed_runs_only`](Self::set_completed_runs_only).
+    pub const fn with_completed_runs_only(mut self, completed_runs_only: bool) -> Self {
+        self.completed_runs_only = completed_runs_only;
+        self
+    }
+
+    /// Accesses the statistic used for combining the segment times.
+    pub const fn statistic(&self) -> Statistic {
+        self.statistic
+    }
+
+    /// Sets the statistic used for combining the segment times.
+    pub fn set_statistic(&mut self, statistic: Statistic) {
+        self.statistic = statistic;
+    }
+
+    /// Collects the sorted indices of the attempts within the window. If all
+    /// the attempts are to be considered, `false` is returned and the indices
+    /// are left empty.
+    fn collect_attempts(
+        &self,
+        attempts: &[Attempt],
+        method: TimingMethod,
+        indices: &mut Vec<i32>,
+    ) -> bool {
+        indices.clear();
+
+        let is_in_window = |a: &Attempt| !self.completed_runs_only || a.time()[method].is_some();
+
+        match self.window {
+            Window::AllAttempts => {
+                if !self.completed_runs_only {
+                    return false;
+                }
+                indices.extend(
+                    attempts
+                        .iter()
+                        .filter(|a| is_in_window(a))
+                        .map(Attempt::index),
+                );
+            }
+            Window::LastAttempts(count) => {
+                super::collect_last_attempts(attempts, count, is_in_window, indices);
+            }
+            Window::Since(date_time) => {
+                indices.extend(
+                    attempts
+                        .iter()
+                        .filter(|a| {
+                            is_in_window(a)
+                                && a.started()
+                                    .map_or(false, |started| started.time >= date_time)
+                        })
+                        .map(Attempt::index),
+                );
+            }
+        }
+
+        indices.sort_unstable();
+        true
+    }
+}
+
+impl Statistic {
+    /// Combines the segment times provided into a single segment time. The
+    /// segment times are sorted in the process. Returns `None` if no segment
+    /// times are provided.
+    fn apply(self, times: &mut [TimeSpan]) -> Option<TimeSpan> {
+        if times.is_empty() {
+            return None;
+        }
+
+        times.sort_unstable();
+
+        Some(match self {
+            Statistic::Mean => mean(times),
+            Statistic::Median => percentile(times, 0.5),
+            Statistic::Percentile(p) => percentile(times, p),
+            Statistic::TrimmedMean(fraction) => {
+                let fraction = fraction.clamp(0.0, 0.5);
+                let trimmed = (times.len() as f64 * fraction) as usize;
+                let trimmed = trimmed.min((times.len() - 1) / 2);
+                mean(&times[trimmed..times.len() - trimmed])
+            }
+        })
+    }
+}
+
+fn mean(times: &[TimeSpan]) -> TimeSpan {
+    let total: f64 = times.iter().map(|t| t.total_seconds()).sum();
+    TimeSpan::from_seconds(total / times.len() as f64)
+}
+
+/// Linearly interpolates between the closest ranks of the sorted segment
+/// times.
+fn percentile(sorted: &[TimeSpan], p: f64) -> TimeSpan {
+    let position = p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lower = position as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    let factor = position - lower as f64;
+    let (lower, upper) = (sorted[lower].total_seconds(), sorted[upper].total_seconds());
+    TimeSpan::from_seconds(lower + factor * (upper - lower))
+}
+
+fn generate(
+    segments: &mut [Segment],
+    name: &str,
+    statistic: Statistic,
+    window: Option<&[i32]>,
+    times: &mut Vec<TimeSpan>,
+    method: TimingMethod,
+) {
+    let mut accumulated = Some(TimeSpan::zero());
+
+    let mut previous_segment: Option<&Segment> = None;
+    for segment in segments {
+        if let Some(accumulated_val) = &mut accumulated {
+            times.clear();
+
+            for &(id, time) in segment.segment_history().iter_actual_runs() {
+                if let Some(time) = time[method] {
+                    if window.map_or(false, |w| w.binary_search(&id).is_err()) {
+                        continue;
+                    }
+
+                    // Skip all the combined segments
+                    let skip = catch! {
+                        previous_segment?.segment_history().get(id)?[method].is_none()
+                    }
+                    .unwrap_or(false);
+
+                    if !skip {
+                        times.push(time);
+                    }
+                }
+            }
+
+            if let Some(time) = statistic.apply(times) {
+                *accumulated_val += time;
+            } else {
+                accumulated = None;
+            }
+        }
+        segment.comparison_mut(name)[method] = accumulated;
+        previous_segment = Some(&*segment);
+    }
+}
+
+impl ComparisonGenerator for RollingComparison {
+    fn name(&self) -> &str {
+        if self.name.is_empty() {
+            NAME
+        } else {
+            &self.name
+        }
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let (indices, times) = (&mut Vec::new(), &mut Vec::new());
+        for method in TimingMethod::all() {
+            let window = if self.collect_attempts(attempts, method, indices) {
+                Some(&indices[..])
+            } else {
+                None
+            };
+            generate(segments, self.name(), self.statistic, window, times, method);
+        }
+    }
+
+    fn settings(&self) -> Option<GeneratorSettings> {
+        Some(GeneratorSettings::Rolling(self.clone()))
+    }
+}
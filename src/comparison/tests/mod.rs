@@ -2,3 +2,7 @@ mod average;
 mod balanced_pb;
 mod empty;
 mod median;
+mod past_attempt;
+mod percentile;
+mod recent;
+mod rolling;
@@ -0,0 +1,44 @@
+use crate::comparison::{ComparisonGenerator, PastAttempt};
+use crate::util::tests_helper::{run_with_splits, run_with_splits_opt};
+use crate::{Run, Segment, TimeSpan, Timer};
+
+#[test]
+fn reconstructs_the_chosen_attempt() {
+    let s = TimeSpan::from_seconds;
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    run.push_segment(Segment::new("Third"));
+
+    let (second, third) = (PastAttempt::new(2), PastAttempt::new(3));
+    let (second_name, third_name) = (second.name().to_owned(), third.name().to_owned());
+
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut().push(Box::new(second));
+    run.comparison_generators_mut().push(Box::new(third));
+
+    let mut timer = Timer::new(run).unwrap();
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    run_with_splits_opt(&mut timer, &[Some(2.0), None, Some(5.0)]);
+    run_with_splits(&mut timer, &[1.5]);
+    run_with_splits(&mut timer, &[0.5, 1.0, 1.5]);
+
+    let run = timer.run();
+    assert_eq!(
+        run.segment(0).comparison(&second_name).game_time,
+        Some(s(2.0))
+    );
+    assert_eq!(run.segment(1).comparison(&second_name).game_time, None);
+    assert_eq!(
+        run.segment(2).comparison(&second_name).game_time,
+        Some(s(5.0))
+    );
+    assert_eq!(
+        run.segment(0).comparison(&third_name).game_time,
+        Some(s(1.5))
+    );
+    assert_eq!(run.segment(1).comparison(&third_name).game_time, None);
+    assert_eq!(run.segment(2).comparison(&third_name).game_time, None);
+}
@@ -0,0 +1,66 @@
+use crate::comparison::{self, ComparisonGenerator, PercentileSegments};
+use crate::util::tests_helper::run_with_splits;
+use crate::{Run, Segment, TimeSpan, Timer};
+
+#[test]
+fn name_is_derived_from_percentile() {
+    assert_eq!(PercentileSegments::new(0.1).name(), "Percentile 10");
+    assert_eq!(PercentileSegments::new(0.125).name(), "Percentile 12.5");
+    assert_eq!(PercentileSegments::new(2.0).name(), "Percentile 100");
+    assert_eq!(PercentileSegments::new(f64::NAN).name(), "Percentile 50");
+}
+
+#[test]
+fn shortening() {
+    assert_eq!(comparison::shorten_dynamic("Percentile 90"), "P90");
+    assert_eq!(comparison::shorten_dynamic("Percentile 12.5"), "P12.5");
+    assert_eq!(
+        comparison::shorten_dynamic("Percentile Foo"),
+        "Percentile Foo"
+    );
+    assert_eq!(
+        comparison::shorten_dynamic("Percentile NaN"),
+        "Percentile NaN"
+    );
+    assert_eq!(comparison::shorten_dynamic("Best Segments"), "Best");
+}
+
+#[test]
+fn extremes_match_best_and_worst_segments() {
+    let s = TimeSpan::from_seconds;
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    let (optimistic, pessimistic) = (PercentileSegments::new(0.0), PercentileSegments::new(1.0));
+    let (optimistic_name, pessimistic_name) =
+        (optimistic.name().to_owned(), pessimistic.name().to_owned());
+
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut().push(Box::new(optimistic));
+    run.comparison_generators_mut().push(Box::new(pessimistic));
+
+    let mut timer = Timer::new(run).unwrap();
+
+    run_with_splits(&mut timer, &[1.0, 5.0]);
+    run_with_splits(&mut timer, &[3.0, 4.0]);
+
+    let run = timer.run();
+    assert_eq!(
+        run.segment(0).comparison(&optimistic_name).game_time,
+        Some(s(1.0))
+    );
+    assert_eq!(
+        run.segment(1).comparison(&optimistic_name).game_time,
+        Some(s(2.0))
+    );
+    assert_eq!(
+        run.segment(0).comparison(&pessimistic_name).game_time,
+        Some(s(3.0))
+    );
+    assert_eq!(
+        run.segment(1).comparison(&pessimistic_name).game_time,
+        Some(s(7.0))
+    );
+}
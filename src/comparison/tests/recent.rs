@@ -0,0 +1,64 @@
+use crate::comparison::{self, ComparisonGenerator, LatestRunsAverage, RecentBestSegments};
+use crate::util::tests_helper::{run_with_splits, run_with_splits_opt};
+use crate::{Run, Segment, TimeSpan, Timer};
+
+fn timer(generator: Box<dyn ComparisonGenerator>) -> Timer {
+    let mut run = Run::new();
+
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut().push(generator);
+
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn latest_runs_average_name_is_derived_from_the_runs() {
+    let generator = LatestRunsAverage::new(10);
+    assert_eq!(generator.name(), "Latest Average 10");
+    assert_eq!(comparison::shorten_dynamic(generator.name()), "Avg10");
+}
+
+#[test]
+fn recent_golds_name_is_derived_from_the_attempts() {
+    let generator = RecentBestSegments::new(20);
+    assert_eq!(generator.name(), "Best of Last 20");
+    assert_eq!(comparison::shorten_dynamic(generator.name()), "BoL20");
+}
+
+#[test]
+fn recent_golds_ignore_old_attempts() {
+    let s = TimeSpan::from_seconds;
+
+    let generator = RecentBestSegments::new(2);
+    let name = generator.name().to_owned();
+    let mut timer = timer(Box::new(generator));
+
+    run_with_splits(&mut timer, &[1.0, 2.0]);
+    run_with_splits(&mut timer, &[3.0, 5.0]);
+    run_with_splits(&mut timer, &[2.0, 3.0]);
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).comparison(&name).game_time, Some(s(2.0)));
+    assert_eq!(run.segment(1).comparison(&name).game_time, Some(s(3.0)));
+}
+
+#[test]
+fn latest_runs_average_only_considers_completed_runs() {
+    let s = TimeSpan::from_seconds;
+
+    let generator = LatestRunsAverage::new(2);
+    let name = generator.name().to_owned();
+    let mut timer = timer(Box::new(generator));
+
+    run_with_splits(&mut timer, &[1.0, 2.0]);
+    run_with_splits(&mut timer, &[2.0, 4.0]);
+    run_with_splits_opt(&mut timer, &[Some(0.5)]);
+    run_with_splits(&mut timer, &[4.0, 6.0]);
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).comparison(&name).game_time, Some(s(3.0)));
+    assert_eq!(run.segment(1).comparison(&name).game_time, Some(s(5.0)));
+}
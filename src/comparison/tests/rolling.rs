@@ -0,0 +1,80 @@
+use crate::comparison::rolling::{RollingComparison, Statistic, Window};
+use crate::util::tests_helper::{run_with_splits, run_with_splits_opt};
+use crate::{Run, Segment, TimeSpan, Timer};
+
+const NAME: &str = "Rolling";
+
+fn timer(window: Window, statistic: Statistic, completed_runs_only: bool) -> Timer {
+    let mut run = Run::new();
+
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut().push(Box::new(
+        RollingComparison::new(NAME, window, statistic)
+            .with_completed_runs_only(completed_runs_only),
+    ));
+
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn last_attempts_only_considers_the_window() {
+    let s = TimeSpan::from_seconds;
+
+    let mut timer = timer(Window::LastAttempts(2), Statistic::Mean, false);
+
+    assert_eq!(timer.run().segment(0).comparison(NAME).game_time, None);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[2.0, 4.0]);
+    run_with_splits(&mut timer, &[4.0, 8.0]);
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).comparison(NAME).game_time, Some(s(3.0)));
+    assert_eq!(run.segment(1).comparison(NAME).game_time, Some(s(6.0)));
+}
+
+#[test]
+fn completed_runs_only_skips_resets() {
+    let s = TimeSpan::from_seconds;
+
+    let mut timer = timer(Window::LastAttempts(1), Statistic::Median, true);
+
+    run_with_splits(&mut timer, &[2.0, 4.0]);
+    run_with_splits_opt(&mut timer, &[Some(1.0)]);
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).comparison(NAME).game_time, Some(s(2.0)));
+    assert_eq!(run.segment(1).comparison(NAME).game_time, Some(s(4.0)));
+}
+
+#[test]
+fn percentile_interpolates() {
+    let s = TimeSpan::from_seconds;
+
+    let mut timer = timer(Window::AllAttempts, Statistic::Percentile(0.25), false);
+
+    for &split in &[1.0, 2.0, 3.0, 4.0, 5.0] {
+        run_with_splits(&mut timer, &[split]);
+    }
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).comparison(NAME).game_time, Some(s(2.0)));
+    assert_eq!(run.segment(1).comparison(NAME).game_time, None);
+}
+
+#[test]
+fn trimmed_mean_discards_outliers() {
+    let s = TimeSpan::from_seconds;
+
+    let mut timer = timer(Window::AllAttempts, Statistic::TrimmedMean(0.2), false);
+
+    for &split in &[1.0, 2.0, 3.0, 4.0, 50.0] {
+        run_with_splits(&mut timer, &[split]);
+    }
+
+    let run = timer.run();
+    assert_eq!(run.segment(0).comparison(NAME).game_time, Some(s(3.0)));
+}
@@ -0,0 +1,325 @@
+//! Provides the Clock Component and relevant types for using it. The Clock
+//! Component shows the local time of day, the time that elapsed since the
+//! first attempt of the day was started and optionally a countdown to a target
+//! time of day. This is useful for streamers and marathon runners that need to
+//! keep track of their schedule.
+
+use super::key_value;
+use crate::{
+    platform::{prelude::*, to_local},
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter, DASH},
+        Snapshot,
+    },
+    AtomicDateTime, TimeSpan, TimerPhase,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+use time::{Duration, Time, UtcOffset};
+
+#[cfg(test)]
+mod tests;
+
+/// The Clock Component shows the local time of day, the time that elapsed
+/// since the first attempt of the day was started and optionally a countdown
+/// to a target time of day.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the names of the rows and their values in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the labels. If `None` is specified, the color is taken
+    /// from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken
+    /// from the layout.
+    pub value_color: Option<Color>,
+    /// Specifies whether the local time of day is shown.
+    pub show_local_time: bool,
+    /// Specifies whether the local time of day is shown in the 24-hour format
+    /// instead of the 12-hour format.
+    pub use_24_hour_format: bool,
+    /// Specifies whether the seconds of the local time of day are shown.
+    pub show_seconds: bool,
+    /// Specifies whether the time that elapsed since the first attempt of the
+    /// day was started is shown.
+    pub show_session_time: bool,
+    /// The local time of day to count down to, like `18:30`. The seconds may
+    /// optionally be specified as well, like `18:30:15`. Once the time of day
+    /// has passed, the countdown continues with the next day. No countdown is
+    /// shown if set to `None`.
+    pub countdown_target: Option<String>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken
+    /// from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken
+    /// from the layout.
+    pub value_color: Option<Color>,
+    /// The rows to visualize. Each row is visualized like a key value based
+    /// component.
+    pub rows: Vec<Row>,
+    /// Specifies whether to display the names of the rows and their values in
+    /// two separate rows.
+    pub display_two_rows: bool,
+}
+
+/// The state object describing a single row of the Clock Component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Row {
+    /// The name of the row.
+    pub key: String,
+    /// The value of the row.
+    pub value: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            show_local_time: true,
+            use_24_hour_format: true,
+            show_seconds: false,
+            show_session_time: true,
+            countdown_target: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Clock Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Clock Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Clock"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut State, timer: &Snapshot<'_>) {
+        let now = AtomicDateTime::now();
+        let offset = to_local(now.time).offset();
+        self.update_state_at(state, timer, now, offset);
+    }
+
+    /// Updates the component's state as if the current time was `now` and the
+    /// local time zone was at the given offset from UTC.
+    fn update_state_at(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        now: AtomicDateTime,
+        offset: UtcOffset,
+    ) {
+        let local_now = now.time.to_offset(offset);
+        let mut row_count = 0;
+
+        if self.settings.show_local_time {
+            let value = next_row(&mut state.rows, &mut row_count, "Local Time");
+            let (hour, minute, second) = local_now.to_hms();
+            let _ = if self.settings.use_24_hour_format {
+                write!(value, "{hour:02}:{minute:02}")
+            } else {
+                write!(value, "{}:{minute:02}", (hour + 11) % 12 + 1)
+            };
+            if self.settings.show_seconds {
+                let _ = write!(value, ":{second:02}");
+            }
+            if !self.settings.use_24_hour_format {
+                value.push_str(if hour < 12 { " AM" } else { " PM" });
+            }
+        }
+
+        if self.settings.show_session_time {
+            let value = next_row(&mut state.rows, &mut row_count, "Session Time");
+            let today = local_now.date();
+
+            let current_attempt = match timer.current_phase() {
+                TimerPhase::Running | TimerPhase::Paused => {
+                    Some(now.time - timer.current_attempt_duration().to_duration())
+                }
+                _ => None,
+            };
+
+            let session_start = timer
+                .run()
+                .attempt_history()
+                .iter()
+                .filter_map(|a| a.started())
+                .map(|started| started.time)
+                .filter(|&started| started.to_offset(offset).date() == today)
+                .chain(current_attempt)
+                .min();
+
+            let elapsed = session_start.map(|start| now - start);
+            let _ = write!(
+                value,
+                "{}",
+                Regular::with_accuracy(Accuracy::Seconds).format(elapsed)
+            );
+        }
+
+        if let Some(target) = &self.settings.countdown_target {
+            let value = next_row(&mut state.rows, &mut row_count, "Countdown");
+            match parse_time_of_day(target) {
+                Some(target) => {
+                    // Once the target time of day has passed, the countdown
+                    // continues with the same time of day on the next day.
+                    let mut remaining = local_now.replace_time(target) - local_now;
+                    if remaining.is_negative() {
+                        remaining += Duration::days(1);
+                    }
+                    let remaining = TimeSpan::from(remaining);
+                    let _ = write!(
+                        value,
+                        "{}",
+                        Regular::with_accuracy(Accuracy::Seconds).format(remaining)
+                    );
+                }
+                None => value.push_str(DASH),
+            }
+        }
+
+        state.rows.truncate(row_count);
+        state.background = self.settings.background;
+        state.label_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.display_two_rows = self.settings.display_two_rows;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new(
+                "Show Local Time".into(),
+                self.settings.show_local_time.into(),
+            ),
+            Field::new(
+                "Use 24-Hour Format".into(),
+                self.settings.use_24_hour_format.into(),
+            ),
+            Field::new("Show Seconds".into(), self.settings.show_seconds.into()),
+            Field::new(
+                "Show Session Time".into(),
+                self.settings.show_session_time.into(),
+            ),
+            Field::new(
+                "Countdown Target".into(),
+                self.settings.countdown_target.clone().into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.show_local_time = value.into(),
+            5 => self.settings.use_24_hour_format = value.into(),
+            6 => self.settings.show_seconds = value.into(),
+            7 => self.settings.show_session_time = value.into(),
+            8 => self.settings.countdown_target = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// Reuses or adds the next row of the state and sets its key. The value of the
+/// row is cleared and returned, so it can be written to.
+fn next_row<'a>(rows: &'a mut Vec<Row>, row_count: &mut usize, key: &str) -> &'a mut String {
+    if *row_count == rows.len() {
+        rows.push(Row::default());
+    }
+    let row = &mut rows[*row_count];
+    *row_count += 1;
+    row.key.clear();
+    row.key.push_str(key);
+    row.value.clear();
+    &mut row.value
+}
+
+/// Parses a time of day in the format `HH:MM` or `HH:MM:SS`.
+fn parse_time_of_day(text: &str) -> Option<Time> {
+    let mut parts = text.trim().split(':');
+    let hour = parts.next()?.trim().parse().ok()?;
+    let minute = parts.next()?.trim().parse().ok()?;
+    let second = match parts.next() {
+        Some(second) => second.trim().parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Time::from_hms(hour, minute, second).ok()
+}
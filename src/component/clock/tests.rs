@@ -0,0 +1,139 @@
+use super::{parse_time_of_day, Component, State};
+use crate::{util::tests_helper::create_run, AtomicDateTime, DateTime, Time, Timer};
+use time::UtcOffset;
+
+// Monday, 2022-01-03 12:00:00 UTC
+const MONDAY_NOON: i64 = 1_641_211_200;
+
+fn at(seconds: i64) -> AtomicDateTime {
+    AtomicDateTime::new(
+        DateTime::from_unix_timestamp(MONDAY_NOON + seconds).unwrap(),
+        false,
+    )
+}
+
+fn offset(hours: i8, minutes: i8) -> UtcOffset {
+    UtcOffset::from_hms(hours, minutes, 0).unwrap()
+}
+
+fn state_at(component: &Component, timer: &Timer, now: AtomicDateTime, offset: UtcOffset) -> State {
+    let mut state = State::default();
+    component.update_state_at(&mut state, &timer.snapshot(), now, offset);
+    state
+}
+
+#[test]
+fn shows_local_time() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+    let now = at(3723);
+
+    let mut component = Component::new();
+    component.settings_mut().show_session_time = false;
+    let state = state_at(&component, &timer, now, UtcOffset::UTC);
+
+    assert_eq!(state.rows.len(), 1);
+    assert_eq!(state.rows[0].key, "Local Time");
+    assert_eq!(state.rows[0].value, "13:02");
+
+    let state = state_at(&component, &timer, now, offset(10, 0));
+    assert_eq!(state.rows[0].value, "23:02");
+
+    component.settings_mut().show_seconds = true;
+    component.settings_mut().use_24_hour_format = false;
+
+    let state = state_at(&component, &timer, now, UtcOffset::UTC);
+    assert_eq!(state.rows[0].value, "1:02:03 PM");
+
+    let state = state_at(&component, &timer, now, offset(-13, 0));
+    assert_eq!(state.rows[0].value, "12:02:03 AM");
+}
+
+#[test]
+fn session_time_starts_with_the_first_attempt_of_the_day() {
+    let mut run = create_run(&["A"]);
+    run.add_attempt(Time::new(), Some(at(-86400)), Some(at(-86000)), None);
+    run.add_attempt(Time::new(), Some(at(-600)), Some(at(-300)), None);
+    run.add_attempt(Time::new(), Some(at(-200)), Some(at(-100)), None);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().show_local_time = false;
+
+    let state = state_at(&component, &timer, at(0), UtcOffset::UTC);
+    assert_eq!(state.rows.len(), 1);
+    assert_eq!(state.rows[0].key, "Session Time");
+    assert_eq!(state.rows[0].value, "10:00");
+
+    // It's 00:05 locally, so the attempt that started at 23:55 belongs to the
+    // previous day and doesn't count.
+    let state = state_at(&component, &timer, at(0), offset(12, 5));
+    assert_eq!(state.rows[0].value, "3:20");
+}
+
+#[test]
+fn session_time_is_empty_without_attempts() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().show_local_time = false;
+    let state = state_at(&component, &timer, at(0), UtcOffset::UTC);
+
+    assert_eq!(state.rows[0].value, "—");
+}
+
+#[test]
+fn counts_down_to_the_target() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+    let now = at(0);
+
+    let mut component = Component::new();
+    component.settings_mut().show_local_time = false;
+    component.settings_mut().show_session_time = false;
+
+    component.settings_mut().countdown_target = Some(String::from("13:00"));
+    let state = state_at(&component, &timer, now, UtcOffset::UTC);
+    assert_eq!(state.rows[0].key, "Countdown");
+    assert_eq!(state.rows[0].value, "1:00:00");
+
+    let state = state_at(&component, &timer, now, offset(-2, 0));
+    assert_eq!(state.rows[0].value, "3:00:00");
+
+    component.settings_mut().countdown_target = Some(String::from("12:00"));
+    let state = state_at(&component, &timer, now, UtcOffset::UTC);
+    assert_eq!(state.rows[0].value, "0:00");
+
+    component.settings_mut().countdown_target = Some(String::from("not a time"));
+    let state = state_at(&component, &timer, now, UtcOffset::UTC);
+    assert_eq!(state.rows[0].value, "—");
+}
+
+#[test]
+fn countdown_rolls_over_to_the_next_day() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().show_local_time = false;
+    component.settings_mut().show_session_time = false;
+    component.settings_mut().countdown_target = Some(String::from("11:30"));
+
+    let state = state_at(&component, &timer, at(0), UtcOffset::UTC);
+    assert_eq!(state.rows[0].value, "23:30:00");
+
+    let state = state_at(&component, &timer, at(1), UtcOffset::UTC);
+    assert_eq!(state.rows[0].value, "23:29:59");
+}
+
+#[test]
+fn parses_time_of_day() {
+    assert_eq!(
+        parse_time_of_day("18:30"),
+        time::Time::from_hms(18, 30, 0).ok()
+    );
+    assert_eq!(
+        parse_time_of_day(" 7:05:09 "),
+        time::Time::from_hms(7, 5, 9).ok()
+    );
+    assert_eq!(parse_time_of_day("24:00"), None);
+    assert_eq!(parse_time_of_day("18"), None);
+    assert_eq!(parse_time_of_day("1:2:3:4"), None);
+}
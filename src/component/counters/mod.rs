@@ -0,0 +1,391 @@
+//! Provides the Counters Component and relevant types for using it. The
+//! Counters Component shows the values of multiple custom variables in a grid,
+//! which is useful for keeping track of things like deaths, resets or
+//! collectibles that an auto splitter or the runner keeps track of.
+//!
+//! While an attempt is in progress, each counter can additionally show the
+//! difference to the value the variable had in the Personal Best when the
+//! current segment was split.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    settings::{
+        CachedImageId, Color, Field, Gradient, Image, ImageData, SemanticColor,
+        SettingsDescription, Value,
+    },
+    timing::{
+        formatter::{DASH, MINUS, PLUS},
+        Snapshot,
+    },
+    GeneralLayoutSettings, Segment, TimerPhase,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+const SETTINGS_BEFORE_COUNTERS: usize = 8;
+const SETTINGS_PER_COUNTER: usize = 4;
+
+/// The Counters Component shows the values of multiple custom variables in a
+/// grid. Each counter can optionally show how the value compares to the value
+/// the variable had in the Personal Best.
+#[derive(Default, Clone)]
+pub struct Component {
+    icon_ids: Vec<CachedImageId>,
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The amount of counters shown next to each other in a single row.
+    pub columns: u32,
+    /// The color of the labels. If `None` is specified, the color is taken
+    /// from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken
+    /// from the layout.
+    pub value_color: Option<Color>,
+    /// Specifies whether the difference to the value the variable had in the
+    /// Personal Best at the last split of the current attempt should be shown.
+    pub show_delta: bool,
+    /// The amount of decimal places numeric values are shown with.
+    pub decimals: u32,
+    /// Specifies whether the digits of numeric values should be grouped in
+    /// groups of three, separated by commas.
+    pub group_digits: bool,
+    /// The counters to show.
+    pub counters: Vec<Counter>,
+}
+
+/// The settings of a single counter of the Counters Component.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Counter {
+    /// The name of the custom variable to show.
+    pub variable_name: String,
+    /// The label shown for the counter. The name of the variable is shown if
+    /// the label is empty.
+    pub label: String,
+    /// The icon shown next to the label.
+    pub icon: Image,
+    /// Specifies whether higher values are better than lower values. This
+    /// determines the color of the difference to the Personal Best.
+    pub higher_is_better: bool,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken
+    /// from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken
+    /// from the layout.
+    pub value_color: Option<Color>,
+    /// The amount of counters shown next to each other in a single row.
+    pub columns: u32,
+    /// The counters to visualize, laid out from left to right and top to
+    /// bottom.
+    pub counters: Vec<CounterState>,
+}
+
+/// The state object describing a single counter of the Counters Component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct CounterState {
+    /// The label of the counter.
+    pub label: String,
+    /// The value of the variable.
+    pub value: String,
+    /// The difference to the value the variable had in the Personal Best. This
+    /// is empty if there is nothing to compare against.
+    pub delta: String,
+    /// The semantic coloring information the delta carries.
+    pub delta_semantic_color: SemanticColor,
+    /// The color of the delta.
+    pub delta_color: Color,
+    /// The counter's icon encoded as the raw file bytes. This value is only
+    /// specified whenever the icon changes. If you explicitly want to query
+    /// this value, remount the component. The buffer itself may be empty. This
+    /// indicates that there is no icon.
+    pub icon_change: Option<ImageData>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            columns: 2,
+            label_color: None,
+            value_color: None,
+            show_delta: true,
+            decimals: 0,
+            group_digits: false,
+            counters: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Counters Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Counters Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Counters"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &mut self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let segments = timer.run().segments();
+        let metadata = timer.run().metadata();
+        // The variables are compared against the values they had at the last
+        // split of the Personal Best that the current attempt reached as well.
+        let completed_splits = match timer.current_phase() {
+            TimerPhase::Running | TimerPhase::Paused => timer.current_split_index(),
+            TimerPhase::Ended => Some(segments.len()),
+            TimerPhase::NotRunning => None,
+        }
+        .filter(|_| self.settings.show_delta);
+
+        let counters = &self.settings.counters;
+        self.icon_ids
+            .resize(counters.len(), CachedImageId::default());
+        state.counters.resize_with(counters.len(), Default::default);
+
+        for ((counter, counter_state), icon_id) in counters
+            .iter()
+            .zip(&mut state.counters)
+            .zip(&mut self.icon_ids)
+        {
+            counter_state.label.clear();
+            counter_state.label.push_str(if counter.label.is_empty() {
+                &counter.variable_name
+            } else {
+                &counter.label
+            });
+
+            let value = metadata.custom_variable_value(&counter.variable_name);
+            counter_state.value.clear();
+            match value {
+                Some(value) => match parse_number(value) {
+                    Some(number) => format_number(number, &self.settings, &mut counter_state.value),
+                    None => counter_state.value.push_str(value),
+                },
+                None => counter_state.value.push_str(DASH),
+            }
+
+            let delta = catch! {
+                let current = parse_number(value?)?;
+                let personal_best = personal_best_value(
+                    &segments[..completed_splits?],
+                    &counter.variable_name,
+                )?;
+                current - personal_best
+            };
+
+            counter_state.delta.clear();
+            counter_state.delta_semantic_color = SemanticColor::Default;
+            if let Some(delta) = delta {
+                if delta != 0.0 {
+                    let is_better = (delta > 0.0) == counter.higher_is_better;
+                    counter_state.delta_semantic_color = if is_better {
+                        SemanticColor::AheadGainingTime
+                    } else {
+                        SemanticColor::BehindLosingTime
+                    };
+                    counter_state
+                        .delta
+                        .push_str(if delta > 0.0 { PLUS } else { MINUS });
+                }
+                format_number(delta.abs(), &self.settings, &mut counter_state.delta);
+            }
+            counter_state.delta_color = counter_state
+                .delta_semantic_color
+                .visualize(layout_settings);
+
+            counter_state.icon_change = icon_id.update_with(Some(&counter.icon)).map(Into::into);
+        }
+
+        state.background = self.settings.background;
+        state.label_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.columns = self.settings.columns.max(1);
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(
+        &mut self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Remounts the component as if it was freshly initialized. The icons
+    /// shown by this component are only provided in the state objects whenever
+    /// the icons change or whenever the component's state is first queried.
+    /// Remounting returns the icons again, whenever its state is queried the
+    /// next time.
+    pub fn remount(&mut self) {
+        self.icon_ids.clear();
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut settings = SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Columns".into(), u64::from(self.settings.columns).into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Show Delta".into(), self.settings.show_delta.into()),
+            Field::new("Decimals".into(), u64::from(self.settings.decimals).into()),
+            Field::new("Group Digits".into(), self.settings.group_digits.into()),
+            Field::new(
+                "Counters".into(),
+                Value::UInt(self.settings.counters.len() as _),
+            ),
+        ]);
+
+        settings
+            .fields
+            .reserve_exact(SETTINGS_PER_COUNTER * self.settings.counters.len());
+
+        for counter in &self.settings.counters {
+            settings.fields.push(Field::new(
+                "Variable Name".into(),
+                counter.variable_name.clone().into(),
+            ));
+            settings
+                .fields
+                .push(Field::new("Label".into(), counter.label.clone().into()));
+            settings
+                .fields
+                .push(Field::new("Icon".into(), counter.icon.clone().into()));
+            settings.fields.push(Field::new(
+                "Higher Is Better".into(),
+                counter.higher_is_better.into(),
+            ));
+        }
+
+        settings
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.columns = value.into_uint().unwrap() as _,
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.show_delta = value.into(),
+            5 => self.settings.decimals = value.into_uint().unwrap() as _,
+            6 => self.settings.group_digits = value.into(),
+            7 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings.counters.resize(new_len, Default::default());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_COUNTERS;
+                let counter = self
+                    .settings
+                    .counters
+                    .get_mut(index / SETTINGS_PER_COUNTER)
+                    .expect("Unsupported Setting Index");
+                match index % SETTINGS_PER_COUNTER {
+                    0 => counter.variable_name = value.into(),
+                    1 => counter.label = value.into(),
+                    2 => counter.icon = value.into(),
+                    _ => counter.higher_is_better = value.into(),
+                }
+            }
+        }
+    }
+}
+
+/// Formats the number with the amount of decimal places and the digit grouping
+/// specified by the settings.
+fn format_number(number: f64, settings: &Settings, buf: &mut String) {
+    let start = buf.len();
+    let _ = write!(buf, "{:.*}", settings.decimals as usize, number);
+    if settings.group_digits {
+        let digits_start = start + buf[start..].find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+        let digits_end = buf[digits_start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(buf.len(), |i| digits_start + i);
+        let mut i = digits_end;
+        while i > digits_start + 3 {
+            i -= 3;
+            buf.insert(i, ',');
+        }
+    }
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    value.trim().parse().ok().filter(|v: &f64| v.is_finite())
+}
+
+/// Looks up the value the variable had in the Personal Best at the end of the
+/// last of the segments provided. If the variable wasn't recorded for that
+/// segment, the closest previous segment that recorded it is used instead.
+fn personal_best_value(segments: &[Segment], variable_name: &str) -> Option<f64> {
+    segments
+        .iter()
+        .rev()
+        .find_map(|segment| parse_number(segment.personal_best_variables().get(variable_name)?))
+}
@@ -0,0 +1,165 @@
+use super::{Component, Counter, Settings, State};
+use crate::{
+    settings::{Image, SemanticColor},
+    util::tests_helper::create_timer,
+    GeneralLayoutSettings, Timer,
+};
+
+fn counter(variable_name: &str) -> Counter {
+    Counter {
+        variable_name: variable_name.into(),
+        ..Default::default()
+    }
+}
+
+fn state_of(component: &mut Component, timer: &Timer) -> State {
+    component.state(&timer.snapshot(), &GeneralLayoutSettings::default())
+}
+
+#[test]
+fn shows_variables() {
+    let mut timer = create_timer(&["A"]);
+    timer.set_custom_variable("Deaths", "3");
+    timer.set_custom_variable("Route", "Glitched");
+
+    let mut component = Component::with_settings(Settings {
+        counters: vec![
+            counter("Deaths"),
+            Counter {
+                label: "Current Route".into(),
+                ..counter("Route")
+            },
+            counter("Missing"),
+        ],
+        ..Default::default()
+    });
+
+    let state = state_of(&mut component, &timer);
+    let counters: Vec<_> = state
+        .counters
+        .iter()
+        .map(|c| (c.label.as_str(), c.value.as_str(), c.delta.as_str()))
+        .collect();
+    assert_eq!(
+        counters,
+        [
+            ("Deaths", "3", ""),
+            ("Current Route", "Glitched", ""),
+            ("Missing", "—", ""),
+        ]
+    );
+}
+
+#[test]
+fn formats_numbers() {
+    let mut timer = create_timer(&["A"]);
+    timer.set_custom_variable("Points", "1234567.891");
+
+    let mut component = Component::with_settings(Settings {
+        counters: vec![counter("Points")],
+        ..Default::default()
+    });
+    assert_eq!(
+        state_of(&mut component, &timer).counters[0].value,
+        "1234568"
+    );
+
+    component.settings_mut().decimals = 2;
+    component.settings_mut().group_digits = true;
+    assert_eq!(
+        state_of(&mut component, &timer).counters[0].value,
+        "1,234,567.89"
+    );
+
+    timer.set_custom_variable("Points", "-1234");
+    assert_eq!(
+        state_of(&mut component, &timer).counters[0].value,
+        "-1,234.00"
+    );
+}
+
+#[test]
+fn compares_against_the_personal_best() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    timer.start();
+    timer.set_custom_variable("Deaths", "2");
+    timer.set_custom_variable("Coins", "10");
+    timer.split();
+    timer.split();
+    timer.set_custom_variable("Deaths", "6");
+    timer.set_custom_variable("Coins", "30");
+    timer.split();
+    timer.reset(true);
+
+    let mut component = Component::with_settings(Settings {
+        counters: vec![
+            counter("Deaths"),
+            Counter {
+                higher_is_better: true,
+                ..counter("Coins")
+            },
+        ],
+        ..Default::default()
+    });
+
+    timer.start();
+    timer.set_custom_variable("Deaths", "3");
+    timer.set_custom_variable("Coins", "10");
+
+    // There is no previous split to compare against yet.
+    let state = state_of(&mut component, &timer);
+    assert_eq!(state.counters[0].delta, "");
+    assert_eq!(state.counters[1].delta, "");
+
+    timer.split();
+    let state = state_of(&mut component, &timer);
+    assert_eq!(state.counters[0].delta, "+1");
+    assert_eq!(
+        state.counters[0].delta_semantic_color,
+        SemanticColor::BehindLosingTime
+    );
+    assert_eq!(state.counters[1].delta, "0");
+    assert_eq!(
+        state.counters[1].delta_semantic_color,
+        SemanticColor::Default
+    );
+
+    // In the middle of a segment the variables are compared against the
+    // previous split rather than the end of the current segment.
+    timer.split();
+    timer.set_custom_variable("Coins", "15");
+    let state = state_of(&mut component, &timer);
+    assert_eq!(state.counters[0].delta, "+1");
+    assert_eq!(state.counters[1].delta, "+5");
+    assert_eq!(
+        state.counters[1].delta_semantic_color,
+        SemanticColor::AheadGainingTime
+    );
+
+    timer.split();
+    let state = state_of(&mut component, &timer);
+    assert_eq!(state.counters[0].delta, "−3");
+    assert_eq!(state.counters[1].delta, "−15");
+
+    component.settings_mut().show_delta = false;
+    let state = state_of(&mut component, &timer);
+    assert_eq!(state.counters[0].delta, "");
+}
+
+#[test]
+fn only_reports_icon_changes() {
+    let timer = create_timer(&["A"]);
+    let mut component = Component::with_settings(Settings {
+        counters: vec![Counter {
+            icon: Image::new(&[1]),
+            ..counter("Deaths")
+        }],
+        ..Default::default()
+    });
+
+    let icon = |state: State| state.counters[0].icon_change.as_ref().map(|i| i.to_vec());
+    assert_eq!(icon(state_of(&mut component, &timer)), Some(vec![1]));
+    assert_eq!(icon(state_of(&mut component, &timer)), None);
+    component.remount();
+    assert_eq!(icon(state_of(&mut component, &timer)), Some(vec![1]));
+}
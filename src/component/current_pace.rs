@@ -95,7 +95,9 @@ impl Component {
                 comparison::best_segments::NAME => "Best Possible Time".into(),
                 comparison::worst_segments::NAME => "Worst Possible Time".into(),
                 comparison::average_segments::NAME => "Predicted Time".into(),
-                comparison => format!("Current Pace ({})", comparison::shorten(comparison)).into(),
+                comparison => {
+                    format!("Current Pace ({})", comparison::shorten_dynamic(comparison)).into()
+                }
             }
         } else {
             "Current Pace".into()
@@ -0,0 +1,257 @@
+//! Provides the Custom Image Component and relevant types for using it. The
+//! Custom Image Component shows an arbitrary image, like a map of the route or
+//! a checklist of items to collect. Additionally, images can be assigned to
+//! individual segments, so that the image switches as the runner progresses
+//! through the run.
+
+use crate::{
+    platform::prelude::*,
+    settings::{CachedImageId, Field, Gradient, Image, ImageData, SettingsDescription, Value},
+    timing::Snapshot,
+    TimerPhase,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+const SETTINGS_BEFORE_SEGMENT_IMAGES: usize = 5;
+const SETTINGS_PER_SEGMENT_IMAGE: usize = 2;
+
+/// The Custom Image Component shows an arbitrary image. The image can switch
+/// to a different image for each of the segments as the runner progresses
+/// through the run.
+#[derive(Default, Clone)]
+pub struct Component {
+    image_id: CachedImageId,
+    settings: Settings,
+}
+
+/// Describes how the Custom Image Component scales its image to fit the space
+/// available to the component.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageScaling {
+    /// The image is scaled to be as large as possible while keeping its aspect
+    /// ratio. The image is centered within the component.
+    #[default]
+    Fit,
+    /// The image is stretched to fill the whole component, ignoring its aspect
+    /// ratio.
+    Stretch,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The image that is shown whenever there is no image for the current
+    /// segment.
+    pub image: Image,
+    /// How the image is scaled to fit the component.
+    pub scaling: ImageScaling,
+    /// The height of the component.
+    pub height: u32,
+    /// The images assigned to individual segments. When a segment is reached
+    /// that doesn't have an image assigned, the image of the closest previous
+    /// segment that has one is kept, so an image can be shared by a whole
+    /// section of the run.
+    pub segment_images: Vec<SegmentImage>,
+}
+
+/// An image that is assigned to a segment.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SegmentImage {
+    /// The name of the segment the image is shown for.
+    pub segment_name: String,
+    /// The image to show.
+    pub image: Image,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The image encoded as the raw file bytes. This value is only specified
+    /// whenever the image changes. If you explicitly want to query this value,
+    /// remount the component. The buffer itself may be empty. This indicates
+    /// that there is no image.
+    pub image_change: Option<ImageData>,
+    /// How the image is supposed to be scaled to fit the component.
+    pub scaling: ImageScaling,
+    /// The height of the component.
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            image: Image::default(),
+            scaling: ImageScaling::Fit,
+            height: 120,
+            segment_images: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Custom Image Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Custom Image Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Custom Image"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&mut self, state: &mut State, timer: &Snapshot<'_>) {
+        let image = current_image(&self.settings, timer);
+        let image_change = self.image_id.update_with(Some(image)).map(Into::into);
+
+        state.background = self.settings.background;
+        state.image_change = image_change;
+        state.scaling = self.settings.scaling;
+        state.height = self.settings.height;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&mut self, timer: &Snapshot<'_>) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Remounts the component as if it was freshly initialized. The image
+    /// shown by this component is only provided in the state objects whenever
+    /// the image changes or whenever the component's state is first queried.
+    /// Remounting returns the image again, whenever its state is queried the
+    /// next time.
+    pub fn remount(&mut self) {
+        self.image_id.reset();
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut settings = SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Image".into(), self.settings.image.clone().into()),
+            Field::new("Scaling".into(), self.settings.scaling.into()),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+            Field::new(
+                "Segment Images".into(),
+                Value::UInt(self.settings.segment_images.len() as _),
+            ),
+        ]);
+
+        settings
+            .fields
+            .reserve_exact(SETTINGS_PER_SEGMENT_IMAGE * self.settings.segment_images.len());
+
+        for segment_image in &self.settings.segment_images {
+            settings.fields.push(Field::new(
+                "Segment Name".into(),
+                segment_image.segment_name.clone().into(),
+            ));
+            settings.fields.push(Field::new(
+                "Segment Image".into(),
+                segment_image.image.clone().into(),
+            ));
+        }
+
+        settings
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.image = value.into(),
+            2 => self.settings.scaling = value.into(),
+            3 => self.settings.height = value.into_uint().unwrap() as _,
+            4 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .segment_images
+                    .resize(new_len, Default::default());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_SEGMENT_IMAGES;
+                let segment_image = self
+                    .settings
+                    .segment_images
+                    .get_mut(index / SETTINGS_PER_SEGMENT_IMAGE)
+                    .expect("Unsupported Setting Index");
+                match index % SETTINGS_PER_SEGMENT_IMAGE {
+                    0 => segment_image.segment_name = value.into(),
+                    _ => segment_image.image = value.into(),
+                }
+            }
+        }
+    }
+}
+
+/// Determines the image to show. While an attempt is in progress, this is
+/// the image of the current segment or the closest previous segment that
+/// has an image assigned. Otherwise the default image is shown.
+fn current_image<'a>(settings: &'a Settings, timer: &Snapshot<'_>) -> &'a Image {
+    let current_split_index = match timer.current_phase() {
+        TimerPhase::Running | TimerPhase::Paused => timer.current_split_index(),
+        _ => None,
+    };
+
+    current_split_index
+        .and_then(|index| {
+            timer.run().segments()[..=index]
+                .iter()
+                .rev()
+                .find_map(|segment| {
+                    settings
+                        .segment_images
+                        .iter()
+                        .find(|i| i.segment_name == segment.name() && !i.image.is_empty())
+                })
+        })
+        .map_or(&settings.image, |i| &i.image)
+}
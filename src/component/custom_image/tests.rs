@@ -0,0 +1,84 @@
+use super::{Component, SegmentImage, Settings};
+use crate::{
+    settings::{Image, Value},
+    util::tests_helper::{create_timer, start_run},
+    Timer,
+};
+
+fn image_change(component: &mut Component, timer: &Timer) -> Option<Vec<u8>> {
+    component
+        .state(&timer.snapshot())
+        .image_change
+        .map(|data| data.to_vec())
+}
+
+fn component() -> Component {
+    Component::with_settings(Settings {
+        image: Image::new(&[1]),
+        segment_images: vec![
+            SegmentImage {
+                segment_name: "B".into(),
+                image: Image::new(&[2]),
+            },
+            SegmentImage {
+                segment_name: "D".into(),
+                image: Image::new(&[3]),
+            },
+        ],
+        ..Default::default()
+    })
+}
+
+#[test]
+fn only_reports_image_changes() {
+    let timer = create_timer(&["A", "B"]);
+    let mut component = component();
+
+    assert_eq!(image_change(&mut component, &timer), Some(vec![1]));
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    component.remount();
+    assert_eq!(image_change(&mut component, &timer), Some(vec![1]));
+}
+
+#[test]
+fn switches_to_segment_images() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    let mut component = component();
+
+    assert_eq!(image_change(&mut component, &timer), Some(vec![1]));
+
+    start_run(&mut timer);
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    timer.split();
+    assert_eq!(image_change(&mut component, &timer), Some(vec![2]));
+
+    // The image of segment B is kept for segment C, which has no image.
+    timer.split();
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    timer.split();
+    assert_eq!(image_change(&mut component, &timer), Some(vec![3]));
+
+    timer.split();
+    assert_eq!(image_change(&mut component, &timer), Some(vec![1]));
+
+    timer.reset(true);
+    assert_eq!(image_change(&mut component, &timer), None);
+}
+
+#[test]
+fn segment_images_can_be_edited() {
+    let mut component = Component::new();
+    component.set_value(4, Value::UInt(2));
+    component.set_value(7, "B".to_string().into());
+    component.set_value(8, Image::new(&[2]).into());
+
+    let fields = component.settings_description().fields;
+    assert_eq!(fields.len(), 9);
+    assert_eq!(fields[7].text, "Segment Name");
+    assert!(fields[7].value == Value::String("B".into()));
+    assert!(fields[8].value == Value::Image(Image::new(&[2])));
+    assert_eq!(component.settings().segment_images[0].segment_name, "");
+}
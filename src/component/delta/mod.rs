@@ -143,8 +143,8 @@ impl Component {
         );
 
         state.key_abbreviations.clear();
-        if let Some(abbreviation) = comparison::try_shorten(text) {
-            state.key_abbreviations.push(abbreviation.into());
+        if let Some(abbreviation) = comparison::try_shorten_dynamic(text) {
+            state.key_abbreviations.push(abbreviation);
         }
 
         state.display_two_rows = self.settings.display_two_rows;
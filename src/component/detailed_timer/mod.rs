@@ -16,6 +16,7 @@ use crate::{
     },
     GeneralLayoutSettings, Segment, TimeSpan, TimerPhase,
 };
+use alloc::borrow::Cow;
 use core::fmt::Write;
 use serde::{Deserialize, Serialize};
 
@@ -93,7 +94,7 @@ pub struct ComparisonState {
 
 fn update_comparison(
     state: &mut Option<ComparisonState>,
-    new_state: Option<(&str, Option<TimeSpan>)>,
+    new_state: Option<(Cow<'_, str>, Option<TimeSpan>)>,
 ) {
     if let Some((name, time)) = new_state {
         let state = state.get_or_insert_with(|| ComparisonState {
@@ -102,7 +103,7 @@ fn update_comparison(
         });
 
         state.name.clear();
-        state.name.push_str(name);
+        state.name.push_str(&name);
 
         state.time.clear();
         let _ = write!(state.time, "{}", SegmentTime::new().format(time));
@@ -242,13 +243,13 @@ impl Component {
             }
 
             let comparison1 = Some((
-                comparison::shorten(comparison1),
+                comparison::shorten_dynamic(comparison1),
                 comparison_single_segment_time(run, last_split_index, comparison1, timing_method),
             ));
 
             let comparison2 = if !hide_comparison {
                 Some((
-                    comparison::shorten(comparison2),
+                    comparison::shorten_dynamic(comparison2),
                     comparison_single_segment_time(
                         run,
                         last_split_index,
@@ -219,7 +219,7 @@ impl Component {
 
     fn text(&self, comparison: Option<&str>) -> Cow<'static, str> {
         if let Some(comparison) = comparison {
-            format!("Graph ({})", comparison::shorten(comparison)).into()
+            format!("Graph ({})", comparison::shorten_dynamic(comparison)).into()
         } else {
             "Graph".into()
         }
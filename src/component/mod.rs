@@ -4,19 +4,26 @@
 //! visualized by any kind of User Interface.
 
 pub mod blank_space;
+pub mod clock;
+pub mod counters;
 pub mod current_comparison;
 pub mod current_pace;
+pub mod custom_image;
 pub mod delta;
 pub mod detailed_timer;
 pub mod graph;
 pub mod pb_chance;
+pub mod pb_history;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod progress_bar;
+pub mod segment_history;
 pub mod segment_time;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
 pub mod text;
+pub mod timeline;
 pub mod timer;
 pub mod title;
 pub mod total_playtime;
@@ -24,19 +31,26 @@ pub mod total_playtime;
 pub mod key_value;
 
 pub use blank_space::Component as BlankSpace;
+pub use clock::Component as Clock;
+pub use counters::Component as Counters;
 pub use current_comparison::Component as CurrentComparison;
 pub use current_pace::Component as CurrentPace;
+pub use custom_image::Component as CustomImage;
 pub use delta::Component as Delta;
 pub use detailed_timer::Component as DetailedTimer;
 pub use graph::Component as Graph;
 pub use pb_chance::Component as PbChance;
+pub use pb_history::Component as PbHistory;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
+pub use progress_bar::Component as ProgressBar;
+pub use segment_history::Component as SegmentHistory;
 pub use segment_time::Component as SegmentTime;
 pub use separator::Component as Separator;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
 pub use text::Component as Text;
+pub use timeline::Component as Timeline;
 pub use timer::Component as Timer;
 pub use title::Component as Title;
 pub use total_playtime::Component as TotalPlaytime;
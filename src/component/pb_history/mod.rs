@@ -0,0 +1,438 @@
+//! Provides the PB History Component and relevant types for using it. The PB
+//! History Component visualizes how the Personal Best improved over time. Every
+//! attempt in the Attempt History that set a new Personal Best lowers the line
+//! shown by the component. Optionally the Sum of Best Segments can be shown
+//! over time as well.
+
+use crate::{
+    platform::{prelude::*, RwLock},
+    run::Attempt,
+    settings::{Color, Field, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, Run, TimeSpan, TimingMethod,
+};
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The PB History Component shows a chart of the Personal Best's final time
+/// over the course of the Attempt History.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+    cache: HistoryCache,
+}
+
+/// Caches the lines calculated from the Attempt History and the Segment
+/// Histories, so that they only need to be recalculated when the
+/// [`history_id`](Run::history_id) of the run changes, instead of on every
+/// update of the state.
+struct HistoryCache(RwLock<Histories>);
+
+struct Histories {
+    key: Option<HistoryKey>,
+    personal_bests: Vec<(f64, TimeSpan)>,
+    sums_of_best: Vec<(f64, TimeSpan)>,
+    improvements: Vec<(i32, usize, TimeSpan)>,
+    best_segments: Vec<Option<TimeSpan>>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+struct HistoryKey {
+    history_id: usize,
+    method: TimingMethod,
+    axis: HistoryAxis,
+    show_sum_of_best: bool,
+}
+
+/// Describes what the horizontal axis of the PB History Component represents.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HistoryAxis {
+    /// The attempts are evenly spaced out by their index.
+    #[default]
+    Attempts,
+    /// The attempts are placed by the date they were started on. Attempts
+    /// without a known date are not shown.
+    Date,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// What the horizontal axis of the chart represents.
+    pub axis: HistoryAxis,
+    /// Specifies whether the Sum of Best Segments should be shown over time
+    /// as well.
+    pub show_sum_of_best: bool,
+    /// The height of the chart.
+    pub height: u32,
+    /// The background color of the chart.
+    pub background_color: Color,
+}
+
+/// The state object describes the information to visualize for this component.
+/// All coordinates are in the range `0..1`. The horizontal coordinates go from
+/// the first to the last attempt shown, while the vertical coordinates go from
+/// the slowest time at the top to the fastest time at the bottom.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The points of the line showing the Personal Best over time. The line
+    /// is a step line, so each improvement is a vertical drop and the line
+    /// extends to the right edge of the chart. This is empty if no attempt
+    /// was ever finished.
+    pub personal_best_points: Vec<Point>,
+    /// The points of the line showing the Sum of Best Segments over time,
+    /// which is shaped just like the line of the Personal Best. This is empty
+    /// if the Sum of Best Segments is not supposed to be shown or there never
+    /// were segment times for all the segments.
+    pub sum_of_best_points: Vec<Point>,
+    /// The background color of the chart.
+    pub background_color: Color,
+    /// The color of the line showing the Personal Best.
+    pub personal_best_color: Color,
+    /// The color of the line showing the Sum of Best Segments.
+    pub sum_of_best_color: Color,
+    /// The height of the chart.
+    pub height: u32,
+}
+
+/// Describes a point on one of the lines of the chart.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    /// The horizontal coordinate of the point.
+    pub x: f32,
+    /// The vertical coordinate of the point.
+    pub y: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            axis: HistoryAxis::default(),
+            show_sum_of_best: true,
+            height: 80,
+            background_color: Color::rgba(1.0, 1.0, 1.0, 0.05),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new PB History Component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new PB History Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            cache: HistoryCache::new(),
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        "PB History".into()
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let run = timer.run();
+        let axis = self.settings.axis;
+        let attempts = run.attempt_history();
+
+        let mut histories = self.cache.0.write().unwrap();
+        histories.update(
+            run,
+            HistoryKey {
+                history_id: run.history_id(),
+                method: timer.current_timing_method(),
+                axis,
+                show_sum_of_best: self.settings.show_sum_of_best,
+            },
+        );
+        let (personal_bests, sums_of_best) = (&histories.personal_bests, &histories.sums_of_best);
+
+        state.personal_best_points.clear();
+        state.sum_of_best_points.clear();
+
+        let last_x = attempts.iter().rev().find_map(|a| position(a, axis));
+        if let Some(last_x) = last_x {
+            let lines = personal_bests.iter().chain(sums_of_best);
+            let first_x = lines.clone().map(|&(x, _)| x).fold(last_x, f64::min);
+            let (min_time, max_time) = lines
+                .map(|&(_, time)| time.total_seconds())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), t| {
+                    (min.min(t), max.max(t))
+                });
+
+            let x_range = (last_x - first_x).max(f64::EPSILON);
+            let time_range = (max_time - min_time).max(f64::EPSILON);
+            let to_point = |(x, time): (f64, TimeSpan)| Point {
+                x: ((x - first_x) / x_range).clamp(0.0, 1.0) as f32,
+                y: ((max_time - time.total_seconds()) / time_range).clamp(0.0, 1.0) as f32,
+            };
+
+            step_line(&mut state.personal_best_points, personal_bests, to_point);
+            step_line(&mut state.sum_of_best_points, sums_of_best, to_point);
+        }
+
+        state.background_color = self.settings.background_color;
+        state.personal_best_color = layout_settings.personal_best_color;
+        state.sum_of_best_color = layout_settings.best_segment_color;
+        state.height = self.settings.height;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = State::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Axis".into(), self.settings.axis.into()),
+            Field::new(
+                "Show Sum of Best Segments".into(),
+                self.settings.show_sum_of_best.into(),
+            ),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+            Field::new(
+                "Background Color".into(),
+                self.settings.background_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.axis = value.into(),
+            1 => self.settings.show_sum_of_best = value.into(),
+            2 => self.settings.height = value.into_uint().unwrap() as _,
+            3 => self.settings.background_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+impl HistoryCache {
+    const fn new() -> Self {
+        Self(RwLock::new(Histories {
+            key: None,
+            personal_bests: Vec::new(),
+            sums_of_best: Vec::new(),
+            improvements: Vec::new(),
+            best_segments: Vec::new(),
+        }))
+    }
+}
+
+impl Default for HistoryCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for HistoryCache {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl Histories {
+    /// Recalculates the lines if they were calculated for a different Attempt
+    /// History or with different settings. The buffers of the previous
+    /// calculation are reused.
+    fn update(&mut self, run: &Run, key: HistoryKey) {
+        if self.key == Some(key) {
+            return;
+        }
+        self.key = Some(key);
+
+        personal_best_history(
+            &mut self.personal_bests,
+            run.attempt_history(),
+            key.method,
+            key.axis,
+        );
+        self.sums_of_best.clear();
+        if key.show_sum_of_best {
+            sum_of_best_history(
+                &mut self.sums_of_best,
+                &mut self.improvements,
+                &mut self.best_segments,
+                run,
+                key.method,
+                key.axis,
+            );
+        }
+    }
+}
+
+/// The position of the attempt along the horizontal axis. This is `None` if the
+/// attempt can't be placed on the axis.
+fn position(attempt: &Attempt, axis: HistoryAxis) -> Option<f64> {
+    match axis {
+        HistoryAxis::Attempts => Some(attempt.index() as f64),
+        HistoryAxis::Date => {
+            let date = attempt.started().or_else(|| attempt.ended())?;
+            Some(date.time.unix_timestamp() as f64)
+        }
+    }
+}
+
+/// Collects every attempt that set a new Personal Best along with its final
+/// time.
+fn personal_best_history(
+    history: &mut Vec<(f64, TimeSpan)>,
+    attempts: &[Attempt],
+    method: TimingMethod,
+    axis: HistoryAxis,
+) {
+    history.clear();
+    let mut best: Option<TimeSpan> = None;
+    for attempt in attempts {
+        if let (Some(time), Some(x)) = (attempt.time()[method], position(attempt, axis)) {
+            if best.map_or(true, |best| time < best) {
+                best = Some(time);
+                history.push((x, time));
+            }
+        }
+    }
+}
+
+/// Collects every point in the Attempt History where the Sum of Best Segments
+/// improved. Segment times that are combined with a skipped segment are
+/// ignored, just like when calculating the Best Segments. Segment times that
+/// don't belong to an attempt, like the ones imported from other timers, are
+/// placed at the start of the chart.
+fn sum_of_best_history(
+    history: &mut Vec<(f64, TimeSpan)>,
+    improvements: &mut Vec<(i32, usize, TimeSpan)>,
+    best_segments: &mut Vec<Option<TimeSpan>>,
+    run: &Run,
+    method: TimingMethod,
+    axis: HistoryAxis,
+) {
+    let segments = run.segments();
+    let attempts = run.attempt_history();
+
+    improvements.clear();
+    for (segment_index, segment) in segments.iter().enumerate() {
+        let mut best: Option<TimeSpan> = None;
+        for &(id, time) in segment.segment_history() {
+            let is_combined = segment_index > 0
+                && segments[segment_index - 1]
+                    .segment_history()
+                    .get(id)
+                    .map_or(false, |time| time[method].is_none());
+            if is_combined {
+                continue;
+            }
+            if let Some(time) = time[method] {
+                if best.map_or(true, |best| time < best) {
+                    best = Some(time);
+                    improvements.push((id, segment_index, time));
+                }
+            }
+        }
+    }
+    // A segment has at most one improvement per attempt, so the order of the
+    // improvements of the same attempt doesn't matter.
+    improvements.sort_unstable_by_key(|&(id, ..)| id);
+
+    let first_x = attempts.iter().find_map(|a| position(a, axis));
+    history.clear();
+    best_segments.clear();
+    best_segments.resize(segments.len(), None);
+    for (index, &(id, segment_index, time)) in improvements.iter().enumerate() {
+        best_segments[segment_index] = Some(time);
+        if improvements
+            .get(index + 1)
+            .map_or(false, |&(next, ..)| next == id)
+        {
+            continue;
+        }
+        let x = attempts
+            .binary_search_by_key(&id, |a| a.index())
+            .ok()
+            .and_then(|index| position(&attempts[index], axis))
+            .or(first_x);
+        let sum = best_segments
+            .iter()
+            .try_fold(TimeSpan::zero(), |sum, &time| Some(sum + time?));
+        if let (Some(x), Some(sum)) = (x, sum) {
+            match history.last_mut() {
+                Some((last_x, last_sum)) if *last_x >= x => {
+                    *last_sum = sum;
+                }
+                Some((_, last_sum)) if *last_sum == sum => {}
+                _ => history.push((x, sum)),
+            }
+        }
+    }
+}
+
+/// Turns the improvements into the points of a step line that extends to the
+/// right edge of the chart. Improvements at the same horizontal position as the
+/// previous one drop straight down, so they don't need an additional corner.
+fn step_line(
+    points: &mut Vec<Point>,
+    improvements: &[(f64, TimeSpan)],
+    to_point: impl Fn((f64, TimeSpan)) -> Point,
+) {
+    for &improvement in improvements {
+        let point = to_point(improvement);
+        if let Some(&previous) = points.last().filter(|previous| previous.x != point.x) {
+            points.push(Point {
+                x: point.x,
+                y: previous.y,
+            });
+        }
+        points.push(point);
+    }
+    if let Some(&last) = points.last() {
+        points.push(Point { x: 1.0, y: last.y });
+    }
+}
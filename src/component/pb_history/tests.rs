@@ -0,0 +1,153 @@
+use super::{step_line, Component, Point, Settings, State};
+use crate::{
+    util::tests_helper::{create_timer, run_with_splits, run_with_splits_opt},
+    GeneralLayoutSettings, TimeSpan, Timer,
+};
+
+fn state_of(component: &Component, timer: &Timer) -> State {
+    component.state(&timer.snapshot(), &GeneralLayoutSettings::default())
+}
+
+fn assert_points(actual: &[Point], expected: &[[f32; 2]]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+    for (a, &[x, y]) in actual.iter().zip(expected) {
+        assert!(
+            (a.x - x).abs() < 1e-5 && (a.y - y).abs() < 1e-5,
+            "{actual:?} != {expected:?}",
+        );
+    }
+}
+
+#[test]
+fn is_empty_without_attempts() {
+    let timer = create_timer(&["A", "B"]);
+    let state = state_of(&Component::new(), &timer);
+    assert!(state.personal_best_points.is_empty());
+    assert!(state.sum_of_best_points.is_empty());
+}
+
+#[test]
+fn drops_with_every_new_personal_best() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 40.0]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    run_with_splits(&mut timer, &[10.0, 40.0]);
+
+    let component = Component::with_settings(Settings {
+        show_sum_of_best: false,
+        ..Default::default()
+    });
+    let state = state_of(&component, &timer);
+    assert_points(
+        &state.personal_best_points,
+        &[[0.0, 0.0], [2.0 / 3.0, 0.0], [2.0 / 3.0, 1.0], [1.0, 1.0]],
+    );
+    assert!(state.sum_of_best_points.is_empty());
+}
+
+#[test]
+fn shows_the_sum_of_best_over_time() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[5.0, 40.0]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+
+    let state = state_of(&Component::new(), &timer);
+    assert_points(
+        &state.personal_best_points,
+        &[[0.0, 0.0], [1.0, 0.0], [1.0, 0.5], [1.0, 0.5]],
+    );
+    assert_points(
+        &state.sum_of_best_points,
+        &[
+            [0.0, 0.0],
+            [0.5, 0.0],
+            [0.5, 0.5],
+            [1.0, 0.5],
+            [1.0, 1.0],
+            [1.0, 1.0],
+        ],
+    );
+}
+
+#[test]
+fn ignores_segment_times_combined_with_skipped_segments() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits_opt(&mut timer, &[None, Some(30.0)]);
+
+    let state = state_of(&Component::new(), &timer);
+    assert_eq!(state.personal_best_points.len(), 2);
+    assert!(state.sum_of_best_points.is_empty());
+}
+
+#[test]
+fn updates_when_the_attempt_history_or_the_settings_change() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 40.0]);
+
+    let mut component = Component::new();
+    let state = state_of(&component, &timer);
+    assert_points(&state.personal_best_points, &[[0.0, 0.0], [1.0, 0.0]]);
+    assert_eq!(state.sum_of_best_points.len(), 2);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    let state = state_of(&component, &timer);
+    assert_points(
+        &state.personal_best_points,
+        &[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [1.0, 1.0]],
+    );
+
+    component.settings_mut().show_sum_of_best = false;
+    let state = state_of(&component, &timer);
+    assert!(state.sum_of_best_points.is_empty());
+}
+
+#[test]
+fn updates_when_the_run_is_replaced_or_its_segment_history_is_edited() {
+    let mut first = create_timer(&["A", "B"]);
+    run_with_splits(&mut first, &[10.0, 30.0]);
+    run_with_splits(&mut first, &[10.0, 25.0]);
+
+    let mut second = create_timer(&["A", "B"]);
+    run_with_splits(&mut second, &[10.0, 25.0]);
+    run_with_splits(&mut second, &[10.0, 30.0]);
+
+    let component = Component::new();
+    let state = state_of(&component, &first);
+    assert_eq!(state.personal_best_points.len(), 4);
+
+    first.replace_run(second.run().clone(), false).unwrap();
+    let state = state_of(&component, &first);
+    assert_points(&state.personal_best_points, &[[0.0, 0.0], [1.0, 0.0]]);
+    let sum_of_best = state.sum_of_best_points;
+
+    // Removing the first attempt's segment times without removing the
+    // attempt itself only changes the Segment History.
+    let mut run = first.run().clone();
+    for segment in run.segments_mut() {
+        segment.segment_history_mut().remove(1);
+    }
+    first.replace_run(run, false).unwrap();
+    let state = state_of(&component, &first);
+    assert!(state.sum_of_best_points != sum_of_best);
+}
+
+#[test]
+fn step_line_drops_straight_down_at_the_same_position() {
+    let mut points = Vec::new();
+    let improvements = [
+        (0.0, TimeSpan::from_seconds(30.0)),
+        (0.0, TimeSpan::from_seconds(20.0)),
+        (1.0, TimeSpan::from_seconds(10.0)),
+    ];
+    step_line(&mut points, &improvements, |(x, time)| Point {
+        x: x as f32,
+        y: (30.0 - time.total_seconds()) as f32 / 20.0,
+    });
+    assert_points(
+        &points,
+        &[[0.0, 0.0], [0.0, 0.5], [1.0, 0.5], [1.0, 1.0], [1.0, 1.0]],
+    );
+}
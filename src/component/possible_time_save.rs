@@ -108,7 +108,12 @@ impl Component {
         };
         let mut text = Cow::from(text);
         if let Some(comparison) = comparison {
-            write!(text.to_mut(), " ({})", comparison::shorten(comparison)).unwrap();
+            write!(
+                text.to_mut(),
+                " ({})",
+                comparison::shorten_dynamic(comparison)
+            )
+            .unwrap();
         }
         text
     }
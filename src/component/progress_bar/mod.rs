@@ -0,0 +1,280 @@
+//! Provides the Progress Bar Component and relevant types for using it. The
+//! Progress Bar Component visualizes how far through the run the runner
+//! currently is. The bar is filled based on the current time compared to the
+//! final time of the chosen comparison, while a second, thinner bar shows how
+//! many of the segments are already completed. Ticks mark the points in time
+//! the comparison reaches each of the splits.
+
+use crate::{
+    analysis::{delta, state_helper},
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, SemanticColor, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, TimerPhase,
+};
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Progress Bar Component visualizes how far through the run the runner
+/// currently is, both by the expected time and by the amount of segments
+/// completed. The bar is colored based on whether the runner is currently
+/// ahead or behind the chosen comparison.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`.
+    pub comparison_override: Option<String>,
+    /// The height of the progress bar.
+    pub height: u32,
+    /// Specifies whether ticks should be shown at the points in time the
+    /// comparison reaches each of the splits.
+    pub show_segment_ticks: bool,
+    /// Specifies whether a second bar should be shown that visualizes the
+    /// amount of segments that are already completed.
+    pub show_segment_progress: bool,
+    /// The background color of the bars.
+    pub background_color: Color,
+    /// The color of the segment ticks.
+    pub tick_color: Color,
+    /// The color of the bar visualizing the amount of segments that are
+    /// already completed.
+    pub segment_progress_color: Color,
+}
+
+/// The state object describes the information to visualize for this component.
+/// All coordinates are in the range `0..1`.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// How far through the run the runner is based on the current time
+    /// compared to the final time of the comparison. If the comparison doesn't
+    /// have a final time, this is the same as the segment progress.
+    pub time_progress: f32,
+    /// The ratio of completed segments to all the segments.
+    pub segment_progress: f32,
+    /// The x-coordinates of the ticks marking the points in time the comparison
+    /// reaches each of the splits. The final split is not included, as it is
+    /// always at the end of the bar.
+    pub ticks: Vec<f32>,
+    /// Specifies whether the current position, which is at the end of the
+    /// filled region of the bar, should be marked. This is the case while an
+    /// attempt is in progress.
+    pub show_current_position: bool,
+    /// The color of the filled region of the bar. This is based on whether the
+    /// runner is currently ahead or behind the comparison.
+    pub fill_color: Color,
+    /// The semantic coloring information the fill color is based on.
+    pub semantic_color: SemanticColor,
+    /// The background color of the bars.
+    pub background_color: Color,
+    /// The color of the segment ticks.
+    pub tick_color: Color,
+    /// The color of the bar visualizing the amount of segments that are
+    /// already completed. This is `None` if the bar is not supposed to be
+    /// shown.
+    pub segment_progress_color: Option<Color>,
+    /// The height of the progress bar.
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            comparison_override: None,
+            height: 24,
+            show_segment_ticks: true,
+            show_segment_progress: true,
+            background_color: Color::rgba(1.0, 1.0, 1.0, 0.1),
+            tick_color: Color::rgba(0.0, 0.0, 0.0, 0.4),
+            segment_progress_color: Color::rgba(1.0, 1.0, 1.0, 0.6),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Progress Bar Component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Progress Bar Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if let Some(comparison) = &self.settings.comparison_override {
+            format!("Progress Bar ({})", comparison::shorten_dynamic(comparison)).into()
+        } else {
+            "Progress Bar".into()
+        }
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+        let method = timer.current_timing_method();
+        let segments = timer.run().segments();
+        let phase = timer.current_phase();
+
+        let completed_segments = match phase {
+            TimerPhase::NotRunning => 0,
+            TimerPhase::Ended => segments.len(),
+            _ => timer.current_split_index().unwrap_or_default(),
+        };
+        state.segment_progress = completed_segments as f32 / segments.len() as f32;
+
+        let final_time = segments
+            .last()
+            .and_then(|s| s.comparison(comparison)[method])
+            .map(|t| t.total_seconds())
+            .filter(|&t| t > 0.0);
+
+        state.time_progress = match (phase, final_time) {
+            (TimerPhase::NotRunning, _) => 0.0,
+            (TimerPhase::Ended, _) => 1.0,
+            (_, Some(final_time)) => {
+                let current_time = timer.current_time()[method].unwrap_or_default();
+                (current_time.total_seconds() / final_time).clamp(0.0, 1.0) as f32
+            }
+            (_, None) => state.segment_progress,
+        };
+
+        state.ticks.clear();
+        if self.settings.show_segment_ticks {
+            if let Some(final_time) = final_time {
+                state.ticks.extend(
+                    segments[..segments.len() - 1]
+                        .iter()
+                        .filter_map(|s| s.comparison(comparison)[method])
+                        .map(|t| (t.total_seconds() / final_time).clamp(0.0, 1.0) as f32),
+                );
+            } else {
+                let len = segments.len() as f32;
+                state
+                    .ticks
+                    .extend((1..segments.len()).map(|i| i as f32 / len));
+            }
+        }
+
+        let (delta, use_live_delta) = delta::calculate(timer, comparison);
+        let mut index = timer.current_split_index();
+        if !use_live_delta {
+            index = index.and_then(|i| i.checked_sub(1));
+        }
+
+        state.semantic_color = match index {
+            Some(index) => {
+                state_helper::split_color(timer, delta, index, true, false, comparison, method)
+            }
+            None => SemanticColor::Default,
+        };
+        state.fill_color = state.semantic_color.visualize(layout_settings);
+
+        state.show_current_position = matches!(phase, TimerPhase::Running | TimerPhase::Paused);
+        state.background_color = self.settings.background_color;
+        state.tick_color = self.settings.tick_color;
+        state.segment_progress_color = if self.settings.show_segment_progress {
+            Some(self.settings.segment_progress_color)
+        } else {
+            None
+        };
+        state.height = self.settings.height;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = State::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+            Field::new(
+                "Show Segment Ticks".into(),
+                self.settings.show_segment_ticks.into(),
+            ),
+            Field::new(
+                "Show Segment Progress".into(),
+                self.settings.show_segment_progress.into(),
+            ),
+            Field::new(
+                "Background Color".into(),
+                self.settings.background_color.into(),
+            ),
+            Field::new("Tick Color".into(), self.settings.tick_color.into()),
+            Field::new(
+                "Segment Progress Color".into(),
+                self.settings.segment_progress_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.comparison_override = value.into(),
+            1 => self.settings.height = value.into_uint().unwrap() as _,
+            2 => self.settings.show_segment_ticks = value.into(),
+            3 => self.settings.show_segment_progress = value.into(),
+            4 => self.settings.background_color = value.into(),
+            5 => self.settings.tick_color = value.into(),
+            6 => self.settings.segment_progress_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
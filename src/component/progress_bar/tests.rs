@@ -0,0 +1,92 @@
+use super::Component;
+use crate::{
+    settings::SemanticColor,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, TimeSpan,
+};
+
+#[test]
+fn is_empty_when_not_running() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let component = Component::new();
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.time_progress, 0.0);
+    assert_eq!(state.segment_progress, 0.0);
+    assert_eq!(state.ticks, [0.25, 0.5, 0.75]);
+    assert!(!state.show_current_position);
+    assert_eq!(state.semantic_color, SemanticColor::Default);
+}
+
+#[test]
+fn progresses_based_on_the_comparison() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(8.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(16.0));
+
+    let component = Component::new();
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.time_progress, 0.4);
+    assert_eq!(state.segment_progress, 0.25);
+    assert!(state.show_current_position);
+    assert_eq!(state.semantic_color, SemanticColor::AheadGainingTime);
+
+    timer.set_game_time(TimeSpan::from_seconds(25.0));
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+}
+
+#[test]
+fn is_full_when_ended() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0), Some(25.0)]);
+
+    let component = Component::new();
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.time_progress, 1.0);
+    assert_eq!(state.segment_progress, 1.0);
+    assert!(!state.show_current_position);
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+}
+
+#[test]
+fn falls_back_to_segment_progress_without_comparison() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(8.0), Some(12.0)]);
+
+    let component = Component::new();
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.time_progress, 0.5);
+    assert_eq!(state.segment_progress, 0.5);
+    assert_eq!(state.ticks, [0.25, 0.5, 0.75]);
+}
+
+#[test]
+fn hides_ticks_and_segment_progress() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().show_segment_ticks = false;
+    component.settings_mut().show_segment_progress = false;
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert!(state.ticks.is_empty());
+    assert_eq!(state.segment_progress_color, None);
+}
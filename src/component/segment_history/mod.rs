@@ -0,0 +1,353 @@
+//! Provides the Segment History Component and relevant types for using it. The
+//! Segment History Component visualizes the distribution of the segment times
+//! achieved in the past for the current segment or a segment of choice. The
+//! segment times are either shown as a histogram or as a sparkline of the most
+//! recent attempts. Markers show where the best segment time, the segment time
+//! of the Personal Best and the live segment time are within the distribution.
+//!
+//! All the positions in the state are expressed along the time axis, which is
+//! normalized to the range `0..1`. For a histogram the time axis is horizontal,
+//! while for a sparkline it is vertical.
+
+use crate::{
+    analysis::state_helper,
+    comparison::personal_best,
+    platform::prelude::*,
+    settings::{Color, Field, SemanticColor, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, Segment, TimeSpan, TimerPhase, TimingMethod,
+};
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Segment History Component visualizes the distribution of the segment
+/// times achieved in the past for a segment, which gives the runner immediate
+/// feedback on whether the segment is going well.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// Describes how the Segment History Component visualizes the segment times.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HistoryStyle {
+    /// The segment times are grouped into buckets of equal size, which are
+    /// shown as bars. The height of a bar is proportional to the amount of
+    /// segment times within the bucket.
+    #[default]
+    Histogram,
+    /// The segment times of the most recent attempts are shown in
+    /// chronological order as a line.
+    Sparkline,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The name of the segment to show the segment history of. Uses the
+    /// current segment if set to `None` or if there's no segment with that
+    /// name.
+    pub segment_override: Option<String>,
+    /// How the segment times are visualized.
+    pub style: HistoryStyle,
+    /// The amount of buckets the segment times are grouped into when they are
+    /// shown as a histogram.
+    pub buckets: u32,
+    /// The amount of most recent attempts that are shown when the segment
+    /// times are shown as a sparkline.
+    pub sparkline_attempts: u32,
+    /// Specifies whether the live segment time should be marked.
+    pub show_live_segment_time: bool,
+    /// The height of the chart.
+    pub height: u32,
+    /// The background color of the chart.
+    pub background_color: Color,
+    /// The color of the histogram's bars or the sparkline.
+    pub history_color: Color,
+}
+
+/// The state object describes the information to visualize for this component.
+/// All coordinates are in the range `0..1`.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// How the segment times are supposed to be visualized.
+    pub style: HistoryStyle,
+    /// For a histogram, these are the heights of the bars, which all have the
+    /// same width and are laid out from the fastest to the slowest bucket. For
+    /// a sparkline, these are the positions of the segment times along the
+    /// time axis in chronological order, with the points evenly spaced out
+    /// horizontally.
+    pub values: Vec<f32>,
+    /// The position of the best segment time along the time axis.
+    pub best_segment: Option<f32>,
+    /// The position of the segment time of the Personal Best along the time
+    /// axis.
+    pub personal_best: Option<f32>,
+    /// The position of the live segment time along the time axis. This is
+    /// `None` if the segment is not the one currently being run.
+    pub live_segment_time: Option<f32>,
+    /// The semantic coloring information the live segment time carries.
+    pub live_segment_semantic_color: SemanticColor,
+    /// The background color of the chart.
+    pub background_color: Color,
+    /// The color of the histogram's bars or the sparkline.
+    pub history_color: Color,
+    /// The color of the best segment time's marker.
+    pub best_segment_color: Color,
+    /// The color of the Personal Best's segment time marker.
+    pub personal_best_color: Color,
+    /// The color of the live segment time's marker.
+    pub live_segment_color: Color,
+    /// The height of the chart.
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            segment_override: None,
+            style: HistoryStyle::Histogram,
+            buckets: 16,
+            sparkline_attempts: 30,
+            show_live_segment_time: true,
+            height: 60,
+            background_color: Color::rgba(1.0, 1.0, 1.0, 0.05),
+            history_color: Color::rgba(1.0, 1.0, 1.0, 0.5),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Segment History Component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Segment History Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if let Some(segment) = &self.settings.segment_override {
+            format!("Segment History ({segment})").into()
+        } else {
+            "Segment History".into()
+        }
+    }
+
+    fn segment_index(&self, timer: &Snapshot<'_>) -> usize {
+        let segments = timer.run().segments();
+        self.settings
+            .segment_override
+            .as_deref()
+            .and_then(|name| segments.iter().position(|s| s.name() == name))
+            .or_else(|| timer.current_split_index())
+            .unwrap_or_default()
+            .min(segments.len() - 1)
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let method = timer.current_timing_method();
+        let segments = timer.run().segments();
+        let index = self.segment_index(timer);
+
+        // The segment times are iterated multiple times instead of being
+        // collected, so no memory needs to be allocated on every update.
+        let times = segment_times(segments, index, method);
+        let skipped = if self.settings.style == HistoryStyle::Sparkline {
+            let count = self.settings.sparkline_attempts as usize;
+            times.clone().count().saturating_sub(count)
+        } else {
+            0
+        };
+        let times = times.skip(skipped);
+
+        let best_segment = segments[index].best_segment_time()[method];
+        let personal_best = state_helper::comparison_single_segment_time(
+            timer.run(),
+            index,
+            personal_best::NAME,
+            method,
+        );
+
+        let live_segment_time = if self.settings.show_live_segment_time
+            && matches!(
+                timer.current_phase(),
+                TimerPhase::Running | TimerPhase::Paused
+            )
+            && timer.current_split_index() == Some(index)
+        {
+            state_helper::live_segment_time(timer, index, method)
+        } else {
+            None
+        };
+
+        let (min, max) = times
+            .clone()
+            .chain(best_segment)
+            .chain(personal_best)
+            .map(|t| t.total_seconds())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), t| {
+                (min.min(t), max.max(t))
+            });
+        let (min, max) = if min > max {
+            (0.0, 1.0)
+        } else if max - min < 1.0 {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        };
+        let position =
+            |t: TimeSpan| ((t.total_seconds() - min) / (max - min)).clamp(0.0, 1.0) as f32;
+
+        state.values.clear();
+        match self.settings.style {
+            HistoryStyle::Histogram => {
+                let buckets = self.settings.buckets.max(1) as usize;
+                state.values.resize(buckets, 0.0);
+                for time in times {
+                    let bucket = (position(time) * buckets as f32) as usize;
+                    state.values[bucket.min(buckets - 1)] += 1.0;
+                }
+                let highest = state.values.iter().copied().fold(0.0, f32::max);
+                if highest > 0.0 {
+                    for value in &mut state.values {
+                        *value /= highest;
+                    }
+                }
+            }
+            HistoryStyle::Sparkline => state.values.extend(times.map(position)),
+        }
+
+        state.live_segment_semantic_color = match (live_segment_time, personal_best) {
+            (Some(live), _) if best_segment.map_or(false, |best| live < best) => {
+                SemanticColor::BestSegment
+            }
+            (Some(live), Some(pb)) if live < pb => SemanticColor::AheadGainingTime,
+            (Some(_), Some(_)) => SemanticColor::BehindLosingTime,
+            _ => SemanticColor::Default,
+        };
+
+        state.style = self.settings.style;
+        state.best_segment = best_segment.map(position);
+        state.personal_best = personal_best.map(position);
+        state.live_segment_time = live_segment_time.map(position);
+        state.background_color = self.settings.background_color;
+        state.history_color = self.settings.history_color;
+        state.best_segment_color = layout_settings.best_segment_color;
+        state.personal_best_color = layout_settings.personal_best_color;
+        state.live_segment_color = state.live_segment_semantic_color.visualize(layout_settings);
+        state.height = self.settings.height;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = State::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Segment".into(),
+                self.settings.segment_override.clone().into(),
+            ),
+            Field::new("Style".into(), self.settings.style.into()),
+            Field::new("Buckets".into(), u64::from(self.settings.buckets).into()),
+            Field::new(
+                "Sparkline Attempts".into(),
+                u64::from(self.settings.sparkline_attempts).into(),
+            ),
+            Field::new(
+                "Show Live Segment Time".into(),
+                self.settings.show_live_segment_time.into(),
+            ),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+            Field::new(
+                "Background Color".into(),
+                self.settings.background_color.into(),
+            ),
+            Field::new("History Color".into(), self.settings.history_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.segment_override = value.into(),
+            1 => self.settings.style = value.into(),
+            2 => self.settings.buckets = value.into_uint().unwrap() as _,
+            3 => self.settings.sparkline_attempts = value.into_uint().unwrap() as _,
+            4 => self.settings.show_live_segment_time = value.into(),
+            5 => self.settings.height = value.into_uint().unwrap() as _,
+            6 => self.settings.background_color = value.into(),
+            7 => self.settings.history_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// Iterates over the segment times the runner actually achieved for the
+/// segment in chronological order. Segment times of attempts that skipped the
+/// previous split are combined segment times and are therefore skipped.
+fn segment_times(
+    segments: &[Segment],
+    index: usize,
+    method: TimingMethod,
+) -> impl Iterator<Item = TimeSpan> + Clone + '_ {
+    let previous_segment = index.checked_sub(1).map(|i| &segments[i]);
+    segments[index]
+        .segment_history()
+        .iter_actual_runs()
+        .filter(move |&&(id, _)| {
+            !catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false)
+        })
+        .filter_map(move |(_, time)| time[method])
+}
@@ -0,0 +1,88 @@
+use super::{Component, HistoryStyle};
+use crate::{
+    settings::SemanticColor,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, TimeSpan, Timer,
+};
+
+fn timer_with_history() -> Timer {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[14.0, 24.0]);
+    run_with_splits(&mut timer, &[12.0, 22.0]);
+    run_with_splits(&mut timer, &[13.0, 23.0]);
+    timer
+}
+
+#[test]
+fn histogram() {
+    let timer = timer_with_history();
+
+    let mut component = Component::new();
+    component.settings_mut().buckets = 4;
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.style, HistoryStyle::Histogram);
+    // The segment times are 10, 14, 12 and 13 seconds.
+    assert_eq!(state.values, [0.5, 0.0, 0.5, 1.0]);
+    assert_eq!(state.best_segment, Some(0.0));
+    assert_eq!(state.personal_best, Some(0.0));
+    assert_eq!(state.live_segment_time, None);
+}
+
+#[test]
+fn sparkline_shows_the_most_recent_attempts() {
+    let timer = timer_with_history();
+
+    let mut component = Component::new();
+    component.settings_mut().style = HistoryStyle::Sparkline;
+    component.settings_mut().sparkline_attempts = 3;
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.values, [1.0, 0.5, 0.75]);
+}
+
+#[test]
+fn uses_the_segment_chosen() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().style = HistoryStyle::Sparkline;
+    component.settings_mut().segment_override = Some(String::from("B"));
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.values, [0.0, 1.0]);
+    assert_eq!(component.name(), "Segment History (B)");
+}
+
+#[test]
+fn marks_the_live_segment_time() {
+    let mut timer = timer_with_history();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(21.0));
+
+    let component = Component::new();
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    // The history of the second segment only contains 10 second segments, so
+    // the range is padded by half a second in each direction.
+    assert_eq!(state.live_segment_time, Some(0.5));
+    assert_eq!(
+        state.live_segment_semantic_color,
+        SemanticColor::BehindLosingTime
+    );
+
+    timer.set_game_time(TimeSpan::from_seconds(20.5));
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+
+    assert_eq!(state.live_segment_time, Some(0.0));
+    assert_eq!(
+        state.live_segment_semantic_color,
+        SemanticColor::BestSegment
+    );
+}
@@ -96,7 +96,9 @@ impl Component {
                 comparison::average_segments::NAME => "Average Segment Time".into(),
                 comparison::median_segments::NAME => "Median Segment Time".into(),
                 comparison::latest_run::NAME => "Latest Segment Time".into(),
-                comparison => format!("Segment Time ({})", comparison::shorten(comparison)).into(),
+                comparison => {
+                    format!("Segment Time ({})", comparison::shorten_dynamic(comparison)).into()
+                }
             }
         } else {
             "Segment Time".into()
@@ -1,6 +1,6 @@
 use crate::{
     analysis::{self, possible_time_save, split_color},
-    comparison,
+    comparison::{self, best_segments},
     component::splits::Settings as SplitsSettings,
     platform::prelude::*,
     settings::{Color, SemanticColor},
@@ -57,6 +57,22 @@ pub struct TimeColumn {
     /// of the Timer is used for showing the time. Otherwise the Timing Method
     /// provided is used.
     pub timing_method: Option<TimingMethod>,
+    /// Rules that override the color of the value based on thresholds. The
+    /// rule with the highest threshold that the value reaches determines the
+    /// color. If the value doesn't reach any of the thresholds, the color is
+    /// based on the value's semantic coloring information.
+    pub color_rules: Vec<ColorRule>,
+}
+
+/// A rule that colors the value of a time column once it reaches a threshold.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorRule {
+    /// The threshold the value needs to be greater than or equal to for the
+    /// rule to apply.
+    pub threshold: TimeSpan,
+    /// The color to use for the value.
+    pub color: Color,
 }
 
 /// A column that shows a variable.
@@ -113,6 +129,20 @@ pub enum ColumnUpdateWith {
     /// Segment component. If there is no time saved or lost, then value gets
     /// replaced by the current attempt's segment time instead.
     SegmentDeltaWithFallback,
+    /// The value gets replaced by how much the delta changed compared to the
+    /// delta of the previous split, which is the time gained or lost on the
+    /// segment. Unlike the segment delta, the value is only colored based on
+    /// whether time was gained or lost, regardless of whether the runner is
+    /// ahead or behind and regardless of best segments.
+    DeltaChange,
+    /// The value gets replaced by how much time could have been saved on the
+    /// segment in the current attempt, which is how much longer the current
+    /// attempt's segment time is than the best segment time.
+    PossibleTimeSave,
+    /// The value gets replaced by the best possible final time at this split,
+    /// which is the current attempt's split time followed by the best segments
+    /// of all the remaining segments.
+    BestPossibleTime,
 }
 
 /// Specifies when a column's value gets updated.
@@ -147,6 +177,7 @@ impl Default for TimeColumn {
             update_trigger: ColumnUpdateTrigger::Contextual,
             comparison_override: None,
             timing_method: None,
+            color_rules: Vec::new(),
         }
     }
 }
@@ -310,7 +341,20 @@ fn update_time_column(
         };
     }
     state.semantic_color = semantic_color;
-    state.visual_color = semantic_color.visualize(layout_settings);
+    state.visual_color = column_value
+        .filter(|_| !is_empty)
+        .and_then(|value| rule_color(&column_settings.color_rules, value))
+        .unwrap_or_else(|| semantic_color.visualize(layout_settings));
+}
+
+/// Determines the color of the rule with the highest threshold that the value
+/// reaches.
+fn rule_color(rules: &[ColorRule], value: TimeSpan) -> Option<Color> {
+    rules
+        .iter()
+        .filter(|rule| value >= rule.threshold)
+        .max_by_key(|rule| rule.threshold)
+        .map(|rule| rule.color)
 }
 
 fn time_column_update_value(
@@ -425,6 +469,63 @@ fn time_column_update_value(
             SemanticColor::Default,
             ColumnFormatter::Delta,
         ),
+
+        (DeltaChange, false) => {
+            let delta = analysis::previous_segment_delta(timer, segment_index, comparison, method);
+            let semantic_color = match delta {
+                Some(delta) if delta < TimeSpan::zero() => SemanticColor::AheadGainingTime,
+                Some(delta) if delta > TimeSpan::zero() => SemanticColor::BehindLosingTime,
+                _ => SemanticColor::Default,
+            };
+            (delta, semantic_color, ColumnFormatter::Delta)
+        }
+        (DeltaChange, true) => (
+            analysis::live_segment_delta(timer, segment_index, comparison, method),
+            SemanticColor::Default,
+            ColumnFormatter::Delta,
+        ),
+
+        (PossibleTimeSave, false) => (
+            analysis::previous_segment_delta(timer, segment_index, best_segments::NAME, method)
+                .map(|delta| delta.max(TimeSpan::zero())),
+            SemanticColor::Default,
+            ColumnFormatter::SegmentTime,
+        ),
+        (PossibleTimeSave, true) => (
+            analysis::live_segment_delta(timer, segment_index, best_segments::NAME, method)
+                .map(|delta| delta.max(TimeSpan::zero())),
+            SemanticColor::Default,
+            ColumnFormatter::SegmentTime,
+        ),
+
+        (BestPossibleTime, false) => (
+            catch! {
+                segment.split_time()[method]?
+                    - segment.comparison(best_segments::NAME)[method]?
+                    + timer.run().segments().last()?.comparison(best_segments::NAME)[method]?
+            },
+            SemanticColor::Default,
+            ColumnFormatter::Time,
+        ),
+        (BestPossibleTime, true) => {
+            // Until the best segment is exceeded, the best possible time is
+            // based on the previous split.
+            let previous_delta =
+                analysis::last_delta(timer.run(), segment_index, best_segments::NAME, method)
+                    .unwrap_or_default();
+            let live_delta = catch! {
+                timer.current_time()[method]?
+                    - segment.comparison(best_segments::NAME)[method]?
+            };
+            (
+                catch! {
+                    timer.run().segments().last()?.comparison(best_segments::NAME)[method]?
+                        + live_delta.map_or(previous_delta, |d| d.max(previous_delta))
+                },
+                SemanticColor::Default,
+                ColumnFormatter::Time,
+            )
+        }
     };
 
     Some((value, is_live))
@@ -433,7 +534,10 @@ fn time_column_update_value(
 impl ColumnUpdateWith {
     const fn is_segment_based(self) -> bool {
         use ColumnUpdateWith::*;
-        matches!(self, SegmentDelta | SegmentTime | SegmentDeltaWithFallback)
+        matches!(
+            self,
+            SegmentDelta | SegmentTime | SegmentDeltaWithFallback | DeltaChange | PossibleTimeSave
+        )
     }
 
     const fn has_fallback(self) -> bool {
@@ -3,7 +3,8 @@
 //! segment is shown in a tabular fashion showing the segment icon, segment
 //! name, the delta compared to the chosen comparison, and the split time. The
 //! list provides scrolling functionality, so not every segment needs to be
-//! shown all the time.
+//! shown all the time. Long runs can additionally be grouped into sections,
+//! where only the current section is shown in full.
 
 use crate::{
     platform::prelude::*,
@@ -13,7 +14,7 @@ use crate::{
     },
     timing::{formatter::Accuracy, Snapshot},
     util::{Clear, ClearVec},
-    GeneralLayoutSettings,
+    GeneralLayoutSettings, Segment,
 };
 use core::cmp::{max, min};
 use serde::{Deserialize, Serialize};
@@ -24,13 +25,15 @@ mod tests;
 mod column;
 
 pub use column::{
-    ColumnKind, ColumnSettings, ColumnStartWith, ColumnState, ColumnUpdateTrigger,
+    ColorRule, ColumnKind, ColumnSettings, ColumnStartWith, ColumnState, ColumnUpdateTrigger,
     ColumnUpdateWith, TimeColumn, VariableColumn,
 };
 
 const SETTINGS_BEFORE_COLUMNS: usize = 15;
+const SETTINGS_AFTER_COLUMNS: usize = 2;
 const SETTINGS_PER_TIME_COLUMN: usize = 6;
 const SETTINGS_PER_VARIABLE_COLUMN: usize = 2;
+const SETTINGS_PER_COLOR_RULE: usize = 2;
 
 /// The Splits Component is the main component for visualizing all the split
 /// times. Each segment is shown in a tabular fashion showing the segment icon,
@@ -43,6 +46,30 @@ pub struct Component {
     settings: Settings,
     current_split_index: Option<usize>,
     scroll_offset: isize,
+    rows: Vec<Row>,
+}
+
+/// A row in the list of splits. This is either an individual segment or a
+/// summary of a collapsed section, which is represented by the segment ending
+/// the section.
+#[derive(Copy, Clone)]
+struct Row {
+    index: usize,
+    is_collapsed_section: bool,
+}
+
+/// Describes how the window showing the segments automatically scrolls when
+/// the current segment changes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScrollMode {
+    /// The window scrolls just far enough to show the amount of upcoming
+    /// segments specified by the split preview count.
+    #[default]
+    Preview,
+    /// The window scrolls such that the current segment stays in the middle
+    /// row of the window, as long as there are enough segments before and after
+    /// it.
+    Centered,
 }
 
 /// The Settings for this component.
@@ -62,6 +89,17 @@ pub struct Settings {
     /// segments to be shown in this scrolling window when it automatically
     /// scrolls.
     pub split_preview_count: usize,
+    /// Specifies how the window showing the segments automatically scrolls
+    /// when the current segment changes.
+    pub scroll_mode: ScrollMode,
+    /// Specifies whether the segments should be grouped into sections, where
+    /// only the section containing the current segment is expanded and every
+    /// other section is collapsed into a single row. Segments whose names start
+    /// with a `-` are subsplits that belong to the section ended by the next
+    /// segment that is not a subsplit. The ending segment can name the section
+    /// by starting its name with the section's name in curly braces, such as
+    /// `{Forest} Boss`.
+    pub collapse_sections: bool,
     /// Specifies whether thin separators should be shown between the individual
     /// segments shown by the component.
     pub show_thin_separators: bool,
@@ -119,6 +157,11 @@ pub struct SplitState {
     /// there can be a scrolling window, showing only a subset of segments. Each
     /// index is guaranteed to be unique.
     pub index: usize,
+    /// Describes whether this row is a summary of a collapsed section rather
+    /// than an individual segment. The name is the name of the section and the
+    /// columns show the values of the segment ending the section, which is
+    /// also the segment the index refers to.
+    pub is_collapsed_section: bool,
 }
 
 impl Clear for SplitState {
@@ -188,6 +231,8 @@ impl Default for Settings {
             ),
             visual_split_count: 16,
             split_preview_count: 1,
+            scroll_mode: ScrollMode::Preview,
+            collapse_sections: false,
             show_thin_separators: true,
             separator_last_split: true,
             always_show_last_split: true,
@@ -211,6 +256,7 @@ impl Default for Settings {
                         update_trigger: ColumnUpdateTrigger::OnEndingSegment,
                         comparison_override: None,
                         timing_method: None,
+                        color_rules: Vec::new(),
                     }),
                 },
                 ColumnSettings {
@@ -221,6 +267,7 @@ impl Default for Settings {
                         update_trigger: ColumnUpdateTrigger::Contextual,
                         comparison_override: None,
                         timing_method: None,
+                        color_rules: Vec::new(),
                     }),
                 },
             ],
@@ -306,13 +353,30 @@ impl Component {
         let run = timer.run();
         self.icon_ids.resize(run.len(), CachedImageId::default());
 
+        let current_split = timer.current_split_index();
+        let method = timer.current_timing_method();
+
+        update_rows(
+            &mut self.rows,
+            run.segments(),
+            self.settings.collapse_sections,
+            current_split,
+        );
+        let row_count = self.rows.len();
+
         let mut visual_split_count = self.settings.visual_split_count;
         if visual_split_count == 0 {
-            visual_split_count = run.len();
+            visual_split_count = row_count;
         }
 
-        let current_split = timer.current_split_index();
-        let method = timer.current_timing_method();
+        // The row of the current segment. Collapsed sections never contain the
+        // current segment, so this is the first row at or after it.
+        let current_row = current_split.map(|current_split| {
+            self.rows
+                .iter()
+                .position(|row| row.index >= current_split)
+                .unwrap_or(row_count)
+        });
 
         let locked_last_split = if self.settings.always_show_last_split {
             1
@@ -320,21 +384,29 @@ impl Component {
             0
         };
         let skip_count = min(
-            current_split.map_or(0, |current_split| {
+            current_row.map_or(0, |current_row| {
                 max(
                     0,
-                    current_split as isize
-                        + self.settings.split_preview_count as isize
-                        + locked_last_split
-                        + 1
-                        - visual_split_count as isize,
+                    match self.settings.scroll_mode {
+                        ScrollMode::Preview => {
+                            current_row as isize
+                                + self.settings.split_preview_count as isize
+                                + locked_last_split
+                                + 1
+                                - visual_split_count as isize
+                        }
+                        ScrollMode::Centered => {
+                            current_row as isize
+                                - (visual_split_count as isize - locked_last_split - 1) / 2
+                        }
+                    },
                 )
             }),
-            run.len() as isize - visual_split_count as isize,
+            row_count as isize - visual_split_count as isize,
         );
         self.scroll_offset = min(
             max(self.scroll_offset, -skip_count),
-            run.len() as isize - skip_count - visual_split_count as isize,
+            row_count as isize - skip_count - visual_split_count as isize,
         );
         let skip_count = max(0, skip_count + self.scroll_offset) as usize;
         let take_count = visual_split_count - locked_last_split as usize;
@@ -342,12 +414,13 @@ impl Component {
 
         let show_final_separator = self.settings.separator_last_split
             && always_show_last_split
-            && skip_count + take_count + 1 < run.len();
+            && skip_count + take_count + 1 < row_count;
 
         let Settings {
             show_thin_separators,
             fill_with_blank_space,
             display_two_rows,
+            collapse_sections,
             ref columns,
             ..
         } = self.settings;
@@ -368,31 +441,41 @@ impl Component {
         icon_changes.clear();
 
         state.splits.clear();
-        for ((i, segment), icon_id) in run
-            .segments()
+        for (_, row) in self
+            .rows
             .iter()
             .enumerate()
-            .zip(self.icon_ids.iter_mut())
             .skip(skip_count)
-            .filter(|&((i, _), _)| {
-                i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
+            .filter(|&(position, _)| {
+                position - skip_count < take_count
+                    || (always_show_last_split && position + 1 == row_count)
             })
         {
+            let i = row.index;
+            let segment = &run.segments()[i];
+
             let state = state.splits.push_with(|| SplitState {
                 name: String::new(),
                 columns: ClearVec::new(),
                 is_current_split: false,
                 index: 0,
+                is_collapsed_section: false,
             });
 
-            if let Some(icon_change) = icon_id.update_with(Some(segment.icon())) {
+            if let Some(icon_change) = self.icon_ids[i].update_with(Some(segment.icon())) {
                 icon_changes.push(IconChange {
                     segment_index: i,
                     icon: icon_change.into(),
                 });
             }
 
-            state.name.push_str(segment.name());
+            state.name.push_str(if !collapse_sections {
+                segment.name()
+            } else if row.is_collapsed_section {
+                section_name(segment.name())
+            } else {
+                name_within_section(segment.name())
+            });
 
             for column in columns {
                 column::update_state(
@@ -415,6 +498,7 @@ impl Component {
 
             state.is_current_split = Some(i) == current_split;
             state.index = i;
+            state.is_collapsed_section = row.is_collapsed_section;
         }
 
         if fill_with_blank_space && state.splits.len() < visual_split_count {
@@ -425,9 +509,11 @@ impl Component {
                     columns: ClearVec::new(),
                     is_current_split: false,
                     index: 0,
+                    is_collapsed_section: false,
                 });
                 state.is_current_split = false;
                 state.index = (usize::max_value() ^ 1) - 2 * i;
+                state.is_collapsed_section = false;
             }
         }
 
@@ -517,11 +603,18 @@ impl Component {
             self.settings
                 .columns
                 .iter()
-                .map(|column| match column.kind {
+                .map(|column| match &column.kind {
                     ColumnKind::Variable(_) => SETTINGS_PER_VARIABLE_COLUMN,
-                    ColumnKind::Time(_) => SETTINGS_PER_TIME_COLUMN,
+                    // The amount of color rules is an additional setting
+                    // that follows the settings of the column itself.
+                    ColumnKind::Time(column) => {
+                        SETTINGS_PER_TIME_COLUMN
+                            + 1
+                            + SETTINGS_PER_COLOR_RULE * column.color_rules.len()
+                    }
                 })
-                .sum(),
+                .sum::<usize>()
+                + SETTINGS_AFTER_COLUMNS,
         );
 
         for column in &self.settings.columns {
@@ -563,10 +656,31 @@ impl Component {
                         "Timing Method".into(),
                         column.timing_method.into(),
                     ));
+                    settings.fields.push(Field::new(
+                        "Color Rules".into(),
+                        Value::UInt(column.color_rules.len() as _),
+                    ));
+                    for rule in &column.color_rules {
+                        settings
+                            .fields
+                            .push(Field::new("Threshold".into(), rule.threshold.into()));
+                        settings
+                            .fields
+                            .push(Field::new("Color".into(), rule.color.into()));
+                    }
                 }
             }
         }
 
+        settings.fields.push(Field::new(
+            "Scroll Mode".into(),
+            self.settings.scroll_mode.into(),
+        ));
+        settings.fields.push(Field::new(
+            "Collapse Sections".into(),
+            self.settings.collapse_sections.into(),
+        ));
+
         settings
     }
 
@@ -626,22 +740,109 @@ impl Component {
                             index -= 1;
                         }
                         ColumnKind::Time(column) => {
-                            if index < 5 {
+                            if index < 6 {
                                 match index {
                                     0 => column.start_with = value.into(),
                                     1 => column.update_with = value.into(),
                                     2 => column.update_trigger = value.into(),
                                     3 => column.comparison_override = value.into(),
-                                    _ => column.timing_method = value.into(),
+                                    4 => column.timing_method = value.into(),
+                                    _ => {
+                                        let new_len = value.into_uint().unwrap() as usize;
+                                        column.color_rules.resize(new_len, Default::default());
+                                    }
                                 }
                                 return;
                             }
-                            index -= 5;
+                            index -= 6;
+                            let rule_settings = SETTINGS_PER_COLOR_RULE * column.color_rules.len();
+                            if index < rule_settings {
+                                let rule = &mut column.color_rules[index / SETTINGS_PER_COLOR_RULE];
+                                match index % SETTINGS_PER_COLOR_RULE {
+                                    0 => rule.threshold = value.into(),
+                                    _ => rule.color = value.into(),
+                                }
+                                return;
+                            }
+                            index -= rule_settings;
                         }
                     }
                 }
-                panic!("Unsupported Setting Index")
+                match index {
+                    0 => self.settings.scroll_mode = value.into(),
+                    1 => self.settings.collapse_sections = value.into(),
+                    _ => panic!("Unsupported Setting Index"),
+                }
             }
         }
     }
 }
+
+/// Subsplits are segments whose names start with a `-`. They belong to the
+/// section that is ended by the next segment that is not a subsplit.
+fn is_subsplit(name: &str) -> bool {
+    name.starts_with('-')
+}
+
+/// The segment ending a section may start its name with the section's name in
+/// curly braces. Otherwise the section is named after the segment itself.
+fn section_name(name: &str) -> &str {
+    name.strip_prefix('{')
+        .and_then(|rest| rest.split_once('}'))
+        .map_or(name, |(section, _)| section.trim())
+}
+
+/// The name of a segment as shown within an expanded section, without the
+/// subsplit marker or the section's name.
+fn name_within_section(name: &str) -> &str {
+    if let Some(name) = name.strip_prefix('-') {
+        return name;
+    }
+    name.strip_prefix('{')
+        .and_then(|rest| rest.split_once('}'))
+        .map_or(name, |(_, name)| name.trim_start())
+}
+
+/// Determines the rows to show for the segments. If sections are collapsed,
+/// every section but the one containing the current segment is represented by
+/// a single row.
+fn update_rows(
+    rows: &mut Vec<Row>,
+    segments: &[Segment],
+    collapse_sections: bool,
+    current_split: Option<usize>,
+) {
+    rows.clear();
+
+    if !collapse_sections {
+        rows.extend((0..segments.len()).map(|index| Row {
+            index,
+            is_collapsed_section: false,
+        }));
+        return;
+    }
+
+    let mut section_start = 0;
+    for (index, segment) in segments.iter().enumerate() {
+        if is_subsplit(segment.name()) && index + 1 != segments.len() {
+            continue;
+        }
+
+        let is_current_section =
+            current_split.map_or(false, |current| (section_start..=index).contains(&current));
+
+        if section_start == index || is_current_section {
+            rows.extend((section_start..=index).map(|index| Row {
+                index,
+                is_collapsed_section: false,
+            }));
+        } else {
+            rows.push(Row {
+                index,
+                is_collapsed_section: true,
+            });
+        }
+
+        section_start = index + 1;
+    }
+}
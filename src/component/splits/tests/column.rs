@@ -3,10 +3,13 @@ use super::{
     State,
 };
 use crate::{
-    component::splits::{ColumnKind, TimeColumn},
-    settings::SemanticColor::{
-        self, AheadGainingTime as AheadGaining, BehindLosingTime as BehindLosing,
-        BestSegment as Best, Default as Text,
+    component::splits::{ColorRule, ColumnKind, TimeColumn},
+    settings::{
+        Color,
+        SemanticColor::{
+            self, AheadGainingTime as AheadGaining, BehindLosingTime as BehindLosing,
+            BestSegment as Best, Default as Text,
+        },
     },
     util::tests_helper::{make_progress_run_with_splits_opt, run_with_splits_opt, start_run},
     Run, Segment, TimeSpan, Timer,
@@ -1343,3 +1346,110 @@ fn check_column_color(state: &State, split_index: usize, expected_color: Semanti
         expected_color
     );
 }
+
+fn state_after_gaining_and_losing_time(time_column: TimeColumn) -> State {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(6.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0), Some(8.0), Some(20.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(21.0));
+
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(time_column),
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    component.state(&timer.snapshot(), &Default::default())
+}
+
+#[test]
+fn delta_change() {
+    let state = state_after_gaining_and_losing_time(TimeColumn {
+        update_with: ColumnUpdateWith::DeltaChange,
+        ..Default::default()
+    });
+    check_column_state(
+        &state,
+        0,
+        &[(
+            ["−1.0", "+3.0", "+3.0", "", "", ""],
+            [AheadGaining, BehindLosing, BehindLosing, Text, Text, Text],
+        )],
+    );
+}
+
+#[test]
+fn possible_time_save_of_the_attempt() {
+    let state = state_after_gaining_and_losing_time(TimeColumn {
+        update_with: ColumnUpdateWith::PossibleTimeSave,
+        ..Default::default()
+    });
+    check_column_state(
+        &state,
+        0,
+        &[(
+            ["0.00", "3.00", "3.00", "", "", ""],
+            [Text, Text, Text, Text, Text, Text],
+        )],
+    );
+}
+
+#[test]
+fn best_possible_time() {
+    let state = state_after_gaining_and_losing_time(TimeColumn {
+        update_with: ColumnUpdateWith::BestPossibleTime,
+        ..Default::default()
+    });
+    check_column_state(
+        &state,
+        0,
+        &[(
+            ["0:29", "0:32", "0:35", "0:35", "", ""],
+            [Text, Text, Text, Text, Text, Text],
+        )],
+    );
+}
+
+#[test]
+fn color_rules_override_the_visual_color() {
+    let gained = Color::rgba(0.0, 1.0, 0.0, 1.0);
+    let lost = Color::rgba(1.0, 0.0, 0.0, 1.0);
+    let state = state_after_gaining_and_losing_time(TimeColumn {
+        update_with: ColumnUpdateWith::DeltaChange,
+        color_rules: vec![
+            ColorRule {
+                threshold: TimeSpan::from_seconds(3.0),
+                color: lost,
+            },
+            ColorRule {
+                threshold: TimeSpan::from_seconds(-10.0),
+                color: gained,
+            },
+        ],
+        ..Default::default()
+    });
+
+    let colors = state
+        .splits
+        .iter()
+        .map(|split| split.columns[0].visual_color)
+        .collect::<Vec<_>>();
+    assert_eq!(colors[..3], [gained, lost, lost]);
+    assert_eq!(colors[3], Text.visualize(&Default::default()));
+    check_column_color(&state, 1, BehindLosing);
+}
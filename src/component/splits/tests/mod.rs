@@ -1,9 +1,11 @@
 use super::{
-    ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, Component, Settings,
-    State,
+    ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, Component, ScrollMode,
+    Settings, State,
 };
 use crate::{
     component::splits::{ColumnKind, TimeColumn},
+    settings::Value,
+    util::tests_helper::create_timer,
     Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 
@@ -137,3 +139,120 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+fn names(state: &State) -> Vec<&str> {
+    state.splits.iter().map(|s| s.name.as_str()).collect()
+}
+
+#[test]
+fn collapses_all_but_the_current_section() {
+    let mut timer = create_timer(&["A", "-B1", "-B2", "{B} B3", "-C1", "C2", "D"]);
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        collapse_sections: true,
+        ..Default::default()
+    });
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(names(&state), ["A", "B", "C2", "D"]);
+    assert!(state.splits[1].is_collapsed_section);
+    assert_eq!(state.splits[1].index, 3);
+    assert!(!state.splits[3].is_collapsed_section);
+
+    timer.start();
+    timer.split();
+    timer.split();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(names(&state), ["A", "B1", "B2", "B3", "C2", "D"]);
+    assert!(state.splits[2].is_current_split);
+    assert!(state.splits.iter().take(4).all(|s| !s.is_collapsed_section));
+    assert!(state.splits[4].is_collapsed_section);
+
+    timer.split();
+    timer.split();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(names(&state), ["A", "B", "C1", "C2", "D"]);
+    assert!(state.splits[2].is_current_split);
+}
+
+#[test]
+fn centered_scroll_mode_keeps_current_split_in_the_middle() {
+    let mut timer = create_timer(&["A", "B", "C", "D", "E", "F", "G", "H"]);
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        always_show_last_split: false,
+        scroll_mode: ScrollMode::Centered,
+        ..Default::default()
+    });
+
+    timer.start();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(names(&state), ["A", "B", "C", "D", "E"]);
+
+    for _ in 0..4 {
+        timer.split();
+    }
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(names(&state), ["C", "D", "E", "F", "G"]);
+    assert!(state.splits[2].is_current_split);
+
+    for _ in 0..3 {
+        timer.split();
+    }
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(names(&state), ["D", "E", "F", "G", "H"]);
+}
+
+#[test]
+fn scroll_and_section_settings_come_after_the_columns() {
+    let mut component = Component::new();
+
+    let fields = component.settings_description().fields;
+    assert_eq!(fields[3].text, "Show Thin Separators");
+    assert_eq!(fields[14].text, "Columns");
+    let len = fields.len();
+    assert_eq!(fields[len - 2].text, "Scroll Mode");
+    assert_eq!(fields[len - 1].text, "Collapse Sections");
+
+    component.set_value(len - 2, ScrollMode::Centered.into());
+    component.set_value(len - 1, true.into());
+    assert_eq!(component.settings().scroll_mode, ScrollMode::Centered);
+    assert!(component.settings().collapse_sections);
+}
+
+#[test]
+fn color_rule_thresholds_are_time_settings() {
+    let mut component = Component::new();
+
+    let fields = component.settings_description().fields;
+    let rules = fields.iter().position(|f| f.text == "Color Rules").unwrap();
+    assert_eq!(fields[rules - 1].text, "Timing Method");
+    component.set_value(rules, Value::UInt(2));
+
+    let fields = component.settings_description().fields;
+    assert_eq!(fields[rules + 1].text, "Threshold");
+    assert_eq!(fields[rules + 3].text, "Threshold");
+
+    component.set_value(rules + 1, TimeSpan::from_seconds(-5.0).into());
+    component.set_value(rules + 3, Value::String("1:30.5".into()));
+    let column = match &component.settings().columns[0].kind {
+        ColumnKind::Time(column) => column,
+        _ => panic!("The first column is not a time column"),
+    };
+    assert_eq!(
+        column.color_rules[0].threshold,
+        TimeSpan::from_seconds(-5.0)
+    );
+    assert_eq!(
+        column.color_rules[1].threshold,
+        TimeSpan::from_seconds(90.5)
+    );
+
+    let fields = component.settings_description().fields;
+    assert!(matches!(
+        fields[rules + 1].value,
+        Value::TimeSpan(threshold) if threshold == TimeSpan::from_seconds(-5.0)
+    ));
+}
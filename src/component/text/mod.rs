@@ -1,7 +1,9 @@
 //! Provides the Text Component and relevant types for using it. The Text
 //! Component simply visualizes any given text. This can either be a single
 //! centered text, or split up into a left and right text, which is suitable for
-//! a situation where you have a label and a value.
+//! a situation where you have a label and a value. The texts may contain
+//! placeholders like `{game}` or `{var:deaths}` that get resolved against the
+//! timer.
 
 use super::key_value;
 use crate::{
@@ -15,15 +17,41 @@ use alloc::borrow::Cow;
 use core::mem;
 use serde::{Deserialize, Serialize};
 
+mod template;
+
 #[cfg(test)]
 mod tests;
 
 /// The Text Component simply visualizes any given text. This can either be a
 /// single centered text, or split up into a left and right text, which is
 /// suitable for a situation where you have a label and a value.
+///
+/// If placeholders are enabled, the texts are treated as templates. A
+/// placeholder is enclosed in curly braces and is replaced by the value it
+/// refers to, so `{game} - {category} | {attempts} attempts` may turn into
+/// `Celeste - Any% | 1234 attempts`. The following placeholders are supported:
+///
+/// - `game`, `category`, `extended_category`, `platform` and `region` for the
+///   information about the run.
+/// - `var:NAME` for the custom variable called `NAME` and `srcom:NAME` for the
+///   speedrun.com variable called `NAME`.
+/// - `attempts`, `completed_attempts` and `today_attempts` for the amount of
+///   attempts.
+/// - `comparison` and `comparison_short` for the current comparison.
+/// - `segment`, `split_index` and `splits` for the current segment.
+/// - `current_time`, `personal_best`, `sum_of_best`, `current_pace`,
+///   `total_playtime` and `today_playtime` for times.
+/// - `pb_chance` for the chance of beating the personal best.
+///
+/// Times can be formatted by appending a format separated by a `|`, like
+/// `{sum_of_best|seconds}`. The supported formats are `regular`, `seconds`,
+/// `tenths`, `hundredths`, `milliseconds`, `complete`, `days`, `segment` and
+/// `delta`. Literal curly braces can be written as `{{` and `}}`. Unknown
+/// placeholders are shown as they are.
 #[derive(Default, Clone)]
 pub struct Component {
     settings: Settings,
+    history_cache: template::HistoryCache,
 }
 
 /// The Settings for this component.
@@ -45,6 +73,10 @@ pub struct Settings {
     pub right_color: Option<Color>,
     /// The text to be shown.
     pub text: Text,
+    /// Specifies whether placeholders like `{game}` in the text are supposed
+    /// to be replaced by the values they refer to. This is ignored if a custom
+    /// variable is shown.
+    pub use_placeholders: bool,
 }
 
 /// The text that is supposed to be shown.
@@ -146,6 +178,7 @@ impl Default for Settings {
             left_center_color: None,
             right_color: None,
             text: Text::Center(String::from("")),
+            use_placeholders: false,
         }
     }
 }
@@ -169,7 +202,10 @@ impl Component {
 
     /// Creates a new Text Component with the given settings.
     pub const fn with_settings(settings: Settings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            history_cache: template::HistoryCache::new(),
+        }
     }
 
     /// Accesses the settings of the component.
@@ -212,7 +248,19 @@ impl Component {
         state.left_center_color = self.settings.left_center_color;
         state.right_color = self.settings.right_color;
 
+        let (mut left_buf, mut right_buf) = (String::new(), String::new());
+        let use_placeholders = self.settings.use_placeholders;
+
         let (left_center, right) = match &self.settings.text {
+            Text::Center(center) if use_placeholders => {
+                template::resolve(center, timer, &self.history_cache, &mut left_buf);
+                (left_buf.as_str(), None)
+            }
+            Text::Split(left, right) if use_placeholders => {
+                template::resolve(left, timer, &self.history_cache, &mut left_buf);
+                template::resolve(right, timer, &self.history_cache, &mut right_buf);
+                (left_buf.as_str(), Some(right_buf.as_str()))
+            }
             Text::Center(center) => (center.as_str(), None),
             Text::Split(left, right) => (left.as_str(), Some(right.as_str())),
             Text::Variable(var_name, is_split) => {
@@ -325,6 +373,13 @@ impl Component {
             ));
         }
 
+        if !is_variable {
+            fields.push(Field::new(
+                "Use Placeholders".into(),
+                self.settings.use_placeholders.into(),
+            ));
+        }
+
         SettingsDescription::with_fields(fields)
     }
 
@@ -336,10 +391,20 @@ impl Component {
     /// the type of the setting's value. A panic can also occur if the index of
     /// the setting provided is out of bounds.
     pub fn set_value(&mut self, mut index: usize, value: Value) {
-        if index >= 5 {
-            if let Text::Variable(_, _) = &self.settings.text {
-                index += 1;
+        let use_placeholders_index = match &self.settings.text {
+            Text::Center(_) => 5,
+            Text::Split(_, _) => 8,
+            Text::Variable(_, _) => {
+                if index >= 5 {
+                    index += 1;
+                }
+                usize::MAX
             }
+        };
+
+        if index == use_placeholders_index {
+            self.settings.use_placeholders = value.into();
+            return;
         }
 
         match index {
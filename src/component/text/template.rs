@@ -0,0 +1,302 @@
+//! Resolves the placeholders of a template text. A placeholder is enclosed in
+//! curly braces, like `{game}`, and is replaced by the value it refers to.
+//! Placeholders that refer to a time may specify how the time is supposed to
+//! be formatted, like `{sum_of_best|seconds}`. Literal curly braces can be
+//! written as `{{` and `}}`. Unknown placeholders are kept as they are, so that
+//! mistakes in the template are easy to spot.
+
+use crate::{
+    analysis::{
+        current_pace, pb_chance, sessions, sum_of_segments::calculate_best, total_playtime,
+    },
+    comparison,
+    platform::{prelude::*, to_local, utc_now, RwLock},
+    timing::formatter::{
+        Accuracy, Complete, Days, Delta, Regular, SegmentTime, TimeFormatter, DASH,
+    },
+    Run, TimeSpan, Timer, TimingMethod,
+};
+use core::fmt::Write;
+use time::Date;
+
+/// Caches the values that are calculated from the whole Attempt History, so
+/// that they only need to be recalculated when the
+/// [`history_id`](Run::history_id) of the run changes, instead of every time
+/// the template is resolved.
+pub struct HistoryCache(RwLock<Option<HistoryValues>>);
+
+#[derive(Copy, Clone)]
+struct HistoryValues {
+    history_id: usize,
+    method: TimingMethod,
+    today: Date,
+    playtime: TimeSpan,
+    completed_attempts: usize,
+    today_statistics: sessions::Statistics,
+}
+
+impl HistoryCache {
+    pub const fn new() -> Self {
+        Self(RwLock::new(None))
+    }
+
+    fn get(&self, run: &Run, method: TimingMethod) -> HistoryValues {
+        let history_id = run.history_id();
+        let today = to_local(utc_now()).date();
+
+        let mut cached = self.0.write().unwrap();
+        match *cached {
+            Some(values)
+                if values.history_id == history_id
+                    && values.method == method
+                    && values.today == today =>
+            {
+                values
+            }
+            _ => *cached.insert(HistoryValues {
+                history_id,
+                method,
+                today,
+                playtime: total_playtime::calculate(run),
+                completed_attempts: run
+                    .attempt_history()
+                    .iter()
+                    .filter(|a| a.time()[method].is_some())
+                    .count(),
+                today_statistics: sessions::day(run, method, today),
+            }),
+        }
+    }
+}
+
+impl Default for HistoryCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for HistoryCache {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+/// Writes the template with all of its placeholders resolved into the buffer
+/// provided.
+pub fn resolve(template: &str, timer: &Timer, cache: &HistoryCache, buf: &mut String) {
+    let mut rem = template;
+
+    while let Some(pos) = rem.find(['{', '}']) {
+        buf.push_str(&rem[..pos]);
+        let is_open = rem[pos..].starts_with('{');
+        rem = &rem[pos + 1..];
+
+        if let Some(after) = rem.strip_prefix(if is_open { '{' } else { '}' }) {
+            buf.push(if is_open { '{' } else { '}' });
+            rem = after;
+            continue;
+        }
+
+        if !is_open {
+            buf.push('}');
+            continue;
+        }
+
+        let end = match rem.find('}') {
+            Some(end) => end,
+            None => {
+                buf.push('{');
+                continue;
+            }
+        };
+
+        let placeholder = &rem[..end];
+        let (key, format) = match placeholder.split_once('|') {
+            Some((key, format)) => (key.trim(), Some(format.trim())),
+            None => (placeholder.trim(), None),
+        };
+
+        if !resolve_placeholder(key, format, timer, cache, buf) {
+            buf.push('{');
+            buf.push_str(placeholder);
+            buf.push('}');
+        }
+
+        rem = &rem[end + 1..];
+    }
+
+    buf.push_str(rem);
+}
+
+/// The way a time is supposed to be formatted.
+#[derive(Copy, Clone)]
+enum Format {
+    Regular(Accuracy),
+    Complete,
+    Days,
+    SegmentTime,
+    Delta,
+}
+
+impl Format {
+    fn parse(format: Option<&str>, default: Format) -> Option<Format> {
+        Some(match format {
+            None => default,
+            Some("regular") => Format::Regular(Accuracy::Hundredths),
+            Some("seconds") => Format::Regular(Accuracy::Seconds),
+            Some("tenths") => Format::Regular(Accuracy::Tenths),
+            Some("hundredths") => Format::Regular(Accuracy::Hundredths),
+            Some("milliseconds") => Format::Regular(Accuracy::Milliseconds),
+            Some("complete") => Format::Complete,
+            Some("days") => Format::Days,
+            Some("segment") => Format::SegmentTime,
+            Some("delta") => Format::Delta,
+            Some(_) => return None,
+        })
+    }
+
+    fn write(self, time: Option<TimeSpan>, buf: &mut String) {
+        let _ = match self {
+            Format::Regular(accuracy) => {
+                write!(buf, "{}", Regular::with_accuracy(accuracy).format(time))
+            }
+            Format::Complete => match time {
+                Some(time) => write!(buf, "{}", Complete.format(time)),
+                None => write!(buf, "{DASH}"),
+            },
+            Format::Days => write!(buf, "{}", Days::new().format(time)),
+            Format::SegmentTime => write!(buf, "{}", SegmentTime::new().format(time)),
+            Format::Delta => write!(buf, "{}", Delta::new().format(time)),
+        };
+    }
+}
+
+fn write_time(
+    time: Option<TimeSpan>,
+    format: Option<&str>,
+    default: Format,
+    buf: &mut String,
+) -> bool {
+    if let Some(format) = Format::parse(format, default) {
+        format.write(time, buf);
+        true
+    } else {
+        false
+    }
+}
+
+fn write_text(text: &str, buf: &mut String) {
+    if text.trim_start().is_empty() {
+        buf.push_str(DASH);
+    } else {
+        buf.push_str(text);
+    }
+}
+
+/// Resolves a single placeholder. Returns `false` if the placeholder is not
+/// known.
+fn resolve_placeholder(
+    key: &str,
+    format: Option<&str>,
+    timer: &Timer,
+    cache: &HistoryCache,
+    buf: &mut String,
+) -> bool {
+    let run = timer.run();
+    let method = timer.current_timing_method();
+    let regular = Format::Regular(Accuracy::Hundredths);
+
+    if let Some(name) = key.strip_prefix("var:") {
+        let value = run
+            .metadata()
+            .custom_variable_value(name)
+            .unwrap_or_default();
+        write_text(value, buf);
+        return true;
+    }
+
+    if let Some(name) = key.strip_prefix("srcom:") {
+        let value = run
+            .metadata()
+            .speedrun_com_variables()
+            .find(|(n, _)| *n == name)
+            .map_or("", |(_, v)| v.as_str());
+        write_text(value, buf);
+        return true;
+    }
+
+    match key {
+        "game" => buf.push_str(run.game_name()),
+        "category" => buf.push_str(run.category_name()),
+        "extended_category" => {
+            let _ = write!(buf, "{}", run.extended_category_name(true, true, true));
+        }
+        "platform" => write_text(run.metadata().platform_name(), buf),
+        "region" => write_text(run.metadata().region_name(), buf),
+        "attempts" => {
+            let _ = write!(buf, "{}", run.attempt_count());
+        }
+        "completed_attempts" => {
+            let completed = cache.get(run, method).completed_attempts;
+            let _ = write!(buf, "{completed}");
+        }
+        "today_attempts" => {
+            let attempts = cache.get(run, method).today_statistics.attempts;
+            let _ = write!(buf, "{attempts}");
+        }
+        "comparison" => buf.push_str(timer.current_comparison()),
+        "comparison_short" => {
+            buf.push_str(&comparison::shorten_dynamic(timer.current_comparison()))
+        }
+        "segment" => write_text(
+            timer.current_split().map_or("", |segment| segment.name()),
+            buf,
+        ),
+        "split_index" => match timer.current_split_index() {
+            Some(index) => {
+                let _ = write!(buf, "{}", index + 1);
+            }
+            None => buf.push_str(DASH),
+        },
+        "splits" => {
+            let _ = write!(buf, "{}", run.len());
+        }
+        "current_time" => {
+            let time = timer.snapshot().current_time()[method];
+            return write_time(time, format, regular, buf);
+        }
+        "personal_best" => {
+            let time = run
+                .segments()
+                .last()
+                .and_then(|s| s.personal_best_split_time()[method]);
+            return write_time(time, format, regular, buf);
+        }
+        "sum_of_best" => {
+            let time = calculate_best(run.segments(), false, true, method);
+            return write_time(time, format, regular, buf);
+        }
+        "current_pace" => {
+            let (time, _) = current_pace::calculate(&timer.snapshot(), timer.current_comparison());
+            return write_time(time, format, regular, buf);
+        }
+        "total_playtime" => {
+            // Only the playtime of the current attempt changes all the time,
+            // so the playtime of the Attempt History is cached.
+            let time = cache.get(run, method).playtime + timer.current_attempt_duration()
+                - timer.get_pause_time().unwrap_or_default();
+            return write_time(Some(time), format, Format::Days, buf);
+        }
+        "today_playtime" => {
+            let time = cache.get(run, method).today_statistics.playtime;
+            return write_time(Some(time), format, Format::Days, buf);
+        }
+        "pb_chance" => {
+            let (chance, _) = pb_chance::for_timer(&timer.snapshot());
+            let _ = write!(buf, "{:.1}%", 100.0 * chance);
+        }
+        _ => return false,
+    }
+
+    true
+}
@@ -1,5 +1,8 @@
 use super::{Component, Text, TextState};
-use crate::{timing::formatter, util::tests_helper::create_run, Timer};
+use crate::{
+    settings::Value, timing::formatter, util::tests_helper::create_run, Time, TimeSpan, Timer,
+    TimingMethod,
+};
 
 #[test]
 fn resolves_variables() {
@@ -73,3 +76,169 @@ fn uses_dash_for_whitespace_variable() {
         TextState::Split(String::from("Goal Time"), String::from(formatter::DASH))
     );
 }
+
+#[test]
+fn resolves_placeholders() {
+    let mut run = create_run(&["A", "B"]);
+    run.set_game_name("Celeste");
+    run.set_category_name("Any%");
+    run.set_attempt_count(1234);
+    run.metadata_mut().custom_variable_mut("deaths").value = String::from("42");
+    run.metadata_mut()
+        .set_speedrun_com_variable("Version", "1.2.1.5");
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().use_placeholders = true;
+    component.settings_mut().text = Text::Center(String::from(
+        "{game} – {category} | {attempts} attempts | {var:deaths} deaths | {srcom:Version}",
+    ));
+
+    let state = component.state(&timer);
+
+    assert_eq!(
+        state.text,
+        TextState::Center(String::from(
+            "Celeste – Any% | 1234 attempts | 42 deaths | 1.2.1.5"
+        ))
+    );
+}
+
+#[test]
+fn placeholders_in_split_text() {
+    let run = create_run(&["A", "B"]);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().use_placeholders = true;
+    component.settings_mut().text = Text::Split(
+        String::from("Split {split_index}/{splits}"),
+        String::from("{sum_of_best|seconds}"),
+    );
+
+    let state = component.state(&timer);
+
+    assert_eq!(
+        state.text,
+        TextState::Split(
+            format!("Split {}/2", formatter::DASH),
+            String::from(formatter::DASH)
+        )
+    );
+}
+
+#[test]
+fn keeps_unknown_placeholders_and_escapes() {
+    let run = create_run(&["A"]);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().use_placeholders = true;
+    component.settings_mut().text =
+        Text::Center(String::from("{{literal}} {unknown} {var:missing} {game"));
+
+    let state = component.state(&timer);
+
+    assert_eq!(
+        state.text,
+        TextState::Center(format!(
+            "{{literal}} {{unknown}} {} {{game",
+            formatter::DASH
+        ))
+    );
+}
+
+#[test]
+fn placeholders_are_only_resolved_when_enabled() {
+    let mut run = create_run(&["A"]);
+    run.set_game_name("Celeste");
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().text = Text::Center(String::from("{game}"));
+
+    let state = component.state(&timer);
+
+    assert_eq!(state.text, TextState::Center(String::from("{game}")));
+}
+
+#[test]
+fn completed_attempts_use_the_current_timing_method() {
+    let mut run = create_run(&["A"]);
+    let time = Some(TimeSpan::from_seconds(10.0));
+    run.add_attempt(Time::new().with_real_time(time), None, None, None);
+    run.add_attempt(
+        Time::new().with_real_time(time).with_game_time(time),
+        None,
+        None,
+        None,
+    );
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().use_placeholders = true;
+    component.settings_mut().text = Text::Center(String::from("{completed_attempts}"));
+
+    let state = component.state(&timer);
+    assert_eq!(state.text, TextState::Center(String::from("2")));
+
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    let state = component.state(&timer);
+    assert_eq!(state.text, TextState::Center(String::from("1")));
+}
+
+#[test]
+fn completed_attempts_follow_a_replaced_run() {
+    let time = Some(TimeSpan::from_seconds(10.0));
+    let run = |completed: usize| {
+        let mut run = create_run(&["A"]);
+        for _ in 0..completed {
+            run.add_attempt(Time::new().with_real_time(time), None, None, None);
+        }
+        run.add_attempt(Time::new(), None, None, None);
+        run
+    };
+    let mut timer = Timer::new(run(1)).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().use_placeholders = true;
+    component.settings_mut().text = Text::Center(String::from("{completed_attempts}"));
+
+    let state = component.state(&timer);
+    assert_eq!(state.text, TextState::Center(String::from("1")));
+
+    // The new run has the same amount of attempts, so only its histories
+    // being different can tell them apart.
+    let mut replacement = run(0);
+    replacement.add_attempt(Time::new(), None, None, None);
+    timer.replace_run(replacement, false).unwrap();
+
+    let state = component.state(&timer);
+    assert_eq!(state.text, TextState::Center(String::from("0")));
+}
+
+#[test]
+fn use_placeholders_is_the_last_setting() {
+    let mut component = Component::new();
+
+    let fields = component.settings_description().fields;
+    assert_eq!(fields.len(), 6);
+    assert_eq!(fields[5].text, "Use Placeholders");
+    component.set_value(5, Value::Bool(true));
+    assert!(component.settings().use_placeholders);
+
+    component.set_value(2, Value::Bool(true));
+    let fields = component.settings_description().fields;
+    assert_eq!(fields.len(), 9);
+    assert_eq!(fields[8].text, "Use Placeholders");
+    component.set_value(8, Value::Bool(false));
+    assert!(!component.settings().use_placeholders);
+
+    component.set_value(1, Value::Bool(true));
+    assert!(component
+        .settings_description()
+        .fields
+        .iter()
+        .all(|field| field.text != "Use Placeholders"));
+}
@@ -0,0 +1,274 @@
+//! Provides the Timeline Component and relevant types for using it. The
+//! Timeline Component shows the run as two bars of stacked segments. The top
+//! bar shows the segment durations of the chosen comparison, while the bottom
+//! bar shows the durations of the segments already completed in the current
+//! attempt. Both bars share the same time scale, so the runner can see at a
+//! glance which segments took longer or shorter than in the comparison.
+
+use crate::{
+    analysis::state_helper,
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, SemanticColor, SettingsDescription, Value},
+    timing::Snapshot,
+    GeneralLayoutSettings, TimeSpan, TimerPhase,
+};
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Timeline Component shows the segment durations of the current attempt
+/// below the segment durations of the chosen comparison, so that the runner
+/// can see where segments grew or shrank.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`.
+    pub comparison_override: Option<String>,
+    /// The height of the component.
+    pub height: u32,
+    /// Specifies whether the segment that is currently being run should be
+    /// shown as part of the current attempt.
+    pub show_live_segment: bool,
+    /// The background color of the bars.
+    pub background_color: Color,
+    /// The color of the comparison's segments.
+    pub comparison_color: Color,
+}
+
+/// The state object describes the information to visualize for this component.
+/// All widths are in the range `0..1`, where `1` is the width of the whole
+/// component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The widths of the comparison's segments, laid out from left to right.
+    /// Segments without a time in the comparison are combined with the
+    /// following segment.
+    pub comparison_segments: Vec<f32>,
+    /// The segments completed in the current attempt, laid out from left to
+    /// right. Skipped segments are combined with the following segment.
+    pub attempt_segments: Vec<SegmentState>,
+    /// The segment that is currently being run. It is placed directly after
+    /// the completed segments. This is `None` if no attempt is in progress or
+    /// the live segment is not supposed to be shown.
+    pub live_segment: Option<SegmentState>,
+    /// The background color of the bars.
+    pub background_color: Color,
+    /// The color of the comparison's segments.
+    pub comparison_color: Color,
+    /// The height of the component.
+    pub height: u32,
+}
+
+/// The state object describing a single segment of the current attempt.
+#[derive(Default, Serialize, Deserialize)]
+pub struct SegmentState {
+    /// The width of the segment.
+    pub width: f32,
+    /// The semantic coloring information the segment carries.
+    pub semantic_color: SemanticColor,
+    /// The color of the segment.
+    pub color: Color,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            comparison_override: None,
+            height: 40,
+            show_live_segment: true,
+            background_color: Color::rgba(1.0, 1.0, 1.0, 0.05),
+            comparison_color: Color::rgba(1.0, 1.0, 1.0, 0.3),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Timeline Component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new Timeline Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if let Some(comparison) = &self.settings.comparison_override {
+            format!("Timeline ({})", comparison::shorten_dynamic(comparison)).into()
+        } else {
+            "Timeline".into()
+        }
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+        let method = timer.current_timing_method();
+        let segments = timer.run().segments();
+        let phase = timer.current_phase();
+
+        let completed_segments = match phase {
+            TimerPhase::NotRunning => 0,
+            TimerPhase::Ended => segments.len(),
+            _ => timer.current_split_index().unwrap_or_default(),
+        };
+
+        let live_segment = if self.settings.show_live_segment
+            && matches!(phase, TimerPhase::Running | TimerPhase::Paused)
+        {
+            timer.current_time()[method].map(|time| (completed_segments, time))
+        } else {
+            None
+        };
+
+        let comparison_end = segments
+            .iter()
+            .filter_map(|s| s.comparison(comparison)[method])
+            .max();
+        let attempt_end = segments[..completed_segments]
+            .iter()
+            .filter_map(|s| s.split_time()[method])
+            .chain(live_segment.map(|(_, time)| time))
+            .max();
+        let total = comparison_end
+            .into_iter()
+            .chain(attempt_end)
+            .max()
+            .map_or(0.0, |t| t.total_seconds())
+            .max(f64::EPSILON);
+        let width = |start: TimeSpan, end: TimeSpan| {
+            ((end - start).total_seconds() / total).clamp(0.0, 1.0) as f32
+        };
+
+        state.comparison_segments.clear();
+        let mut previous = TimeSpan::zero();
+        for split_time in segments
+            .iter()
+            .filter_map(|s| s.comparison(comparison)[method])
+        {
+            state.comparison_segments.push(width(previous, split_time));
+            previous = split_time;
+        }
+
+        state.attempt_segments.clear();
+        let mut previous = TimeSpan::zero();
+        for (index, segment) in segments[..completed_segments].iter().enumerate() {
+            if let Some(split_time) = segment.split_time()[method] {
+                let delta = catch! { split_time - segment.comparison(comparison)[method]? };
+                let semantic_color =
+                    state_helper::split_color(timer, delta, index, true, true, comparison, method);
+                state.attempt_segments.push(SegmentState {
+                    width: width(previous, split_time),
+                    semantic_color,
+                    color: semantic_color.visualize(layout_settings),
+                });
+                previous = split_time;
+            }
+        }
+
+        state.live_segment = live_segment.map(|(index, current_time)| {
+            let delta = catch! { current_time - segments[index].comparison(comparison)[method]? };
+            let semantic_color =
+                state_helper::split_color(timer, delta, index, true, false, comparison, method);
+            SegmentState {
+                width: width(previous, current_time),
+                semantic_color,
+                color: semantic_color.visualize(layout_settings),
+            }
+        });
+
+        state.background_color = self.settings.background_color;
+        state.comparison_color = self.settings.comparison_color;
+        state.height = self.settings.height;
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = State::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+            Field::new(
+                "Show Live Segment".into(),
+                self.settings.show_live_segment.into(),
+            ),
+            Field::new(
+                "Background Color".into(),
+                self.settings.background_color.into(),
+            ),
+            Field::new(
+                "Comparison Color".into(),
+                self.settings.comparison_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.comparison_override = value.into(),
+            1 => self.settings.height = value.into_uint().unwrap() as _,
+            2 => self.settings.show_live_segment = value.into(),
+            3 => self.settings.background_color = value.into(),
+            4 => self.settings.comparison_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::{Component, State};
+use crate::{
+    settings::SemanticColor,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, TimeSpan, Timer,
+};
+
+fn state_of(component: &Component, timer: &Timer) -> State {
+    component.state(&timer.snapshot(), &GeneralLayoutSettings::default())
+}
+
+fn assert_widths(actual: impl IntoIterator<Item = f32>, expected: &[f32]) {
+    let actual: Vec<f32> = actual.into_iter().collect();
+    assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn shows_only_the_comparison_when_not_running() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 30.0, 40.0]);
+
+    let state = state_of(&Component::new(), &timer);
+    assert_widths(state.comparison_segments, &[0.25, 0.5, 0.25]);
+    assert!(state.attempt_segments.is_empty());
+    assert!(state.live_segment.is_none());
+}
+
+#[test]
+fn colors_the_completed_segments() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 30.0, 40.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(15.0), Some(30.0)]);
+
+    let state = state_of(&Component::new(), &timer);
+    assert_widths(
+        state.attempt_segments.iter().map(|s| s.width),
+        &[0.375, 0.375],
+    );
+    assert_eq!(
+        state.attempt_segments[0].semantic_color,
+        SemanticColor::BehindLosingTime,
+    );
+    // 15 seconds is a new best segment for B.
+    assert_eq!(
+        state.attempt_segments[1].semantic_color,
+        SemanticColor::BestSegment,
+    );
+    assert!(state.live_segment.is_some());
+}
+
+#[test]
+fn grows_the_scale_when_the_attempt_is_slower() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(20.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(40.0));
+
+    let state = state_of(&Component::new(), &timer);
+    assert_widths(state.comparison_segments, &[0.25, 0.25]);
+    assert_widths(state.attempt_segments.iter().map(|s| s.width), &[0.5]);
+    assert_widths(state.live_segment.map(|s| s.width), &[0.5]);
+}
+
+#[test]
+fn combines_skipped_segments_with_the_next_one() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 40.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[None, Some(20.0), Some(40.0)]);
+
+    let state = state_of(&Component::new(), &timer);
+    assert_widths(state.attempt_segments.iter().map(|s| s.width), &[0.5, 0.5]);
+    assert!(state.live_segment.is_none());
+}
@@ -0,0 +1,293 @@
+//! The accessibility module provides a way to describe the information shown
+//! by a layout as a tree of accessibility nodes. Rendering a layout only
+//! produces pixels, which screen readers can't make any sense of. A frontend
+//! can instead hand the nodes to the accessibility APIs of its platform, so
+//! that the layout can be navigated and read out loud.
+
+use super::{ComponentState, LayoutState};
+use crate::{
+    component::{
+        clock, counters, detailed_timer, key_value, progress_bar, splits, text::TextState, timer,
+        title,
+    },
+    platform::prelude::*,
+    settings::SemanticColor,
+};
+use core::iter;
+use serde::{Deserialize, Serialize};
+
+/// A node of the accessibility tree of a layout.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Node {
+    /// The kind of element the node describes.
+    pub role: Role,
+    /// The name of the element, such as the label of a value or the name of a
+    /// segment. This may be empty if the element has no name.
+    pub name: String,
+    /// The value shown by the element, if it shows any.
+    pub value: Option<String>,
+    /// Describes what the color of the value means, such as the runner being
+    /// ahead of the comparison. Screen reader users can't see the color, so
+    /// this should be read out together with the value.
+    pub description: Option<String>,
+    /// Describes how changes to the value are supposed to be announced.
+    pub live: LiveRegion,
+    /// Specifies whether the element is the current one within its parent,
+    /// such as the row of the segment the active attempt is on.
+    pub is_current: bool,
+    /// The nodes of the elements contained within this element.
+    pub children: Vec<Node>,
+}
+
+/// The kind of element an accessibility [`Node`] describes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    /// The whole layout. This is the root of the tree.
+    Layout,
+    /// A group of related elements.
+    Group,
+    /// A heading, such as the title of the run.
+    Heading,
+    /// A table of rows, such as the splits.
+    Table,
+    /// A row of a table.
+    Row,
+    /// A cell of a row.
+    Cell,
+    /// A time that is continuously counting.
+    Timer,
+    /// A piece of text, usually a label together with a value.
+    Text,
+    /// An image.
+    Image,
+    /// A chart visualizing some data.
+    Chart,
+    /// A bar showing the progress of something.
+    ProgressBar,
+    /// A separator between other elements.
+    Separator,
+    /// An element that is purely visual and can be ignored.
+    Presentation,
+}
+
+/// Describes how changes to the value of a [`Node`] are supposed to be
+/// announced by a screen reader.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiveRegion {
+    /// Changes are not announced. This is used for values that change so
+    /// frequently that announcing them would drown out everything else.
+    Off,
+    /// Changes are announced once the screen reader is done with what it is
+    /// currently reading out.
+    Polite,
+}
+
+impl Node {
+    fn new(role: Role, name: impl Into<String>) -> Self {
+        Self {
+            role,
+            name: name.into(),
+            value: None,
+            description: None,
+            live: LiveRegion::Off,
+            is_current: false,
+            children: Vec::new(),
+        }
+    }
+
+    fn with_value(mut self, value: &str) -> Self {
+        if !value.is_empty() {
+            self.value = Some(value.into());
+        }
+        self
+    }
+
+    fn with_children(mut self, children: Vec<Node>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Describes the semantic color of the value and marks the value as a
+    /// live region if the semantic color carries any meaning, as that is the
+    /// case for deltas and similar values. Values that update frequently are
+    /// never announced.
+    fn with_semantic_color(
+        mut self,
+        semantic_color: SemanticColor,
+        updates_frequently: bool,
+    ) -> Self {
+        self.description = describe(semantic_color).map(Into::into);
+        if self.description.is_some() && !updates_frequently {
+            self.live = LiveRegion::Polite;
+        }
+        self
+    }
+}
+
+impl LayoutState {
+    /// Describes the information shown by the layout as a tree of
+    /// accessibility nodes. The root node describes the layout itself. Its
+    /// children correspond to the components of the layout in the same order,
+    /// so each component can be looked up by its index.
+    pub fn accessibility_tree(&self) -> Node {
+        Node::new(Role::Layout, "Layout")
+            .with_children(self.components.iter().map(component_node).collect())
+    }
+}
+
+fn component_node(component: &ComponentState) -> Node {
+    match component {
+        ComponentState::BlankSpace(_) => Node::new(Role::Presentation, ""),
+        ComponentState::Clock(state) => clock_node(state),
+        ComponentState::Counters(state) => counters_node(state),
+        ComponentState::CustomImage(_) => Node::new(Role::Image, "Image"),
+        ComponentState::DetailedTimer(state) => detailed_timer_node(state),
+        ComponentState::Graph(_) => Node::new(Role::Chart, "Graph"),
+        ComponentState::KeyValue(state) => key_value_node(state),
+        ComponentState::PbHistory(_) => Node::new(Role::Chart, "PB History"),
+        ComponentState::ProgressBar(state) => progress_bar_node(state),
+        ComponentState::SegmentHistory(_) => Node::new(Role::Chart, "Segment History"),
+        ComponentState::Separator(_) => Node::new(Role::Separator, ""),
+        ComponentState::Splits(state) => splits_node(state),
+        ComponentState::Text(state) => match &state.text {
+            TextState::Center(text) => Node::new(Role::Text, text.as_str()),
+            TextState::Split(left, right) => Node::new(Role::Text, left.as_str()).with_value(right),
+        },
+        ComponentState::Timeline(_) => Node::new(Role::Chart, "Timeline"),
+        ComponentState::Timer(state) => timer_node("Timer", state),
+        ComponentState::Title(state) => title_node(state),
+    }
+}
+
+fn clock_node(state: &clock::State) -> Node {
+    Node::new(Role::Group, "Clock").with_children(
+        state
+            .rows
+            .iter()
+            .map(|row| Node::new(Role::Text, row.key.as_str()).with_value(&row.value))
+            .collect(),
+    )
+}
+
+fn counters_node(state: &counters::State) -> Node {
+    Node::new(Role::Group, "Counters").with_children(
+        state
+            .counters
+            .iter()
+            .map(|counter| {
+                let node = if counter.delta.is_empty() {
+                    Node::new(Role::Text, counter.label.as_str()).with_value(&counter.value)
+                } else {
+                    Node::new(Role::Text, counter.label.as_str())
+                        .with_value(&format!("{} ({})", counter.value, counter.delta))
+                };
+                node.with_semantic_color(counter.delta_semantic_color, false)
+            })
+            .collect(),
+    )
+}
+
+fn detailed_timer_node(state: &detailed_timer::State) -> Node {
+    let name = state.segment_name.as_deref().unwrap_or("Detailed Timer");
+    let mut children = vec![
+        timer_node("Timer", &state.timer),
+        timer_node("Segment Timer", &state.segment_timer),
+    ];
+    children.extend(
+        [&state.comparison1, &state.comparison2]
+            .into_iter()
+            .flatten()
+            .map(|comparison| {
+                Node::new(Role::Text, comparison.name.as_str()).with_value(&comparison.time)
+            }),
+    );
+    Node::new(Role::Group, name).with_children(children)
+}
+
+fn key_value_node(state: &key_value::State) -> Node {
+    Node::new(Role::Text, state.key.as_str())
+        .with_value(&state.value)
+        .with_semantic_color(state.semantic_color, state.updates_frequently)
+}
+
+fn progress_bar_node(state: &progress_bar::State) -> Node {
+    let percentage = (100.0 * state.time_progress.clamp(0.0, 1.0)) as u32;
+    Node::new(Role::ProgressBar, "Progress")
+        .with_value(&format!("{percentage}%"))
+        .with_semantic_color(state.semantic_color, true)
+}
+
+fn splits_node(state: &splits::State) -> Node {
+    let labels = state.column_labels.as_deref().unwrap_or_default();
+    Node::new(Role::Table, "Splits").with_children(
+        state
+            .splits
+            .iter()
+            // The splits may be filled up with blank rows, which carry no
+            // information at all.
+            .filter(|split| {
+                !split.name.is_empty() || split.columns.iter().any(|c| !c.value.is_empty())
+            })
+            .map(|split| {
+                // The columns are stored from right to left, but are supposed
+                // to be read from left to right.
+                let mut cells: Vec<_> = split
+                    .columns
+                    .iter()
+                    .zip(labels.iter().map(String::as_str).chain(iter::repeat("")))
+                    .map(|(column, label)| {
+                        Node::new(Role::Cell, label)
+                            .with_value(&column.value)
+                            .with_semantic_color(column.semantic_color, column.updates_frequently)
+                    })
+                    .collect();
+                cells.reverse();
+                let mut row = Node::new(Role::Row, split.name.as_str()).with_children(cells);
+                row.is_current = split.is_current_split;
+                row
+            })
+            .collect(),
+    )
+}
+
+fn timer_node(name: &str, state: &timer::State) -> Node {
+    let mut node = Node::new(Role::Timer, name)
+        .with_value(&format!("{}{}", state.time, state.fraction))
+        .with_semantic_color(state.semantic_color, state.updates_frequently);
+    // The timer's value changes whenever it stops running, such as when the
+    // attempt gets paused or finished, which is worth announcing.
+    if !state.updates_frequently {
+        node.live = LiveRegion::Polite;
+    }
+    node
+}
+
+fn title_node(state: &title::State) -> Node {
+    let line1 = state.line1.first().map_or("", |l| &**l);
+    let name = match state.line2.first() {
+        Some(line2) => format!("{line1} - {line2}"),
+        None => line1.into(),
+    };
+    let node = Node::new(Role::Heading, name);
+    match (state.finished_runs, state.attempts) {
+        (Some(finished_runs), Some(attempts)) => {
+            node.with_value(&format!("{finished_runs}/{attempts}"))
+        }
+        (Some(count), None) | (None, Some(count)) => node.with_value(&format!("{count}")),
+        _ => node,
+    }
+}
+
+const fn describe(semantic_color: SemanticColor) -> Option<&'static str> {
+    Some(match semantic_color {
+        SemanticColor::Default => return None,
+        SemanticColor::AheadGainingTime => "Ahead, gaining time",
+        SemanticColor::AheadLosingTime => "Ahead, losing time",
+        SemanticColor::BehindLosingTime => "Behind, losing time",
+        SemanticColor::BehindGainingTime => "Behind, gaining time",
+        SemanticColor::BestSegment => "Best segment",
+        SemanticColor::NotRunning => "Not running",
+        SemanticColor::Paused => "Paused",
+        SemanticColor::PersonalBest => "Personal best",
+    })
+}
@@ -1,9 +1,10 @@
 use super::{ComponentSettings, ComponentState, GeneralSettings};
 use crate::{
     component::{
-        blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        blank_space, clock, counters, current_comparison, current_pace, custom_image, delta,
+        detailed_timer, graph, pb_chance, pb_history, possible_time_save, previous_segment,
+        progress_bar, segment_history, segment_time, separator, splits, sum_of_best, text,
+        timeline, timer, title, total_playtime,
     },
     platform::prelude::*,
     settings::{SettingsDescription, Value},
@@ -17,10 +18,16 @@ use alloc::borrow::Cow;
 pub enum Component {
     /// The Blank Space Component.
     BlankSpace(blank_space::Component),
+    /// The Clock Component.
+    Clock(clock::Component),
+    /// The Counters Component.
+    Counters(counters::Component),
     /// The Current Comparison Component.
     CurrentComparison(current_comparison::Component),
     /// The Current Pace Component.
     CurrentPace(current_pace::Component),
+    /// The Custom Image Component.
+    CustomImage(custom_image::Component),
     /// The Delta Component.
     Delta(delta::Component),
     /// The Detailed Timer Component.
@@ -29,10 +36,16 @@ pub enum Component {
     Graph(graph::Component),
     /// The PB Chance Component.
     PbChance(pb_chance::Component),
+    /// The PB History Component.
+    PbHistory(pb_history::Component),
     /// The Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Component),
     /// The Previous Segment Component.
     PreviousSegment(previous_segment::Component),
+    /// The Progress Bar Component.
+    ProgressBar(progress_bar::Component),
+    /// The Segment History Component.
+    SegmentHistory(segment_history::Component),
     /// The Segment Time Component.
     SegmentTime(segment_time::Component),
     /// The Separator Component.
@@ -43,6 +56,8 @@ pub enum Component {
     SumOfBest(sum_of_best::Component),
     /// The Text Component.
     Text(text::Component),
+    /// The Timeline Component.
+    Timeline(timeline::Component),
     /// The Timer Component.
     Timer(timer::Component),
     /// The Title Component.
@@ -57,6 +72,12 @@ impl From<blank_space::Component> for Component {
     }
 }
 
+impl From<clock::Component> for Component {
+    fn from(component: clock::Component) -> Self {
+        Self::Clock(component)
+    }
+}
+
 impl From<current_comparison::Component> for Component {
     fn from(component: current_comparison::Component) -> Self {
         Self::CurrentComparison(component)
@@ -69,6 +90,18 @@ impl From<current_pace::Component> for Component {
     }
 }
 
+impl From<counters::Component> for Component {
+    fn from(component: counters::Component) -> Self {
+        Self::Counters(component)
+    }
+}
+
+impl From<custom_image::Component> for Component {
+    fn from(component: custom_image::Component) -> Self {
+        Self::CustomImage(component)
+    }
+}
+
 impl From<delta::Component> for Component {
     fn from(component: delta::Component) -> Self {
         Self::Delta(component)
@@ -93,6 +126,12 @@ impl From<pb_chance::Component> for Component {
     }
 }
 
+impl From<pb_history::Component> for Component {
+    fn from(component: pb_history::Component) -> Self {
+        Self::PbHistory(component)
+    }
+}
+
 impl From<possible_time_save::Component> for Component {
     fn from(component: possible_time_save::Component) -> Self {
         Self::PossibleTimeSave(component)
@@ -105,6 +144,18 @@ impl From<previous_segment::Component> for Component {
     }
 }
 
+impl From<progress_bar::Component> for Component {
+    fn from(component: progress_bar::Component) -> Self {
+        Self::ProgressBar(component)
+    }
+}
+
+impl From<segment_history::Component> for Component {
+    fn from(component: segment_history::Component) -> Self {
+        Self::SegmentHistory(component)
+    }
+}
+
 impl From<segment_time::Component> for Component {
     fn from(component: segment_time::Component) -> Self {
         Self::SegmentTime(component)
@@ -135,6 +186,12 @@ impl From<text::Component> for Component {
     }
 }
 
+impl From<timeline::Component> for Component {
+    fn from(component: timeline::Component) -> Self {
+        Self::Timeline(component)
+    }
+}
+
 impl From<timer::Component> for Component {
     fn from(component: timer::Component) -> Self {
         Self::Timer(component)
@@ -168,12 +225,21 @@ impl Component {
             (ComponentState::BlankSpace(state), Component::BlankSpace(component)) => {
                 component.update_state(state)
             }
+            (ComponentState::Clock(state), Component::Clock(component)) => {
+                component.update_state(state, timer)
+            }
+            (ComponentState::Counters(state), Component::Counters(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::CurrentComparison(component)) => {
                 component.update_state(state, timer)
             }
             (ComponentState::KeyValue(state), Component::CurrentPace(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::CustomImage(state), Component::CustomImage(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::KeyValue(state), Component::Delta(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
@@ -186,12 +252,21 @@ impl Component {
             (ComponentState::KeyValue(state), Component::PbChance(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::PbHistory(state), Component::PbHistory(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::PossibleTimeSave(component)) => {
                 component.update_state(state, timer)
             }
             (ComponentState::KeyValue(state), Component::PreviousSegment(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
+            (ComponentState::ProgressBar(state), Component::ProgressBar(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
+            (ComponentState::SegmentHistory(state), Component::SegmentHistory(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::SegmentTime(component)) => {
                 component.update_state(state, timer)
             }
@@ -207,6 +282,9 @@ impl Component {
             (ComponentState::Text(state), Component::Text(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::Timeline(state), Component::Timeline(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::Timer(state), Component::Timer(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
@@ -231,10 +309,17 @@ impl Component {
     ) -> ComponentState {
         match self {
             Component::BlankSpace(component) => ComponentState::BlankSpace(component.state()),
+            Component::Clock(component) => ComponentState::Clock(component.state(timer)),
+            Component::Counters(component) => {
+                ComponentState::Counters(component.state(timer, layout_settings))
+            }
             Component::CurrentComparison(component) => {
                 ComponentState::KeyValue(component.state(timer))
             }
             Component::CurrentPace(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::CustomImage(component) => {
+                ComponentState::CustomImage(component.state(timer))
+            }
             Component::Delta(component) => {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
@@ -245,12 +330,21 @@ impl Component {
                 ComponentState::Graph(component.state(timer, layout_settings))
             }
             Component::PbChance(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::PbHistory(component) => {
+                ComponentState::PbHistory(component.state(timer, layout_settings))
+            }
             Component::PossibleTimeSave(component) => {
                 ComponentState::KeyValue(component.state(timer))
             }
             Component::PreviousSegment(component) => {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
+            Component::ProgressBar(component) => {
+                ComponentState::ProgressBar(component.state(timer, layout_settings))
+            }
+            Component::SegmentHistory(component) => {
+                ComponentState::SegmentHistory(component.state(timer, layout_settings))
+            }
             Component::SegmentTime(component) => ComponentState::KeyValue(component.state(timer)),
             Component::Separator(component) => ComponentState::Separator(component.state()),
             Component::Splits(component) => {
@@ -258,6 +352,9 @@ impl Component {
             }
             Component::SumOfBest(component) => ComponentState::KeyValue(component.state(timer)),
             Component::Text(component) => ComponentState::Text(component.state(timer)),
+            Component::Timeline(component) => {
+                ComponentState::Timeline(component.state(timer, layout_settings))
+            }
             Component::Timer(component) => {
                 ComponentState::Timer(component.state(timer, layout_settings))
             }
@@ -275,12 +372,19 @@ impl Component {
             Component::BlankSpace(component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::Clock(component) => ComponentSettings::Clock(component.settings().clone()),
             Component::CurrentComparison(component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
             Component::CurrentPace(component) => {
                 ComponentSettings::CurrentPace(component.settings().clone())
             }
+            Component::Counters(component) => {
+                ComponentSettings::Counters(component.settings().clone())
+            }
+            Component::CustomImage(component) => {
+                ComponentSettings::CustomImage(component.settings().clone())
+            }
             Component::Delta(component) => ComponentSettings::Delta(component.settings().clone()),
             Component::DetailedTimer(component) => {
                 ComponentSettings::DetailedTimer(Box::new(component.settings().clone()))
@@ -289,12 +393,21 @@ impl Component {
             Component::PbChance(component) => {
                 ComponentSettings::PbChance(component.settings().clone())
             }
+            Component::PbHistory(component) => {
+                ComponentSettings::PbHistory(component.settings().clone())
+            }
             Component::PossibleTimeSave(component) => {
                 ComponentSettings::PossibleTimeSave(component.settings().clone())
             }
             Component::PreviousSegment(component) => {
                 ComponentSettings::PreviousSegment(component.settings().clone())
             }
+            Component::ProgressBar(component) => {
+                ComponentSettings::ProgressBar(component.settings().clone())
+            }
+            Component::SegmentHistory(component) => {
+                ComponentSettings::SegmentHistory(component.settings().clone())
+            }
             Component::SegmentTime(component) => {
                 ComponentSettings::SegmentTime(component.settings().clone())
             }
@@ -304,6 +417,9 @@ impl Component {
                 ComponentSettings::SumOfBest(component.settings().clone())
             }
             Component::Text(component) => ComponentSettings::Text(component.settings().clone()),
+            Component::Timeline(component) => {
+                ComponentSettings::Timeline(component.settings().clone())
+            }
             Component::Timer(component) => ComponentSettings::Timer(component.settings().clone()),
             Component::Title(component) => ComponentSettings::Title(component.settings().clone()),
             Component::TotalPlaytime(component) => {
@@ -316,19 +432,26 @@ impl Component {
     pub fn name(&self) -> Cow<'_, str> {
         match self {
             Component::BlankSpace(component) => component.name().into(),
+            Component::Clock(component) => component.name().into(),
             Component::CurrentComparison(component) => component.name().into(),
             Component::CurrentPace(component) => component.name(),
+            Component::Counters(component) => component.name().into(),
+            Component::CustomImage(component) => component.name().into(),
             Component::Delta(component) => component.name(),
             Component::DetailedTimer(component) => component.name().into(),
             Component::Graph(component) => component.name(),
             Component::PbChance(component) => component.name().into(),
+            Component::PbHistory(component) => component.name(),
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
+            Component::ProgressBar(component) => component.name(),
+            Component::SegmentHistory(component) => component.name(),
             Component::SegmentTime(component) => component.name(),
             Component::Separator(component) => component.name().into(),
             Component::Splits(component) => component.name().into(),
             Component::SumOfBest(component) => component.name().into(),
             Component::Text(component) => component.name(),
+            Component::Timeline(component) => component.name(),
             Component::Timer(component) => component.name().into(),
             Component::Title(component) => component.name().into(),
             Component::TotalPlaytime(component) => component.name().into(),
@@ -357,6 +480,8 @@ impl Component {
     /// time.
     pub fn remount(&mut self) {
         match self {
+            Component::Counters(component) => component.remount(),
+            Component::CustomImage(component) => component.remount(),
             Component::DetailedTimer(component) => component.remount(),
             Component::Splits(component) => component.remount(),
             Component::Title(component) => component.remount(),
@@ -371,19 +496,26 @@ impl Component {
     pub fn settings_description(&self) -> SettingsDescription {
         match self {
             Component::BlankSpace(component) => component.settings_description(),
+            Component::Clock(component) => component.settings_description(),
             Component::CurrentComparison(component) => component.settings_description(),
             Component::CurrentPace(component) => component.settings_description(),
+            Component::Counters(component) => component.settings_description(),
+            Component::CustomImage(component) => component.settings_description(),
             Component::Delta(component) => component.settings_description(),
             Component::DetailedTimer(component) => component.settings_description(),
             Component::Graph(component) => component.settings_description(),
             Component::PbChance(component) => component.settings_description(),
+            Component::PbHistory(component) => component.settings_description(),
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
+            Component::ProgressBar(component) => component.settings_description(),
+            Component::SegmentHistory(component) => component.settings_description(),
             Component::SegmentTime(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
             Component::Text(component) => component.settings_description(),
+            Component::Timeline(component) => component.settings_description(),
             Component::Timer(component) => component.settings_description(),
             Component::Title(component) => component.settings_description(),
             Component::TotalPlaytime(component) => component.settings_description(),
@@ -401,19 +533,26 @@ impl Component {
     pub fn set_value(&mut self, index: usize, value: Value) {
         match self {
             Component::BlankSpace(component) => component.set_value(index, value),
+            Component::Clock(component) => component.set_value(index, value),
             Component::CurrentComparison(component) => component.set_value(index, value),
             Component::CurrentPace(component) => component.set_value(index, value),
+            Component::Counters(component) => component.set_value(index, value),
+            Component::CustomImage(component) => component.set_value(index, value),
             Component::Delta(component) => component.set_value(index, value),
             Component::DetailedTimer(component) => component.set_value(index, value),
             Component::Graph(component) => component.set_value(index, value),
             Component::PbChance(component) => component.set_value(index, value),
+            Component::PbHistory(component) => component.set_value(index, value),
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
+            Component::ProgressBar(component) => component.set_value(index, value),
+            Component::SegmentHistory(component) => component.set_value(index, value),
             Component::SegmentTime(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
             Component::Text(component) => component.set_value(index, value),
+            Component::Timeline(component) => component.set_value(index, value),
             Component::Timer(component) => component.set_value(index, value),
             Component::Title(component) => component.set_value(index, value),
             Component::TotalPlaytime(component) => component.set_value(index, value),
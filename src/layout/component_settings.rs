@@ -1,7 +1,8 @@
 use super::Component;
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-    possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
+    blank_space, clock, counters, current_comparison, current_pace, custom_image, delta,
+    detailed_timer, graph, pb_chance, pb_history, possible_time_save, previous_segment,
+    progress_bar, segment_history, segment_time, separator, splits, sum_of_best, text, timeline,
     timer, title, total_playtime,
 };
 use crate::platform::prelude::*;
@@ -12,10 +13,16 @@ use serde::{Deserialize, Serialize};
 pub enum ComponentSettings {
     /// The Settings for the Blank Space Component.
     BlankSpace(blank_space::Settings),
+    /// The Settings for the Clock Component.
+    Clock(clock::Settings),
+    /// The Settings for the Counters Component.
+    Counters(counters::Settings),
     /// The Settings for the Current Comparison Component.
     CurrentComparison(current_comparison::Settings),
     /// The Settings for the Current Pace Component.
     CurrentPace(current_pace::Settings),
+    /// The Settings for the Custom Image Component.
+    CustomImage(custom_image::Settings),
     /// The Settings for the Delta Component.
     Delta(delta::Settings),
     /// The Settings for the Detailed Timer Component.
@@ -24,10 +31,16 @@ pub enum ComponentSettings {
     Graph(graph::Settings),
     /// The Settings for the PB Chance Component.
     PbChance(pb_chance::Settings),
+    /// The Settings for the PB History Component.
+    PbHistory(pb_history::Settings),
     /// The Settings for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Settings),
     /// The Settings for the Previous Segment Component.
     PreviousSegment(previous_segment::Settings),
+    /// The Settings for the Progress Bar Component.
+    ProgressBar(progress_bar::Settings),
+    /// The Settings for the Segment History Component.
+    SegmentHistory(segment_history::Settings),
     /// The Settings for the Segment Time Component.
     SegmentTime(segment_time::Settings),
     /// The Settings for the Separator Component.
@@ -38,6 +51,8 @@ pub enum ComponentSettings {
     SumOfBest(sum_of_best::Settings),
     /// The Settings for the Text Component.
     Text(text::Settings),
+    /// The Settings for the Timeline Component.
+    Timeline(timeline::Settings),
     /// The Settings for the Timer Component.
     Timer(timer::Settings),
     /// The Settings for the Title Component.
@@ -52,12 +67,21 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::Clock(settings) => {
+                Component::Clock(clock::Component::with_settings(settings))
+            }
+            ComponentSettings::Counters(settings) => {
+                Component::Counters(counters::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
             ComponentSettings::CurrentPace(settings) => {
                 Component::CurrentPace(current_pace::Component::with_settings(settings))
             }
+            ComponentSettings::CustomImage(settings) => {
+                Component::CustomImage(custom_image::Component::with_settings(settings))
+            }
             ComponentSettings::Delta(settings) => {
                 Component::Delta(delta::Component::with_settings(settings))
             }
@@ -70,12 +94,21 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::PbChance(settings) => {
                 Component::PbChance(pb_chance::Component::with_settings(settings))
             }
+            ComponentSettings::PbHistory(settings) => {
+                Component::PbHistory(pb_history::Component::with_settings(settings))
+            }
             ComponentSettings::PossibleTimeSave(settings) => {
                 Component::PossibleTimeSave(possible_time_save::Component::with_settings(settings))
             }
             ComponentSettings::PreviousSegment(settings) => {
                 Component::PreviousSegment(previous_segment::Component::with_settings(settings))
             }
+            ComponentSettings::ProgressBar(settings) => {
+                Component::ProgressBar(progress_bar::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentHistory(settings) => {
+                Component::SegmentHistory(segment_history::Component::with_settings(settings))
+            }
             ComponentSettings::SegmentTime(settings) => {
                 Component::SegmentTime(segment_time::Component::with_settings(settings))
             }
@@ -89,6 +122,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::Text(settings) => {
                 Component::Text(text::Component::with_settings(settings))
             }
+            ComponentSettings::Timeline(settings) => {
+                Component::Timeline(timeline::Component::with_settings(settings))
+            }
             ComponentSettings::Timer(settings) => {
                 Component::Timer(timer::Component::with_settings(settings))
             }
@@ -1,5 +1,6 @@
 use crate::component::{
-    blank_space, detailed_timer, graph, key_value, separator, splits, text, timer, title,
+    blank_space, clock, counters, custom_image, detailed_timer, graph, key_value, pb_history,
+    progress_bar, segment_history, separator, splits, text, timeline, timer, title,
 };
 use crate::platform::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -9,18 +10,32 @@ use serde::{Deserialize, Serialize};
 pub enum ComponentState {
     /// The state object for the Blank Space Component.
     BlankSpace(blank_space::State),
+    /// The state object for the Clock Component.
+    Clock(clock::State),
+    /// The state object for the Counters Component.
+    Counters(counters::State),
+    /// The state object for the Custom Image Component.
+    CustomImage(custom_image::State),
     /// The state object for the Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::State>),
     /// The state object for the Graph Component.
     Graph(graph::State),
     /// The state object for a key value based component.
     KeyValue(key_value::State),
+    /// The state object for the PB History Component.
+    PbHistory(pb_history::State),
+    /// The state object for the Progress Bar Component.
+    ProgressBar(progress_bar::State),
+    /// The state object for the Segment History Component.
+    SegmentHistory(segment_history::State),
     /// The state object for the Separator Component.
     Separator(separator::State),
     /// The state object for the Splits Component.
     Splits(splits::State),
     /// The state object for the Text Component.
     Text(text::State),
+    /// The state object for the Timeline Component.
+    Timeline(timeline::State),
     /// The state object for the Timer Component.
     Timer(timer::State),
     /// The state object for the Title Component.
@@ -4,8 +4,12 @@
 //! editor as state objects that can be visualized by any kind of User
 //! Interface.
 
-use super::{Component, Layout, LayoutState};
-use crate::{settings::Value, timing::Snapshot};
+use super::{Component, GridCell, GridColumn, Layout, LayoutGrid, LayoutState, Theme};
+use crate::{
+    platform::prelude::*,
+    settings::{Field, SettingsDescription, Value},
+    timing::Snapshot,
+};
 use core::result::Result as StdResult;
 
 mod state;
@@ -99,10 +103,12 @@ impl Editor {
     /// component instead.
     pub fn remove_component(&mut self) {
         if self.can_remove_component() {
+            self.change_cell_size(self.selected_component, -1);
             self.layout.components.remove(self.selected_component);
             if self.selected_component >= self.layout.components.len() {
                 self.selected_component = self.layout.components.len() - 1;
             }
+            self.normalize_grid();
             self.layout.remount();
         }
     }
@@ -114,12 +120,19 @@ impl Editor {
     }
 
     /// Moves the selected component up, unless the first component is selected.
+    /// If the components are arranged in a grid and the component is the first
+    /// one in its cell, it moves to the end of the previous cell instead.
     pub fn move_component_up(&mut self) {
         if self.can_move_component_up() {
-            self.layout
-                .components
-                .swap(self.selected_component, self.selected_component - 1);
-            self.selected_component -= 1;
+            if self.is_first_in_cell(self.selected_component) {
+                self.move_between_cells(self.selected_component, self.selected_component - 1);
+                self.normalize_grid();
+            } else {
+                self.layout
+                    .components
+                    .swap(self.selected_component, self.selected_component - 1);
+                self.selected_component -= 1;
+            }
             self.layout.remount();
         }
     }
@@ -131,27 +144,36 @@ impl Editor {
     }
 
     /// Moves the selected component down, unless the last component is
-    /// selected.
+    /// selected. If the components are arranged in a grid and the component is
+    /// the last one in its cell, it moves to the start of the next cell
+    /// instead.
     pub fn move_component_down(&mut self) {
         if self.can_move_component_down() {
-            self.layout
-                .components
-                .swap(self.selected_component, self.selected_component + 1);
-            self.selected_component += 1;
+            if self.is_first_in_cell(self.selected_component + 1) {
+                self.move_between_cells(self.selected_component, self.selected_component + 1);
+                self.normalize_grid();
+            } else {
+                self.layout
+                    .components
+                    .swap(self.selected_component, self.selected_component + 1);
+                self.selected_component += 1;
+            }
             self.layout.remount();
         }
     }
 
     /// Moves the selected component to the index provided. You may not provide
-    /// an invalid index.
+    /// an invalid index. If the components are arranged in a grid, the
+    /// component ends up in the cell of the component that was at that index
+    /// before.
     pub fn move_component(&mut self, dst_index: usize) {
-        if dst_index < self.layout.components.len() {
-            while self.selected_component > dst_index {
-                self.move_component_up();
-            }
-            while self.selected_component < dst_index {
-                self.move_component_down();
-            }
+        if dst_index < self.layout.components.len() && dst_index != self.selected_component {
+            self.move_between_cells(self.selected_component, dst_index);
+            let component = self.layout.components.remove(self.selected_component);
+            self.layout.components.insert(dst_index, component);
+            self.selected_component = dst_index;
+            self.normalize_grid();
+            self.layout.remount();
         }
     }
 
@@ -163,6 +185,7 @@ impl Editor {
 
         let component = self.layout.components[index].clone();
         self.layout.components.insert(new_index, component);
+        self.change_cell_size(index, 1);
 
         self.selected_component = new_index;
         self.layout.remount();
@@ -188,7 +211,205 @@ impl Editor {
     /// This panics if the type of the value to be set is not compatible with
     /// the type of the setting's value. A panic can also occur if the index of
     /// the setting provided is out of bounds.
+    ///
+    /// If the layout references a theme and the setting is one of the theme's
+    /// settings, the setting overrides the theme's setting from now on.
     pub fn set_general_settings_value(&mut self, index: usize, value: Value) {
-        self.layout.general_settings_mut().set_value(index, value);
+        let layout = &mut self.layout;
+        layout.settings.set_value(index, value);
+        if layout.theme.is_some() {
+            layout
+                .theme_overrides
+                .override_setting(index, &layout.settings);
+        }
+    }
+
+    /// Applies the theme provided to the layout. All the general settings the
+    /// layout overrides are kept, while all the others are replaced by the
+    /// theme's settings. Check [`Layout::apply_theme`] for more information.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        self.layout.apply_theme(theme);
+    }
+
+    /// Stops the layout from referencing a theme and drops all the overrides.
+    /// The general settings of the layout keep their current values.
+    pub fn detach_theme(&mut self) {
+        self.layout.detach_theme();
+    }
+
+    /// Checks if the currently selected component can be moved into a new
+    /// column. This is not possible if it is the only component in its
+    /// column.
+    pub fn can_move_component_into_new_column(&self) -> bool {
+        match &self.layout.grid {
+            Some(grid) => {
+                let (column_index, _) = self.selected_cell(grid);
+                let column = &grid.columns[column_index];
+                column.cells.iter().map(|c| c.components).sum::<usize>() > 1
+            }
+            None => self.layout.components.len() > 1,
+        }
+    }
+
+    /// Moves the selected component into a new column that is placed right
+    /// after the column it is currently in. If the components are not
+    /// arranged in a grid yet, a grid is created that places all the other
+    /// components in the first column.
+    pub fn move_component_into_new_column(&mut self) {
+        if self.can_move_component_into_new_column() {
+            self.ensure_grid();
+            let grid = self.layout.grid.as_ref().unwrap();
+            let (column_index, cell_index) = self.selected_cell(grid);
+            let direction = grid.columns[column_index].cells[cell_index].direction;
+            self.change_cell_size(self.selected_component, -1);
+
+            let grid = self.layout.grid.as_mut().unwrap();
+            grid.columns
+                .insert(column_index + 1, GridColumn::new(direction, 1));
+            let dst_index = grid.first_component_of(column_index + 1, 0);
+            self.move_selected_to(dst_index);
+        }
+    }
+
+    /// Checks if the currently selected component can be moved into a new row.
+    /// This is not possible if it is the only component in its cell.
+    pub fn can_move_component_into_new_row(&self) -> bool {
+        match &self.layout.grid {
+            Some(grid) => {
+                let (column_index, cell_index) = self.selected_cell(grid);
+                grid.columns[column_index].cells[cell_index].components > 1
+            }
+            None => self.layout.components.len() > 1,
+        }
+    }
+
+    /// Moves the selected component into a new cell that is placed right below
+    /// the cell it is currently in. If the components are not arranged in a
+    /// grid yet, a grid is created that places all the other components in
+    /// the first cell.
+    pub fn move_component_into_new_row(&mut self) {
+        if self.can_move_component_into_new_row() {
+            self.ensure_grid();
+            let grid = self.layout.grid.as_ref().unwrap();
+            let (column_index, cell_index) = self.selected_cell(grid);
+            let direction = grid.columns[column_index].cells[cell_index].direction;
+            self.change_cell_size(self.selected_component, -1);
+
+            let grid = self.layout.grid.as_mut().unwrap();
+            grid.columns[column_index]
+                .cells
+                .insert(cell_index + 1, GridCell::new(direction, 1));
+            let dst_index = grid.first_component_of(column_index, cell_index + 1);
+            self.move_selected_to(dst_index);
+        }
+    }
+
+    /// Sets a setting's value of the grid cell the selected component is in by
+    /// its setting index to the given value. This does nothing if the
+    /// components are not arranged in a grid.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_cell_settings_value(&mut self, index: usize, value: Value) {
+        if let Some(grid) = &self.layout.grid {
+            let (column_index, cell_index) = self.selected_cell(grid);
+            let grid = self.layout.grid.as_mut().unwrap();
+            let column = &mut grid.columns[column_index];
+            match index {
+                0 => column.cells[cell_index].direction = value.into(),
+                1 => column.size = u64::from(value).clamp(1, u32::MAX as u64) as u32,
+                2 => {
+                    column.cells[cell_index].size =
+                        u64::from(value).clamp(1, u32::MAX as u64) as u32
+                }
+                _ => panic!("Unsupported Setting Index"),
+            }
+        }
+    }
+
+    /// Accesses a generic description of the settings of the grid cell the
+    /// selected component is in. This is `None` if the components are not
+    /// arranged in a grid.
+    pub(super) fn cell_settings_description(&self) -> Option<SettingsDescription> {
+        let grid = self.layout.grid.as_ref()?;
+        let (column_index, cell_index) = self.selected_cell(grid);
+        let column = &grid.columns[column_index];
+        let cell = &column.cells[cell_index];
+        Some(SettingsDescription::with_fields(vec![
+            Field::new("Direction".into(), cell.direction.into()),
+            Field::new("Column Width".into(), u64::from(column.size).into()),
+            Field::new("Row Height".into(), u64::from(cell.size).into()),
+        ]))
+    }
+
+    fn selected_cell(&self, grid: &LayoutGrid) -> (usize, usize) {
+        grid.cell_of(self.selected_component).unwrap_or_default()
+    }
+
+    fn ensure_grid(&mut self) {
+        if self.layout.grid.is_none() {
+            let direction = self.layout.general_settings().direction;
+            self.layout.grid = Some(LayoutGrid::new(direction, self.layout.components.len()));
+        }
+    }
+
+    fn is_first_in_cell(&self, index: usize) -> bool {
+        self.layout.grid.as_ref().map_or(false, |grid| {
+            grid.cell_of(index).map_or(false, |(column, cell)| {
+                grid.first_component_of(column, cell) == index
+            })
+        })
+    }
+
+    /// Moves one component from the grid cell that holds the component at the
+    /// source index to the grid cell that holds the component at the
+    /// destination index. Only the amounts of components held by the cells
+    /// are adjusted.
+    fn move_between_cells(&mut self, src_index: usize, dst_index: usize) {
+        if let Some(grid) = &mut self.layout.grid {
+            if let (Some(src), Some(dst)) = (grid.cell_of(src_index), grid.cell_of(dst_index)) {
+                grid.columns[src.0].cells[src.1].components -= 1;
+                grid.columns[dst.0].cells[dst.1].components += 1;
+            }
+        }
+    }
+
+    /// Changes the amount of components held by the grid cell that holds the
+    /// component with the index provided.
+    fn change_cell_size(&mut self, index: usize, change: isize) {
+        if let Some(grid) = &mut self.layout.grid {
+            if let Some((column_index, cell_index)) = grid.cell_of(index) {
+                let cell = &mut grid.columns[column_index].cells[cell_index];
+                cell.components = (cell.components as isize + change) as usize;
+            }
+        }
+    }
+
+    /// Moves the selected component to the index provided without adjusting
+    /// the grid, as the caller already did so. The index refers to the
+    /// components without the selected component.
+    fn move_selected_to(&mut self, dst_index: usize) {
+        let component = self.layout.components.remove(self.selected_component);
+        self.layout.components.insert(dst_index, component);
+        self.selected_component = dst_index;
+        self.normalize_grid();
+        self.layout.remount();
+    }
+
+    /// Removes the cells and columns that don't hold any components anymore.
+    /// If only a single cell is left, the grid is removed entirely and its
+    /// direction is used for the whole layout.
+    fn normalize_grid(&mut self) {
+        self.layout.fit_grid();
+        if let Some(grid) = &self.layout.grid {
+            if grid.is_single_cell() {
+                let direction = grid.columns[0].cells[0].direction;
+                self.layout.general_settings_mut().direction = direction;
+                self.layout.grid = None;
+            }
+        }
     }
 }
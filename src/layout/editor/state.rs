@@ -1,4 +1,5 @@
 use super::Editor;
+use crate::layout::LayoutGrid;
 use crate::platform::prelude::*;
 use crate::settings::SettingsDescription;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,17 @@ pub struct State {
     /// A generic description of the general settings available for the layout
     /// and their current values.
     pub general_settings: SettingsDescription,
+    /// The grid the components are arranged in. If there is no grid, the
+    /// components are laid out in the direction specified by the general
+    /// settings.
+    pub grid: Option<LayoutGrid>,
+    /// A generic description of the settings available for the grid cell the
+    /// selected component is in and their current values. This is `None` if
+    /// the components are not arranged in a grid.
+    pub cell_settings: Option<SettingsDescription>,
+    /// The name of the theme the layout references. This is `None` if the
+    /// layout doesn't reference a theme.
+    pub theme: Option<String>,
 }
 
 /// Describes which actions are currently available. Depending on how many
@@ -35,6 +47,13 @@ pub struct Buttons {
     /// Describes whether the currently selected component can be moved down. If
     /// the last component is selected, it can't be moved.
     pub can_move_down: bool,
+    /// Describes whether the currently selected component can be moved into a
+    /// new column. If it is the only component in its column, it can't be
+    /// moved.
+    pub can_move_into_new_column: bool,
+    /// Describes whether the currently selected component can be moved into a
+    /// new row. If it is the only component in its cell, it can't be moved.
+    pub can_move_into_new_row: bool,
 }
 
 #[cfg(feature = "std")]
@@ -62,6 +81,8 @@ impl Editor {
             can_remove: self.can_remove_component(),
             can_move_up: self.can_move_component_up(),
             can_move_down: self.can_move_component_down(),
+            can_move_into_new_column: self.can_move_component_into_new_column(),
+            can_move_into_new_row: self.can_move_component_into_new_row(),
         };
 
         State {
@@ -71,6 +92,9 @@ impl Editor {
             component_settings: self.layout.components[self.selected_component]
                 .settings_description(),
             general_settings: self.layout.general_settings().settings_description(),
+            grid: self.layout.grid().cloned(),
+            cell_settings: self.cell_settings_description(),
+            theme: self.layout.theme.clone(),
         }
     }
 }
@@ -9,7 +9,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct GeneralSettings {
-    /// The direction which the components are laid out in.
+    /// The direction which the components are laid out in. This only applies
+    /// if the components aren't arranged in a grid.
     pub direction: LayoutDirection,
     /// The font to use for the timer text. `None` means a default font should
     /// be used.
@@ -48,6 +49,9 @@ pub struct GeneralSettings {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// Specifies whether changes in the layout, such as the splits scrolling or
+    /// a delta changing its color, are animated.
+    pub animations: bool,
 }
 
 impl Default for GeneralSettings {
@@ -69,6 +73,7 @@ impl Default for GeneralSettings {
             thin_separators_color: Color::hsla(0.0, 0.0, 1.0, 0.09),
             separators_color: Color::hsla(0.0, 0.0, 1.0, 0.35),
             text_color: Color::hsla(0.0, 0.0, 1.0, 1.0),
+            animations: true,
         }
     }
 }
@@ -106,6 +111,7 @@ impl GeneralSettings {
             Field::new("Thin Separators".into(), self.thin_separators_color.into()),
             Field::new("Separators".into(), self.separators_color.into()),
             Field::new("Text".into(), self.text_color.into()),
+            Field::new("Animations".into(), self.animations.into()),
         ])
     }
 
@@ -134,6 +140,7 @@ impl GeneralSettings {
             13 => self.thin_separators_color = value.into(),
             14 => self.separators_color = value.into(),
             15 => self.text_color = value.into(),
+            16 => self.animations = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
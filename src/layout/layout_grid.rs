@@ -0,0 +1,136 @@
+use super::LayoutDirection;
+use crate::platform::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A grid arranges the components of a layout in columns that are placed next
+/// to each other. Each column is split into cells placed on top of each other
+/// and each cell holds its own list of components. The components of the
+/// layout are assigned to the cells in order, going through the cells of the
+/// first column from top to bottom, then through the ones of the second column
+/// and so on.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutGrid {
+    /// The columns of the grid from left to right.
+    pub columns: Vec<GridColumn>,
+}
+
+/// A column of a [`LayoutGrid`].
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GridColumn {
+    /// The width of the column relative to the other columns.
+    pub size: u32,
+    /// The cells of the column from top to bottom.
+    pub cells: Vec<GridCell>,
+}
+
+/// A cell of a [`GridColumn`] that holds a list of components.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GridCell {
+    /// The height of the cell relative to the other cells in the column.
+    pub size: u32,
+    /// The direction which the components of the cell are laid out in.
+    pub direction: LayoutDirection,
+    /// The amount of components the cell holds.
+    pub components: usize,
+}
+
+impl GridColumn {
+    /// Creates a new column with a single cell that holds the amount of
+    /// components provided.
+    pub fn new(direction: LayoutDirection, components: usize) -> Self {
+        Self {
+            size: 1,
+            cells: vec![GridCell::new(direction, components)],
+        }
+    }
+}
+
+impl GridCell {
+    /// Creates a new cell that holds the amount of components provided.
+    pub const fn new(direction: LayoutDirection, components: usize) -> Self {
+        Self {
+            size: 1,
+            direction,
+            components,
+        }
+    }
+}
+
+impl LayoutGrid {
+    /// Creates a new grid with a single cell that holds the amount of
+    /// components provided.
+    pub fn new(direction: LayoutDirection, components: usize) -> Self {
+        Self {
+            columns: vec![GridColumn::new(direction, components)],
+        }
+    }
+
+    /// Iterates over all the cells of the grid in the order the components are
+    /// assigned to them.
+    pub fn cells(&self) -> impl Iterator<Item = &GridCell> + '_ {
+        self.columns.iter().flat_map(|c| &c.cells)
+    }
+
+    /// Returns the total amount of components held by the cells of the grid.
+    pub fn component_count(&self) -> usize {
+        self.cells().map(|c| c.components).sum()
+    }
+
+    /// Looks up the cell that holds the component with the index provided.
+    /// The cell is returned as the index of its column and its index within
+    /// that column.
+    pub fn cell_of(&self, component_index: usize) -> Option<(usize, usize)> {
+        let mut end = 0;
+        for (column_index, column) in self.columns.iter().enumerate() {
+            for (cell_index, cell) in column.cells.iter().enumerate() {
+                end += cell.components;
+                if component_index < end {
+                    return Some((column_index, cell_index));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the index of the first component held by the cell provided.
+    pub fn first_component_of(&self, column_index: usize, cell_index: usize) -> usize {
+        let previous_columns = self.columns[..column_index]
+            .iter()
+            .flat_map(|c| &c.cells)
+            .map(|c| c.components);
+        let previous_cells = self.columns[column_index].cells[..cell_index]
+            .iter()
+            .map(|c| c.components);
+        previous_columns.chain(previous_cells).sum()
+    }
+
+    /// Makes sure the grid holds exactly the amount of components provided.
+    /// Cells that hold more components than available are shrunk and any
+    /// components not held by any cell are added to the last cell. Cells that
+    /// don't hold any components are removed, just like columns that end up
+    /// without any cells. Sizes of zero are treated as a size of one.
+    pub fn fit(&mut self, component_count: usize) {
+        let mut remaining = component_count;
+        for column in &mut self.columns {
+            column.size = column.size.max(1);
+            for cell in &mut column.cells {
+                cell.size = cell.size.max(1);
+                cell.components = cell.components.min(remaining);
+                remaining -= cell.components;
+            }
+        }
+        if let Some(cell) = self.columns.last_mut().and_then(|c| c.cells.last_mut()) {
+            cell.components += remaining;
+        }
+        for column in &mut self.columns {
+            column.cells.retain(|c| c.components != 0);
+        }
+        self.columns.retain(|c| !c.cells.is_empty());
+    }
+
+    /// Checks if the grid consists of only a single cell, which makes it
+    /// equivalent to a layout without a grid.
+    pub fn is_single_cell(&self) -> bool {
+        self.cells().nth(1).is_none()
+    }
+}
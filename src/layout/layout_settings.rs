@@ -1,4 +1,4 @@
-use super::{ComponentSettings, GeneralSettings};
+use super::{ComponentSettings, GeneralSettings, LayoutGrid, ThemeOverrides};
 use crate::platform::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +10,18 @@ pub struct LayoutSettings {
     pub components: Vec<ComponentSettings>,
     /// The general settings of the layout that apply to all components.
     pub general: GeneralSettings,
+    /// The grid the components are arranged in. If there is no grid, the
+    /// components are laid out in the direction specified by the general
+    /// settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grid: Option<LayoutGrid>,
+    /// The name of the theme the layout references.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// The settings of the layout that override the ones of the theme it
+    /// references.
+    #[serde(default, skip_serializing_if = "ThemeOverrides::is_empty")]
+    pub theme_overrides: ThemeOverrides,
 }
 
 #[cfg(feature = "std")]
@@ -1,4 +1,4 @@
-use super::{ComponentState, LayoutDirection};
+use super::{ComponentState, LayoutDirection, LayoutGrid};
 use crate::{
     platform::prelude::*,
     settings::{Color, Font, Gradient},
@@ -10,8 +10,13 @@ use serde::{Deserialize, Serialize};
 pub struct LayoutState {
     /// The state objects for all of the components in the layout.
     pub components: Vec<ComponentState>,
-    /// The direction which the components are laid out in.
+    /// The direction which the components are laid out in. This only applies
+    /// if the components aren't arranged in a grid.
     pub direction: LayoutDirection,
+    /// The grid the components are arranged in. Each cell of the grid lays
+    /// out its components in its own direction. If there is no grid, the
+    /// components are laid out in the direction specified above.
+    pub grid: Option<LayoutGrid>,
     /// The font to use for the timer text. `None` means a default font should
     /// be used.
     pub timer_font: Option<Font>,
@@ -29,6 +34,9 @@ pub struct LayoutState {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// Specifies whether changes in the layout, such as the splits scrolling or
+    /// a delta changing its color, are supposed to be animated.
+    pub animations: bool,
 }
 
 #[cfg(feature = "std")]
@@ -2,20 +2,30 @@
 //! Layout allows you to combine multiple components together to visualize a
 //! variety of information the runner is interested in.
 
+pub mod accessibility;
 mod component;
 mod component_settings;
 mod component_state;
 pub mod editor;
 mod general_settings;
 mod layout_direction;
+mod layout_grid;
 mod layout_settings;
 mod layout_state;
 pub mod parser;
+mod theme;
 
 pub use self::{
-    component::Component, component_settings::ComponentSettings, component_state::ComponentState,
-    editor::Editor, general_settings::GeneralSettings, layout_direction::LayoutDirection,
-    layout_settings::LayoutSettings, layout_state::LayoutState,
+    component::Component,
+    component_settings::ComponentSettings,
+    component_state::ComponentState,
+    editor::Editor,
+    general_settings::GeneralSettings,
+    layout_direction::LayoutDirection,
+    layout_grid::{GridCell, GridColumn, LayoutGrid},
+    layout_settings::LayoutSettings,
+    layout_state::LayoutState,
+    theme::{Theme, ThemeOverrides},
 };
 
 use crate::{
@@ -31,6 +41,9 @@ pub struct Layout {
     /// All of the layout's components.
     pub components: Vec<Component>,
     settings: GeneralSettings,
+    grid: Option<LayoutGrid>,
+    theme: Option<String>,
+    theme_overrides: ThemeOverrides,
 }
 
 impl Layout {
@@ -52,19 +65,27 @@ impl Layout {
                 previous_segment::Component::new().into(),
             ],
             settings: GeneralSettings::default(),
+            grid: None,
+            theme: None,
+            theme_overrides: ThemeOverrides::default(),
         }
     }
 
     /// Creates a new layout from the layout settings of the whole layout.
     pub fn from_settings(layout_settings: LayoutSettings) -> Self {
-        Self {
+        let mut layout = Self {
             components: layout_settings
                 .components
                 .into_iter()
                 .map(Into::into)
                 .collect(),
             settings: layout_settings.general,
-        }
+            grid: None,
+            theme: layout_settings.theme,
+            theme_overrides: layout_settings.theme_overrides,
+        };
+        layout.set_grid(layout_settings.grid);
+        layout
     }
 
     /// Accesses the general settings of the layout that apply to all
@@ -79,14 +100,86 @@ impl Layout {
         &mut self.settings
     }
 
-    /// Adds a new component to the end of the layout.
+    /// Accesses the name of the theme the layout references.
+    pub fn theme(&self) -> Option<&str> {
+        self.theme.as_deref()
+    }
+
+    /// Accesses the settings of the layout that override the ones of the
+    /// theme it references.
+    pub const fn theme_overrides(&self) -> &ThemeOverrides {
+        &self.theme_overrides
+    }
+
+    /// Grants mutable access to the settings of the layout that override the
+    /// ones of the theme it references. The changes only affect the general
+    /// settings once a theme gets applied.
+    pub fn theme_overrides_mut(&mut self) -> &mut ThemeOverrides {
+        &mut self.theme_overrides
+    }
+
+    /// Applies the theme provided to the layout and references it by its name
+    /// from now on. All the general settings the layout overrides are kept,
+    /// while all the others are replaced by the theme's settings. Applying an
+    /// updated version of the referenced theme again updates the layout
+    /// accordingly.
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply_to(&mut self.settings, &self.theme_overrides);
+        self.theme = Some(theme.name.clone());
+    }
+
+    /// Stops referencing a theme and drops all the overrides. The general
+    /// settings of the layout keep their current values.
+    pub fn detach_theme(&mut self) {
+        self.theme = None;
+        self.theme_overrides = ThemeOverrides::default();
+    }
+
+    /// Accesses the grid the components are arranged in. If there is no grid,
+    /// the components are laid out in the direction specified by the general
+    /// settings.
+    pub const fn grid(&self) -> Option<&LayoutGrid> {
+        self.grid.as_ref()
+    }
+
+    /// Arranges the components in the grid provided. The grid is adjusted to
+    /// hold exactly the components of the layout. Check [`LayoutGrid::fit`]
+    /// for how this works. Setting the grid to `None` lays out the components
+    /// in the direction specified by the general settings again.
+    pub fn set_grid(&mut self, grid: Option<LayoutGrid>) {
+        self.grid = grid;
+        self.fit_grid();
+    }
+
+    /// Ensures the grid holds exactly the components of the layout, as they may
+    /// have been modified directly.
+    fn fit_grid(&mut self) {
+        if let Some(grid) = &mut self.grid {
+            grid.fit(self.components.len());
+            if grid.columns.is_empty() {
+                self.grid = None;
+            }
+        }
+    }
+
+    /// Adds a new component to the end of the layout. If the components are
+    /// arranged in a grid, the component is added to the last cell.
     pub fn push<C: Into<Component>>(&mut self, component: C) {
         self.components.push(component.into());
+        if let Some(cell) = self
+            .grid
+            .as_mut()
+            .and_then(|g| g.columns.last_mut())
+            .and_then(|c| c.cells.last_mut())
+        {
+            cell.components += 1;
+        }
     }
 
     /// Updates the layout's state based on the timer provided. You can use this
     /// to visualize all of the components of a layout.
     pub fn update_state(&mut self, state: &mut LayoutState, timer: &Snapshot<'_>) {
+        self.fit_grid();
         let settings = &self.settings;
 
         state.components.truncate(self.components.len());
@@ -108,7 +201,9 @@ impl Layout {
         state.thin_separators_color = settings.thin_separators_color;
         state.separators_color = settings.separators_color;
         state.text_color = settings.text_color;
+        state.animations = settings.animations;
         state.direction = settings.direction;
+        state.grid.clone_from(&self.grid);
     }
 
     /// Calculates the layout's state based on the timer provided. You can use
@@ -124,6 +219,9 @@ impl Layout {
         LayoutSettings {
             components: self.components.iter().map(Component::settings).collect(),
             general: self.settings.clone(),
+            grid: self.grid.clone(),
+            theme: self.theme.clone(),
+            theme_overrides: self.theme_overrides.clone(),
         }
     }
 
@@ -613,19 +613,26 @@ where
                 if let Some(component) = &mut component {
                     match component {
                         Component::BlankSpace(c) => blank_space::settings(reader, c),
+                        Component::Clock(_) => end_tag(reader),
+                        Component::Counters(_) => end_tag(reader),
+                        Component::CustomImage(_) => end_tag(reader),
                         Component::CurrentComparison(c) => current_comparison::settings(reader, c),
                         Component::CurrentPace(c) => current_pace::settings(reader, c),
                         Component::Delta(c) => delta::settings(reader, c),
                         Component::DetailedTimer(c) => detailed_timer::settings(reader, c),
                         Component::Graph(c) => graph::settings(reader, c),
                         Component::PbChance(c) => pb_chance::settings(reader, c),
+                        Component::PbHistory(_) => end_tag(reader),
                         Component::PossibleTimeSave(c) => possible_time_save::settings(reader, c),
                         Component::PreviousSegment(c) => previous_segment::settings(reader, c),
+                        Component::ProgressBar(_) => end_tag(reader),
+                        Component::SegmentHistory(_) => end_tag(reader),
                         Component::SegmentTime(_) => end_tag(reader),
                         Component::Separator(_) => end_tag(reader),
                         Component::Splits(c) => splits::settings(reader, c),
                         Component::SumOfBest(c) => sum_of_best::settings(reader, c),
                         Component::Text(c) => text::settings(reader, c),
+                        Component::Timeline(_) => end_tag(reader),
                         Component::Timer(c) => timer::settings(reader, c),
                         Component::Title(c) => title::settings(reader, c),
                         Component::TotalPlaytime(c) => total_playtime::settings(reader, c),
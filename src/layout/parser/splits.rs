@@ -139,6 +139,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                                         update_trigger: ColumnUpdateTrigger::OnEndingSegment,
                                         comparison_override: comparison_override.clone(),
                                         timing_method: None,
+                                        color_rules: Vec::new(),
                                     }),
                                 });
                                 settings.columns.push(ColumnSettings {
@@ -149,6 +150,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                                         update_trigger: ColumnUpdateTrigger::Contextual,
                                         comparison_override,
                                         timing_method: None,
+                                        color_rules: Vec::new(),
                                     }),
                                 });
                             }
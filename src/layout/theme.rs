@@ -0,0 +1,254 @@
+use super::GeneralSettings;
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Font, Gradient, SemanticColor},
+};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A theme describes the look of a layout, independent of the components it
+/// consists of. It contains the fonts, the background and all the colors,
+/// including the colors for each [`SemanticColor`]. A layout references the
+/// theme that is applied to it by its name and may override any of the theme's
+/// settings with its [`ThemeOverrides`]. This allows sharing a single color
+/// scheme between many layouts and updating all of them by applying the updated
+/// theme again.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// The name of the theme.
+    pub name: String,
+    /// The font to use for the timer text. `None` means a default font should
+    /// be used.
+    pub timer_font: Option<Font>,
+    /// The font to use for the times and other values. `None` means a default
+    /// font should be used.
+    pub times_font: Option<Font>,
+    /// The font to use for regular text. `None` means a default font should be
+    /// used.
+    pub text_font: Option<Font>,
+    /// The background to show behind the layout.
+    pub background: Gradient,
+    /// The color to use for when the runner achieved a best segment.
+    pub best_segment_color: Color,
+    /// The color to use for when the runner is ahead of the comparison and is
+    /// gaining even more time.
+    pub ahead_gaining_time_color: Color,
+    /// The color to use for when the runner is ahead of the comparison, but is
+    /// losing time.
+    pub ahead_losing_time_color: Color,
+    /// The color to use for when the runner is behind the comparison, but is
+    /// gaining back time.
+    pub behind_gaining_time_color: Color,
+    /// The color to use for when the runner is behind the comparison and is
+    /// losing even more time.
+    pub behind_losing_time_color: Color,
+    /// The color to use for when there is no active attempt.
+    pub not_running_color: Color,
+    /// The color to use for when the runner achieved a new Personal Best.
+    pub personal_best_color: Color,
+    /// The color to use for when the timer is paused.
+    pub paused_color: Color,
+    /// The color of thin separators.
+    pub thin_separators_color: Color,
+    /// The color of normal separators.
+    pub separators_color: Color,
+    /// The text color to use for text that doesn't specify its own color.
+    pub text_color: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::from_settings(String::new(), &GeneralSettings::default())
+    }
+}
+
+impl Theme {
+    /// Creates a new theme with the name provided out of the look of a
+    /// layout's general settings.
+    pub fn from_settings(name: String, settings: &GeneralSettings) -> Self {
+        Self {
+            name,
+            timer_font: settings.timer_font.clone(),
+            times_font: settings.times_font.clone(),
+            text_font: settings.text_font.clone(),
+            background: settings.background,
+            best_segment_color: settings.best_segment_color,
+            ahead_gaining_time_color: settings.ahead_gaining_time_color,
+            ahead_losing_time_color: settings.ahead_losing_time_color,
+            behind_gaining_time_color: settings.behind_gaining_time_color,
+            behind_losing_time_color: settings.behind_losing_time_color,
+            not_running_color: settings.not_running_color,
+            personal_best_color: settings.personal_best_color,
+            paused_color: settings.paused_color,
+            thin_separators_color: settings.thin_separators_color,
+            separators_color: settings.separators_color,
+            text_color: settings.text_color,
+        }
+    }
+
+    /// Accesses the color the theme uses for the [`SemanticColor`] provided.
+    pub const fn semantic_color(&self, semantic_color: SemanticColor) -> Color {
+        match semantic_color {
+            SemanticColor::Default => self.text_color,
+            SemanticColor::AheadGainingTime => self.ahead_gaining_time_color,
+            SemanticColor::AheadLosingTime => self.ahead_losing_time_color,
+            SemanticColor::BehindLosingTime => self.behind_losing_time_color,
+            SemanticColor::BehindGainingTime => self.behind_gaining_time_color,
+            SemanticColor::BestSegment => self.best_segment_color,
+            SemanticColor::NotRunning => self.not_running_color,
+            SemanticColor::Paused => self.paused_color,
+            SemanticColor::PersonalBest => self.personal_best_color,
+        }
+    }
+
+    /// Applies the theme to a layout's general settings. Each setting that is
+    /// overridden is set to its override, while all the other ones are
+    /// replaced by the theme's settings.
+    pub fn apply_to(&self, settings: &mut GeneralSettings, overrides: &ThemeOverrides) {
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                $(
+                    let value = overrides.$field.as_ref().unwrap_or(&self.$field);
+                    settings.$field.clone_from(value);
+                )*
+            };
+        }
+
+        apply!(
+            timer_font,
+            times_font,
+            text_font,
+            background,
+            best_segment_color,
+            ahead_gaining_time_color,
+            ahead_losing_time_color,
+            behind_gaining_time_color,
+            behind_losing_time_color,
+            not_running_color,
+            personal_best_color,
+            paused_color,
+            thin_separators_color,
+            separators_color,
+            text_color
+        );
+    }
+}
+
+/// The settings of a layout that override the ones of the [`Theme`] it
+/// references. Each setting that is `None` is not overridden and is taken from
+/// the theme.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeOverrides {
+    /// Overrides the font to use for the timer text. `Some(None)` overrides it
+    /// with a default font.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_font_override"
+    )]
+    pub timer_font: Option<Option<Font>>,
+    /// Overrides the font to use for the times and other values. `Some(None)`
+    /// overrides it with a default font.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_font_override"
+    )]
+    pub times_font: Option<Option<Font>>,
+    /// Overrides the font to use for regular text. `Some(None)` overrides it
+    /// with a default font.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_font_override"
+    )]
+    pub text_font: Option<Option<Font>>,
+    /// Overrides the background to show behind the layout.
+    pub background: Option<Gradient>,
+    /// Overrides the color to use for when the runner achieved a best segment.
+    pub best_segment_color: Option<Color>,
+    /// Overrides the color to use for when the runner is ahead of the
+    /// comparison and is gaining even more time.
+    pub ahead_gaining_time_color: Option<Color>,
+    /// Overrides the color to use for when the runner is ahead of the
+    /// comparison, but is losing time.
+    pub ahead_losing_time_color: Option<Color>,
+    /// Overrides the color to use for when the runner is behind the
+    /// comparison, but is gaining back time.
+    pub behind_gaining_time_color: Option<Color>,
+    /// Overrides the color to use for when the runner is behind the
+    /// comparison and is losing even more time.
+    pub behind_losing_time_color: Option<Color>,
+    /// Overrides the color to use for when there is no active attempt.
+    pub not_running_color: Option<Color>,
+    /// Overrides the color to use for when the runner achieved a new Personal
+    /// Best.
+    pub personal_best_color: Option<Color>,
+    /// Overrides the color to use for when the timer is paused.
+    pub paused_color: Option<Color>,
+    /// Overrides the color of thin separators.
+    pub thin_separators_color: Option<Color>,
+    /// Overrides the color of normal separators.
+    pub separators_color: Option<Color>,
+    /// Overrides the text color to use for text that doesn't specify its own
+    /// color.
+    pub text_color: Option<Color>,
+}
+
+/// A font override that is present, but `null`, overrides the font with a
+/// default font, which a plain `Option` can't distinguish from a missing
+/// override.
+fn deserialize_font_override<'de, D>(deserializer: D) -> Result<Option<Option<Font>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+impl ThemeOverrides {
+    /// Checks whether none of the theme's settings are overridden.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Overrides the setting of the theme that the general setting with the
+    /// index provided corresponds to with the current value of that general
+    /// setting. General settings that are not part of a theme are ignored.
+    pub(super) fn override_setting(&mut self, index: usize, settings: &GeneralSettings) {
+        match index {
+            1 => self.timer_font = Some(settings.timer_font.clone()),
+            2 => self.times_font = Some(settings.times_font.clone()),
+            3 => self.text_font = Some(settings.text_font.clone()),
+            4 => self.background = Some(settings.background),
+            5 => self.best_segment_color = Some(settings.best_segment_color),
+            6 => self.ahead_gaining_time_color = Some(settings.ahead_gaining_time_color),
+            7 => self.ahead_losing_time_color = Some(settings.ahead_losing_time_color),
+            8 => self.behind_gaining_time_color = Some(settings.behind_gaining_time_color),
+            9 => self.behind_losing_time_color = Some(settings.behind_losing_time_color),
+            10 => self.not_running_color = Some(settings.not_running_color),
+            11 => self.personal_best_color = Some(settings.personal_best_color),
+            12 => self.paused_color = Some(settings.paused_color),
+            13 => self.thin_separators_color = Some(settings.thin_separators_color),
+            14 => self.separators_color = Some(settings.separators_color),
+            15 => self.text_color = Some(settings.text_color),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Theme {
+    /// Decodes the theme from JSON.
+    pub fn from_json<R>(reader: R) -> serde_json::Result<Theme>
+    where
+        R: std::io::Read,
+    {
+        serde_json::from_reader(reader)
+    }
+
+    /// Encodes the theme as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
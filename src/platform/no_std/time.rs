@@ -1,6 +1,6 @@
 use crate::platform::prelude::*;
 use core::{
-    ops::Sub,
+    ops::{Add, Sub},
     sync::atomic::{self, AtomicPtr},
 };
 
@@ -52,6 +52,14 @@ impl Instant {
     }
 }
 
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Instant {
+        Self(self.0 + rhs)
+    }
+}
+
 impl Sub<Duration> for Instant {
     type Output = Instant;
 
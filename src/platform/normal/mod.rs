@@ -106,7 +106,7 @@ cfg_if::cfg_if! {
         target_os = "macos",
         target_os = "ios",
     ))] {
-        use core::ops::Sub;
+        use core::ops::{Add, Sub};
 
         #[derive(Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Debug)]
         pub struct Instant(Duration);
@@ -140,6 +140,14 @@ cfg_if::cfg_if! {
             }
         }
 
+        impl Add<Duration> for Instant {
+            type Output = Instant;
+
+            fn add(self, rhs: Duration) -> Instant {
+                Self(self.0 + rhs)
+            }
+        }
+
         impl Sub<Duration> for Instant {
             type Output = Instant;
 
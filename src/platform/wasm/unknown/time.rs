@@ -1,4 +1,7 @@
-use core::{mem::MaybeUninit, ops::Sub};
+use core::{
+    mem::MaybeUninit,
+    ops::{Add, Sub},
+};
 
 pub use time::{Duration, OffsetDateTime as DateTime};
 
@@ -38,6 +41,14 @@ impl Instant {
     }
 }
 
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Instant {
+        Self(self.0 + rhs)
+    }
+}
+
 impl Sub<Duration> for Instant {
     type Output = Instant;
 
@@ -1,5 +1,5 @@
 use js_sys::{Date, Reflect};
-use std::ops::Sub;
+use std::ops::{Add, Sub};
 use time::UtcOffset;
 use wasm_bindgen::{prelude::*, JsCast};
 use web_sys::Performance;
@@ -25,6 +25,14 @@ impl Instant {
     }
 }
 
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Instant {
+        Self(self.0 + rhs)
+    }
+}
+
 impl Sub<Duration> for Instant {
     type Output = Instant;
 
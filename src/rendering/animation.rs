@@ -0,0 +1,129 @@
+use crate::{settings::Color, TimeStamp};
+
+/// The duration in seconds it takes the splits to scroll to their new position.
+pub const SCROLL_DURATION: f32 = 0.25;
+/// The duration in seconds it takes a color to fade to a new color.
+pub const COLOR_FADE_DURATION: f32 = 0.3;
+/// The duration in seconds a gold split flashes for.
+pub const FLASH_DURATION: f32 = 1.0;
+
+/// Drives all the animations of a single update of the scene. It keeps track of
+/// whether any of them is still running, so that another frame is needed.
+#[derive(Copy, Clone)]
+pub struct Animator {
+    now: TimeStamp,
+    enabled: bool,
+    is_animating: bool,
+}
+
+impl Animator {
+    pub const fn new(now: TimeStamp, enabled: bool) -> Self {
+        Self {
+            now,
+            enabled,
+            is_animating: false,
+        }
+    }
+
+    pub const fn now(&self) -> TimeStamp {
+        self.now
+    }
+
+    pub const fn is_animating(&self) -> bool {
+        self.is_animating
+    }
+
+    /// Calculates how far along an animation that started at the time provided
+    /// is. The progress is eased such that the animation slows down towards
+    /// its end. It is always `1.0` when animations are disabled.
+    pub fn progress(&mut self, start: TimeStamp, duration: f32) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        let elapsed = (self.now - start).total_seconds() as f32;
+        if elapsed >= duration {
+            return 1.0;
+        }
+        self.is_animating = true;
+        let remaining = 1.0 - elapsed.max(0.0) / duration;
+        1.0 - remaining * remaining * remaining
+    }
+}
+
+pub trait Lerp: Copy + PartialEq {
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Color {
+            red: self.red.lerp(other.red, t),
+            green: self.green.lerp(other.green, t),
+            blue: self.blue.lerp(other.blue, t),
+            alpha: self.alpha.lerp(other.alpha, t),
+        }
+    }
+}
+
+/// A value that smoothly transitions to a new target whenever it changes.
+pub struct Transition<T> {
+    from: T,
+    to: T,
+    start: Option<TimeStamp>,
+}
+
+impl<T: Lerp> Transition<T> {
+    /// Creates a transition that rests at the value provided.
+    pub const fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            start: None,
+        }
+    }
+
+    /// Creates a transition that starts animating from one value to another.
+    pub const fn between(from: T, to: T, animator: &Animator) -> Self {
+        Self {
+            from,
+            to,
+            start: Some(animator.now()),
+        }
+    }
+
+    /// Returns the value the transition ends at.
+    pub const fn target(&self) -> T {
+        self.to
+    }
+
+    /// Starts transitioning from the current value to the target provided, if
+    /// it isn't the target already.
+    pub fn transition_to(&mut self, target: T, animator: &mut Animator, duration: f32) {
+        if target != self.to {
+            *self = Self::between(self.value(animator, duration), target, animator);
+        }
+    }
+
+    /// Immediately jumps to the value provided without animating.
+    pub fn jump_to(&mut self, value: T) {
+        *self = Self::new(value);
+    }
+
+    /// Calculates the current value of the transition.
+    pub fn value(&mut self, animator: &mut Animator, duration: f32) -> T {
+        if let Some(start) = self.start {
+            let progress = animator.progress(start, duration);
+            if progress < 1.0 {
+                return self.from.lerp(self.to, progress);
+            }
+            self.start = None;
+        }
+        self.to
+    }
+}
@@ -0,0 +1,71 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::clock::State,
+    layout::{LayoutDirection, LayoutState},
+    platform::prelude::*,
+    rendering::{
+        font::{AbbreviatedLabel, CachedLabel},
+        resource::ResourceAllocator,
+        RenderContext,
+    },
+};
+
+pub struct Cache<I, L> {
+    rows: Vec<(AbbreviatedLabel<L>, CachedLabel<L>)>,
+    _image: PhantomData<I>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            _image: PhantomData,
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    let row_count = component.rows.len().max(1) as f32;
+    let is_horizontal = context.direction == LayoutDirection::Horizontal;
+    let (row_dim, (delta_x, delta_y)) = if is_horizontal {
+        let row_width = width / row_count;
+        ([row_width, height], (row_width, 0.0))
+    } else {
+        let row_height = height / row_count;
+        ([width, row_height], (0.0, row_height))
+    };
+
+    let key_color = component.label_color.unwrap_or(layout_state.text_color);
+    let value_color = component.value_color.unwrap_or(layout_state.text_color);
+
+    cache.rows.resize_with(component.rows.len(), || {
+        (AbbreviatedLabel::new(), CachedLabel::new())
+    });
+
+    let transform = context.transform;
+    for (row, (key_label, value_label)) in component.rows.iter().zip(&mut cache.rows) {
+        context.render_key_value_component(
+            &row.key,
+            &[],
+            key_label,
+            &row.value,
+            value_label,
+            true,
+            row_dim,
+            key_color,
+            value_color,
+            component.display_two_rows || is_horizontal,
+        );
+        context.translate(delta_x, delta_y);
+    }
+    context.transform = transform;
+}
@@ -0,0 +1,120 @@
+use crate::{
+    component::counters::State,
+    layout::LayoutState,
+    platform::prelude::*,
+    rendering::{
+        consts::{
+            vertical_padding, BOTH_PADDINGS, DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING,
+            TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP,
+        },
+        font::CachedLabel,
+        icon::Icon,
+        resource::ResourceAllocator,
+        solid, Layer, RenderContext,
+    },
+};
+
+pub struct Cache<I, L> {
+    counters: Vec<CounterCache<I, L>>,
+}
+
+struct CounterCache<I, L> {
+    icon: Option<Icon<I>>,
+    label: CachedLabel<L>,
+    value: CachedLabel<L>,
+    delta: CachedLabel<L>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            counters: Vec::new(),
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    let columns = component.columns.max(1) as usize;
+    let rows = ((component.counters.len() + columns - 1) / columns).max(1);
+    let cell_width = width / columns as f32;
+    let cell_height = height / rows as f32;
+
+    let label_color = solid(&component.label_color.unwrap_or(layout_state.text_color));
+    let value_color = solid(&component.value_color.unwrap_or(layout_state.text_color));
+
+    let vertical_padding = vertical_padding(DEFAULT_COMPONENT_HEIGHT);
+    let icon_size = DEFAULT_COMPONENT_HEIGHT - 2.0 * vertical_padding;
+    let text_y = 0.5 * (cell_height - DEFAULT_COMPONENT_HEIGHT);
+
+    cache
+        .counters
+        .resize_with(component.counters.len(), || CounterCache {
+            icon: None,
+            label: CachedLabel::new(),
+            value: CachedLabel::new(),
+            delta: CachedLabel::new(),
+        });
+
+    let transform = context.transform;
+    for (i, (counter, counter_cache)) in component
+        .counters
+        .iter()
+        .zip(&mut cache.counters)
+        .enumerate()
+    {
+        context.transform = transform.pre_translate(
+            (i % columns) as f32 * cell_width,
+            (i / columns) as f32 * cell_height + text_y,
+        );
+
+        if let Some(icon) = &counter.icon_change {
+            counter_cache.icon = context.create_icon(icon);
+        }
+
+        let label_x = if let Some(icon) = &counter_cache.icon {
+            context.render_icon([PADDING, vertical_padding], [icon_size, icon_size], icon);
+            BOTH_PADDINGS + icon_size
+        } else {
+            PADDING
+        };
+
+        let mut value_end_x = cell_width - PADDING;
+        if !counter.delta.is_empty() {
+            value_end_x = context.render_numbers(
+                &counter.delta,
+                &mut counter_cache.delta,
+                Layer::Bottom,
+                [value_end_x, DEFAULT_COMPONENT_HEIGHT + TEXT_ALIGN_BOTTOM],
+                DEFAULT_TEXT_SIZE,
+                solid(&counter.delta_color),
+            ) - PADDING;
+        }
+
+        let value_x = context.render_numbers(
+            &counter.value,
+            &mut counter_cache.value,
+            Layer::Bottom,
+            [value_end_x, DEFAULT_COMPONENT_HEIGHT + TEXT_ALIGN_BOTTOM],
+            DEFAULT_TEXT_SIZE,
+            value_color,
+        );
+
+        context.render_text_ellipsis(
+            &counter.label,
+            &mut counter_cache.label,
+            [label_x, TEXT_ALIGN_TOP],
+            DEFAULT_TEXT_SIZE,
+            label_color,
+            value_x - PADDING,
+        );
+    }
+    context.transform = transform;
+}
@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::custom_image::{ImageScaling, State},
+    layout::LayoutState,
+    rendering::{
+        consts::{vertical_padding, BOTH_PADDINGS, PADDING},
+        icon::Icon,
+        resource::ResourceAllocator,
+        RenderContext,
+    },
+};
+
+pub struct Cache<I, L> {
+    image: Option<Icon<I>>,
+    _label: PhantomData<L>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            image: None,
+            _label: PhantomData,
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    _layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    if let Some(image) = &component.image_change {
+        cache.image = context.create_icon(image);
+    }
+
+    if let Some(image) = &cache.image {
+        let vertical_padding = vertical_padding(height);
+        let pos = [PADDING, vertical_padding];
+        let size = [
+            (width - BOTH_PADDINGS).max(0.0),
+            (height - 2.0 * vertical_padding).max(0.0),
+        ];
+        match component.scaling {
+            ImageScaling::Fit => context.render_icon(pos, size, image),
+            ImageScaling::Stretch => context.render_stretched_icon(pos, size, image),
+        }
+    }
+}
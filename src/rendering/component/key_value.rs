@@ -44,6 +44,6 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         dim,
         component.key_color.unwrap_or(layout_state.text_color),
         component.value_color.unwrap_or(layout_state.text_color),
-        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
+        component.display_two_rows || context.direction == LayoutDirection::Horizontal,
     );
 }
@@ -7,17 +7,27 @@ use super::{
 };
 
 pub mod blank_space;
+pub mod clock;
+pub mod counters;
+pub mod custom_image;
 pub mod detailed_timer;
 pub mod graph;
 pub mod key_value;
+pub mod pb_history;
+pub mod progress_bar;
+pub mod segment_history;
 pub mod separator;
 pub mod splits;
 pub mod text;
+pub mod timeline;
 pub mod timer;
 pub mod title;
 
 pub enum Cache<I, L> {
     Empty,
+    Clock(clock::Cache<I, L>),
+    Counters(counters::Cache<I, L>),
+    CustomImage(custom_image::Cache<I, L>),
     DetailedTimer(detailed_timer::Cache<I, L>),
     KeyValue(key_value::Cache<I, L>),
     Splits(splits::Cache<I, L>),
@@ -45,6 +55,9 @@ macro_rules! accessors {
 impl<I, L> Cache<I, L> {
     pub const fn new(component: &ComponentState) -> Self {
         match component {
+            ComponentState::Clock(_) => Self::Clock(clock::Cache::new()),
+            ComponentState::Counters(_) => Self::Counters(counters::Cache::new()),
+            ComponentState::CustomImage(_) => Self::CustomImage(custom_image::Cache::new()),
             ComponentState::DetailedTimer(_) => Self::DetailedTimer(detailed_timer::Cache::new()),
             ComponentState::KeyValue(_) => Self::KeyValue(key_value::Cache::new()),
             ComponentState::Splits(_) => Self::Splits(splits::Cache::new()),
@@ -60,6 +73,9 @@ impl<I, L> Cache<I, L> {
     }
 
     accessors! {
+        Clock clock,
+        Counters counters,
+        CustomImage custom_image,
         DetailedTimer detailed_timer,
         KeyValue key_value,
         Splits splits,
@@ -69,20 +85,33 @@ impl<I, L> Cache<I, L> {
     }
 }
 
-pub fn layout_width(layout: &LayoutState) -> f32 {
-    layout.components.iter().map(width).sum()
+pub fn layout_width(components: &[ComponentState]) -> f32 {
+    components.iter().map(width).sum()
 }
 
-pub fn layout_height(layout: &LayoutState) -> f32 {
-    layout.components.iter().map(height).sum()
+pub fn layout_height(components: &[ComponentState]) -> f32 {
+    components.iter().map(height).sum()
 }
 
 pub fn width(component: &ComponentState) -> f32 {
     match component {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Clock(state) => {
+            let rows = state.rows.len() as f32;
+            if rows > 1.0 {
+                6.0 * rows
+            } else {
+                6.0
+            }
+        }
+        ComponentState::Counters(state) => 6.0 * state.columns.max(1) as f32,
+        ComponentState::CustomImage(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::DetailedTimer(_) => 7.0,
         ComponentState::Graph(_) => 7.0,
         ComponentState::KeyValue(_) => 6.0,
+        ComponentState::PbHistory(_) => 7.0,
+        ComponentState::ProgressBar(_) => 7.0,
+        ComponentState::SegmentHistory(_) => 7.0,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             let column_count = 2.0; // FIXME: Not always 2.
@@ -91,6 +120,7 @@ pub fn width(component: &ComponentState) -> f32 {
             state.splits.len() as f32 * split_width
         }
         ComponentState::Text(_) => 6.0,
+        ComponentState::Timeline(_) => 7.0,
         ComponentState::Timer(_) => 8.25,
         ComponentState::Title(_) => 8.0,
     }
@@ -99,6 +129,20 @@ pub fn width(component: &ComponentState) -> f32 {
 pub fn height(component: &ComponentState) -> f32 {
     match component {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Clock(state) => {
+            state.rows.len().max(1) as f32
+                * if state.display_two_rows {
+                    TWO_ROW_HEIGHT
+                } else {
+                    DEFAULT_COMPONENT_HEIGHT
+                }
+        }
+        ComponentState::Counters(state) => {
+            let columns = state.columns.max(1) as usize;
+            let rows = (state.counters.len() + columns - 1) / columns;
+            rows.max(1) as f32 * DEFAULT_COMPONENT_HEIGHT
+        }
+        ComponentState::CustomImage(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::DetailedTimer(_) => 2.5,
         ComponentState::Graph(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::KeyValue(state) => {
@@ -108,6 +152,9 @@ pub fn height(component: &ComponentState) -> f32 {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
+        ComponentState::PbHistory(state) => state.height as f32 * PSEUDO_PIXELS,
+        ComponentState::ProgressBar(state) => state.height as f32 * PSEUDO_PIXELS,
+        ComponentState::SegmentHistory(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             state.splits.len() as f32
@@ -129,6 +176,7 @@ pub fn height(component: &ComponentState) -> f32 {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
+        ComponentState::Timeline(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::Timer(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::Title(_) => TWO_ROW_HEIGHT,
     }
@@ -146,6 +194,15 @@ pub(super) fn render<A: ResourceAllocator>(
             cache.make_empty();
             blank_space::render(context, dim, state)
         }
+        ComponentState::Clock(component) => {
+            clock::render(cache.clock(), context, dim, component, state)
+        }
+        ComponentState::Counters(component) => {
+            counters::render(cache.counters(), context, dim, component, state)
+        }
+        ComponentState::CustomImage(component) => {
+            custom_image::render(cache.custom_image(), context, dim, component, state)
+        }
         ComponentState::DetailedTimer(component) => {
             detailed_timer::render(cache.detailed_timer(), context, dim, component, state)
         }
@@ -156,6 +213,18 @@ pub(super) fn render<A: ResourceAllocator>(
         ComponentState::KeyValue(component) => {
             key_value::render(cache.key_value(), context, dim, component, state)
         }
+        ComponentState::PbHistory(component) => {
+            cache.make_empty();
+            pb_history::render(context, dim, component, state)
+        }
+        ComponentState::ProgressBar(component) => {
+            cache.make_empty();
+            progress_bar::render(context, dim, component, state)
+        }
+        ComponentState::SegmentHistory(component) => {
+            cache.make_empty();
+            segment_history::render(context, dim, component, state)
+        }
         ComponentState::Separator(component) => {
             cache.make_empty();
             separator::render(context, dim, component, state)
@@ -166,6 +235,10 @@ pub(super) fn render<A: ResourceAllocator>(
         ComponentState::Text(component) => {
             text::render(cache.text(), context, dim, component, state)
         }
+        ComponentState::Timeline(component) => {
+            cache.make_empty();
+            timeline::render(context, dim, component)
+        }
         ComponentState::Timer(component) => {
             timer::render(cache.timer(), context, dim, component);
         }
@@ -0,0 +1,52 @@
+use crate::{
+    component::pb_history::{Point, State},
+    layout::LayoutState,
+    rendering::{PathBuilder, RenderContext, ResourceAllocator},
+    settings::{Color, Gradient},
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl ResourceAllocator>,
+    [width, height]: [f32; 2],
+    component: &State,
+    _layout_state: &LayoutState,
+) {
+    let old_transform = context.transform;
+    context.scale(height);
+    let width = width / height;
+
+    const PADDING: f32 = 0.1;
+    const LINE_WIDTH: f32 = 0.025;
+
+    context.render_rectangle(
+        [0.0, 0.0],
+        [width, 1.0],
+        &Gradient::Plain(component.background_color),
+    );
+
+    let inner_width = width - 2.0 * PADDING;
+    let inner_height = 1.0 - 2.0 * PADDING;
+
+    let mut render_line = |points: &[Point], color: Color| {
+        let mut points = points
+            .iter()
+            .map(|p| (PADDING + inner_width * p.x, PADDING + inner_height * p.y));
+        if let Some((x, y)) = points.next() {
+            let mut builder = context.handles.path_builder();
+            builder.move_to(x, y);
+            for (x, y) in points {
+                builder.line_to(x, y);
+            }
+            let path = builder.finish();
+            context.top_layer_stroke_path(path, color, LINE_WIDTH);
+        }
+    };
+
+    render_line(&component.sum_of_best_points, component.sum_of_best_color);
+    render_line(
+        &component.personal_best_points,
+        component.personal_best_color,
+    );
+
+    context.transform = old_transform;
+}
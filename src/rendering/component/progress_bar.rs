@@ -0,0 +1,73 @@
+use crate::{
+    component::progress_bar::State,
+    layout::LayoutState,
+    rendering::{RenderContext, ResourceAllocator},
+    settings::Gradient,
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl ResourceAllocator>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    let old_transform = context.transform;
+    context.scale(height);
+    let width = width / height;
+
+    const TICK_WIDTH: f32 = 0.03;
+    const MARKER_WIDTH: f32 = 0.05;
+    const SEGMENT_BAR_TOP: f32 = 0.8;
+    const SEGMENT_BAR_GAP: f32 = 0.1;
+
+    let bar_bottom = if component.segment_progress_color.is_some() {
+        SEGMENT_BAR_TOP - SEGMENT_BAR_GAP
+    } else {
+        1.0
+    };
+
+    context.render_rectangle(
+        [0.0, 0.0],
+        [width, bar_bottom],
+        &Gradient::Plain(component.background_color),
+    );
+
+    let position = width * component.time_progress;
+    context.render_top_rectangle(
+        [0.0, 0.0],
+        [position, bar_bottom],
+        &Gradient::Plain(component.fill_color),
+    );
+
+    for &x in &component.ticks {
+        context.render_top_rectangle(
+            [width * x - TICK_WIDTH, 0.0],
+            [width * x + TICK_WIDTH, bar_bottom],
+            &Gradient::Plain(component.tick_color),
+        );
+    }
+
+    if component.show_current_position {
+        let position = position.clamp(MARKER_WIDTH, width - MARKER_WIDTH);
+        context.render_top_rectangle(
+            [position - MARKER_WIDTH, 0.0],
+            [position + MARKER_WIDTH, bar_bottom],
+            &Gradient::Plain(layout_state.text_color),
+        );
+    }
+
+    if let Some(color) = component.segment_progress_color {
+        context.render_rectangle(
+            [0.0, SEGMENT_BAR_TOP],
+            [width, 1.0],
+            &Gradient::Plain(component.background_color),
+        );
+        context.render_top_rectangle(
+            [0.0, SEGMENT_BAR_TOP],
+            [width * component.segment_progress, 1.0],
+            &Gradient::Plain(color),
+        );
+    }
+
+    context.transform = old_transform;
+}
@@ -0,0 +1,111 @@
+use crate::{
+    component::segment_history::{HistoryStyle, State},
+    layout::LayoutState,
+    rendering::{PathBuilder, RenderContext, ResourceAllocator},
+    settings::Gradient,
+};
+
+const PADDING: f32 = 0.1;
+const BAR_GAP: f32 = 0.02;
+const LINE_WIDTH: f32 = 0.025;
+const MARKER_WIDTH: f32 = 0.02;
+const LIVE_MARKER_WIDTH: f32 = 0.035;
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl ResourceAllocator>,
+    [width, height]: [f32; 2],
+    component: &State,
+    _layout_state: &LayoutState,
+) {
+    let old_transform = context.transform;
+    context.scale(height);
+    let width = width / height;
+
+    context.render_rectangle(
+        [0.0, 0.0],
+        [width, 1.0],
+        &Gradient::Plain(component.background_color),
+    );
+
+    let markers = [
+        (
+            component.personal_best,
+            component.personal_best_color,
+            MARKER_WIDTH,
+        ),
+        (
+            component.best_segment,
+            component.best_segment_color,
+            MARKER_WIDTH,
+        ),
+        (
+            component.live_segment_time,
+            component.live_segment_color,
+            LIVE_MARKER_WIDTH,
+        ),
+    ];
+
+    match component.style {
+        HistoryStyle::Histogram => {
+            let bar_width = width / component.values.len().max(1) as f32;
+            for (i, &value) in component.values.iter().enumerate() {
+                if value > 0.0 {
+                    let x = i as f32 * bar_width;
+                    context.render_top_rectangle(
+                        [x + BAR_GAP, 1.0 - value * (1.0 - PADDING)],
+                        [x + bar_width - BAR_GAP, 1.0],
+                        &Gradient::Plain(component.history_color),
+                    );
+                }
+            }
+
+            for (position, color, marker_width) in markers {
+                if let Some(position) = position {
+                    let x = (width * position).clamp(marker_width, width - marker_width);
+                    context.render_top_rectangle(
+                        [x - marker_width, 0.0],
+                        [x + marker_width, 1.0],
+                        &Gradient::Plain(color),
+                    );
+                }
+            }
+        }
+        HistoryStyle::Sparkline => {
+            let to_y = |value: f32| PADDING + (1.0 - value) * (1.0 - 2.0 * PADDING);
+
+            for (position, color, marker_width) in markers {
+                if let Some(position) = position {
+                    let y = to_y(position);
+                    context.render_top_rectangle(
+                        [0.0, y - marker_width],
+                        [width, y + marker_width],
+                        &Gradient::Plain(color),
+                    );
+                }
+            }
+
+            if let [value] = component.values[..] {
+                let circle =
+                    context
+                        .handles
+                        .build_circle(width / 2.0, to_y(value), 2.0 * LINE_WIDTH);
+                context.top_layer_path(circle, component.history_color);
+            } else if !component.values.is_empty() {
+                let step = (width - 2.0 * PADDING) / (component.values.len() - 1) as f32;
+                let mut builder = context.handles.path_builder();
+                for (i, &value) in component.values.iter().enumerate() {
+                    let (x, y) = (PADDING + i as f32 * step, to_y(value));
+                    if i == 0 {
+                        builder.move_to(x, y);
+                    } else {
+                        builder.line_to(x, y);
+                    }
+                }
+                let path = builder.finish();
+                context.top_layer_stroke_path(path, component.history_color, LINE_WIDTH);
+            }
+        }
+    }
+
+    context.transform = old_transform;
+}
@@ -1,10 +1,11 @@
 use core::iter;
 
 use crate::{
-    component::splits::State,
+    component::splits::{ColumnState, State},
     layout::{LayoutDirection, LayoutState},
     platform::prelude::*,
     rendering::{
+        animation::{Animator, Transition, COLOR_FADE_DURATION, FLASH_DURATION, SCROLL_DURATION},
         consts::{
             vertical_padding, BOTH_PADDINGS, DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING,
             TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP, THIN_SEPARATOR_THICKNESS, TWO_ROW_HEIGHT,
@@ -15,7 +16,8 @@ use crate::{
         scene::Layer,
         solid, RenderContext,
     },
-    settings::{Gradient, ListGradient},
+    settings::{Color, Gradient, ListGradient, SemanticColor},
+    TimeStamp,
 };
 
 pub struct Cache<I, L> {
@@ -27,17 +29,68 @@ pub struct Cache<I, L> {
 }
 
 struct SplitCache<L> {
+    index: Option<usize>,
+    position: Transition<f32>,
+    current_position: f32,
+    flash: Option<(TimeStamp, Color)>,
     name: CachedLabel<L>,
-    columns: Vec<CachedLabel<L>>,
+    columns: Vec<ColumnCache<L>>,
 }
 
 impl<L> SplitCache<L> {
     const fn new() -> Self {
         Self {
+            index: None,
+            position: Transition::new(0.0),
+            current_position: 0.0,
+            flash: None,
             name: CachedLabel::new(),
             columns: Vec::new(),
         }
     }
+
+    fn is_resting(&self) -> bool {
+        self.current_position == self.position.target()
+    }
+}
+
+struct ColumnCache<L> {
+    label: CachedLabel<L>,
+    color: Transition<Color>,
+    semantic_color: SemanticColor,
+    has_value: bool,
+}
+
+impl<L> ColumnCache<L> {
+    const fn new() -> Self {
+        Self {
+            label: CachedLabel::new(),
+            color: Transition::new(Color::transparent()),
+            semantic_color: SemanticColor::Default,
+            has_value: false,
+        }
+    }
+
+    /// Updates the color of the column, cross-fading to it if the column
+    /// already showed a value for the same segment before. Returns whether the
+    /// column just turned into a best segment.
+    fn update(&mut self, column: &ColumnState, is_new_row: bool, animator: &mut Animator) -> bool {
+        let has_value = !column.value.is_empty();
+        let is_still_shown = has_value && self.has_value && !is_new_row;
+        if is_still_shown {
+            self.color
+                .transition_to(column.visual_color, animator, COLOR_FADE_DURATION);
+        } else {
+            self.color.jump_to(column.visual_color);
+        }
+        let turned_gold = !is_new_row
+            && has_value
+            && column.semantic_color == SemanticColor::BestSegment
+            && self.semantic_color != SemanticColor::BestSegment;
+        self.semantic_color = column.semantic_color;
+        self.has_value = has_value;
+        turned_gold
+    }
 }
 
 impl<I, L> Cache<I, L> {
@@ -74,7 +127,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     };
 
     let display_two_rows =
-        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal;
+        component.display_two_rows || context.direction == LayoutDirection::Horizontal;
 
     let split_height = if display_two_rows {
         TWO_ROW_HEIGHT
@@ -85,7 +138,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     let vertical_padding = vertical_padding(split_height);
 
     let (split_width, (delta_x, delta_y), separator_pos, split_background_bottom_right, icon_y) =
-        if layout_state.direction == LayoutDirection::Horizontal {
+        if context.direction == LayoutDirection::Horizontal {
             let split_width = width / component.splits.len() as f32;
             (
                 split_width,
@@ -118,7 +171,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     cache.column_label_widths.clear();
 
     if let Some(column_labels) = &component.column_labels {
-        if layout_state.direction == LayoutDirection::Vertical {
+        if context.direction == LayoutDirection::Vertical {
             cache
                 .column_labels
                 .resize_with(column_labels.len(), CachedLabel::new);
@@ -148,6 +201,8 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         }
     }
 
+    let row_transform = context.transform;
+
     let icon_size = split_height - 2.0 * vertical_padding;
     let icon_right = if component.has_icons {
         BOTH_PADDINGS + icon_size
@@ -155,11 +210,89 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         PADDING
     };
 
-    cache
+    // The caches follow the segments they are showing, so the labels and the
+    // animations carry over when the splits scroll.
+    if cache.splits.len() < component.splits.len() {
+        cache
+            .splits
+            .resize_with(component.splits.len(), SplitCache::new);
+    }
+    for (i, split) in component.splits.iter().enumerate() {
+        if let Some(offset) = cache.splits[i..]
+            .iter()
+            .position(|c| c.index == Some(split.index))
+        {
+            cache.splits.swap(i, i + offset);
+        }
+    }
+    cache.splits.truncate(component.splits.len());
+
+    // Segments that just scrolled into view slide in together with the ones
+    // that were already visible.
+    let scroll_offset = component
         .splits
-        .resize_with(component.splits.len(), SplitCache::new);
+        .iter()
+        .zip(&mut cache.splits)
+        .enumerate()
+        .find(|(i, (split, c))| c.index == Some(split.index) && c.position.target() != *i as f32)
+        .map(|(i, (_, c))| c.position.value(context.animator, SCROLL_DURATION) - i as f32)
+        .unwrap_or_default();
 
     for (i, (split, split_cache)) in component.splits.iter().zip(&mut cache.splits).enumerate() {
+        let position = i as f32;
+        if split_cache.index == Some(split.index) {
+            split_cache
+                .position
+                .transition_to(position, context.animator, SCROLL_DURATION);
+        } else if scroll_offset != 0.0 {
+            split_cache.position =
+                Transition::between(position + scroll_offset, position, context.animator);
+        } else {
+            split_cache.position.jump_to(position);
+        }
+        split_cache.current_position = split_cache
+            .position
+            .value(context.animator, SCROLL_DURATION);
+    }
+
+    let last_position = component.splits.len().saturating_sub(1) as f32;
+
+    for (i, split) in component.splits.iter().enumerate() {
+        let split_cache = &cache.splits[i];
+        let position = split_cache.current_position;
+
+        // While scrolling, there's no way to clip the segments to the
+        // component, so the segments that would only partially be visible or
+        // would overlap a segment that stays in place are hidden instead.
+        let is_visible = split_cache.is_resting()
+            || ((0.0..=last_position).contains(&position)
+                && cache.splits.iter().all(|other| {
+                    !other.is_resting() || (other.current_position - position).abs() >= 1.0
+                }));
+
+        let split_cache = &mut cache.splits[i];
+        let is_new_row = split_cache.index != Some(split.index);
+        if is_new_row {
+            split_cache.index = Some(split.index);
+            split_cache.flash = None;
+        }
+
+        split_cache
+            .columns
+            .resize_with(split.columns.len(), ColumnCache::new);
+        for (column, column_cache) in split.columns.iter().zip(&mut split_cache.columns) {
+            if column_cache.update(column, is_new_row, context.animator) {
+                split_cache.flash = Some((context.animator.now(), column.visual_color));
+            }
+        }
+
+        if !is_visible {
+            continue;
+        }
+
+        context.transform = row_transform.pre_translate(delta_x * position, delta_y * position);
+        context.record_split_bounds(i, split.index, [split_width, split_height]);
+
         if component.show_thin_separators && i + 1 != component.splits.len() {
             context.render_rectangle(
                 separator_pos,
@@ -178,6 +311,16 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             context.render_background(split_background_bottom_right, color);
         }
 
+        if let Some((start, mut color)) = split_cache.flash {
+            let progress = context.animator.progress(start, FLASH_DURATION);
+            if progress < 1.0 {
+                color.alpha *= 0.4 * (1.0 - progress);
+                context.render_background(split_background_bottom_right, &Gradient::Plain(color));
+            } else {
+                split_cache.flash = None;
+            }
+        }
+
         {
             if let Some(Some(icon)) = cache.icons.get(split.index) {
                 context.render_icon([PADDING, icon_y], [icon_size, icon_size], icon);
@@ -186,10 +329,6 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             let mut left_x = split_width - PADDING;
             let mut right_x = left_x;
 
-            split_cache
-                .columns
-                .resize_with(split.columns.len(), CachedLabel::new);
-
             for ((column, column_cache), column_label_width) in
                 split.columns.iter().zip(&mut split_cache.columns).zip(
                     cache
@@ -200,13 +339,16 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 )
             {
                 if !column.value.is_empty() {
+                    let color = column_cache
+                        .color
+                        .value(context.animator, COLOR_FADE_DURATION);
                     left_x = context.render_numbers(
                         &column.value,
-                        column_cache,
+                        &mut column_cache.label,
                         Layer::from_updates_frequently(column.updates_frequently),
                         [right_x, split_height + TEXT_ALIGN_BOTTOM],
                         DEFAULT_TEXT_SIZE,
-                        solid(&column.visual_color),
+                        solid(&color),
                     );
                 }
                 right_x -= max_column_width.max(column_label_width) + COLUMN_PADDING;
@@ -225,10 +367,11 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 left_x - PADDING,
             );
         }
-        context.translate(delta_x, delta_y);
     }
+    let split_count = component.splits.len() as f32;
+    context.transform = row_transform.pre_translate(delta_x * split_count, delta_y * split_count);
     if component.show_final_separator {
-        let (pos, end) = if layout_state.direction == LayoutDirection::Horizontal {
+        let (pos, end) = if context.direction == LayoutDirection::Horizontal {
             (
                 [-split_width - THIN_SEPARATOR_THICKNESS, 0.0],
                 [-split_width + THIN_SEPARATOR_THICKNESS, split_height],
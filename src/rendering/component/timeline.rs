@@ -0,0 +1,63 @@
+use crate::{
+    component::timeline::State,
+    rendering::{RenderContext, ResourceAllocator},
+    settings::{Color, Gradient},
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl ResourceAllocator>,
+    [width, height]: [f32; 2],
+    component: &State,
+) {
+    let old_transform = context.transform;
+    context.scale(height);
+    let width = width / height;
+
+    const BAR_GAP: f32 = 0.1;
+    const SEGMENT_GAP: f32 = 0.02;
+
+    let comparison_bar = [0.0, 0.5 - BAR_GAP / 2.0];
+    let attempt_bar = [0.5 + BAR_GAP / 2.0, 1.0];
+
+    for [top, bottom] in [comparison_bar, attempt_bar] {
+        context.render_rectangle(
+            [0.0, top],
+            [width, bottom],
+            &Gradient::Plain(component.background_color),
+        );
+    }
+
+    let mut render_segment = |x: &mut f32, segment_width: f32, [top, bottom]: [f32; 2], color| {
+        let start = *x;
+        *x += width * segment_width;
+        let end = (*x - SEGMENT_GAP).min(width);
+        if end > start {
+            context.render_top_rectangle([start, top], [end, bottom], &Gradient::Plain(color));
+        }
+    };
+
+    let mut x = 0.0;
+    for &segment_width in &component.comparison_segments {
+        render_segment(
+            &mut x,
+            segment_width,
+            comparison_bar,
+            component.comparison_color,
+        );
+    }
+
+    let mut x = 0.0;
+    for segment in &component.attempt_segments {
+        render_segment(&mut x, segment.width, attempt_bar, segment.color);
+    }
+
+    if let Some(segment) = &component.live_segment {
+        let color = Color {
+            alpha: segment.color.alpha * 0.5,
+            ..segment.color
+        };
+        render_segment(&mut x, segment.width, attempt_bar, color);
+    }
+
+    context.transform = old_transform;
+}
@@ -95,6 +95,13 @@ pub fn calculate_hash<P, I, L>(
     hasher.finish()
 }
 
+#[cfg(feature = "software-rendering")]
+pub fn calculate_entity_hash<P, I, L>(entity: &Entity<P, I, L>) -> u64 {
+    let mut hasher = FxHasher(0x517cc1b727220a95);
+    entity.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[inline]
 fn hash_float(f: f32, state: &mut impl Hasher) {
     u32::hash(&bytemuck::cast(f), state);
@@ -81,6 +81,9 @@ impl<L> AbbreviatedLabel<L> {
                 }
             }
 
+            // If none of the abbreviations fit, the longest one gets cut off by
+            // the text engine. It does so at the logical end of the text, so
+            // for right-to-left text the ellipsis ends up on the left.
             let chosen = if within_longest.is_empty() {
                 total_longest
             } else {
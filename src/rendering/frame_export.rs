@@ -0,0 +1,337 @@
+//! Provides a way to export an attempt as a sequence of frames without any
+//! window or GPU being involved. The attempt is either taken from the attempt
+//! history of a [`Run`] or described by a list of
+//! [`RecordedActions`](RecordedAction). The timer is driven by a virtual clock
+//! that gets stepped at a fixed frame rate, while each frame is rendered by the
+//! software renderer. The frames can then be written out as a sequence of PNG
+//! files or as raw RGBA8 encoded frames, which can for example be piped into
+//! ffmpeg to produce a video.
+
+use std::{
+    io::{self, Write},
+    path::Path,
+};
+
+use snafu::ResultExt;
+
+use super::software::Renderer;
+use crate::{
+    layout::{Layout, LayoutState},
+    platform::{prelude::*, DateTime, Duration},
+    AtomicDateTime, Run, TimeSpan, TimeStamp, Timer,
+};
+
+/// An action that gets applied to the timer when replaying an attempt.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Starts the timer.
+    Start,
+    /// Splits the current segment.
+    Split,
+    /// Skips the current segment.
+    SkipSplit,
+    /// Undoes the last split.
+    UndoSplit,
+    /// Pauses the timer.
+    Pause,
+    /// Resumes the timer.
+    Resume,
+    /// Resets the timer without updating the splits.
+    Reset,
+}
+
+/// An action that happened at a specific point in time of a recording.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RecordedAction {
+    /// The point in time the action happened at, relative to the beginning of
+    /// the recording.
+    pub time: TimeSpan,
+    /// The action that happened.
+    pub action: Action,
+}
+
+/// Describes an error that happened when exporting the frames of an attempt.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The run has no segments, so there is no timer to replay the attempt on.
+    EmptyRun,
+    /// There is no attempt with the index provided in the attempt history.
+    UnknownAttempt,
+    /// The frame rate needs to be at least one frame per second.
+    InvalidFrameRate,
+    /// Failed to write out a frame.
+    Io {
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// Failed to encode a frame as a PNG.
+    Png {
+        /// The underlying error.
+        source: image::ImageError,
+    },
+}
+
+/// The Result type for exporting the frames of an attempt.
+pub type Result<T = ()> = core::result::Result<T, Error>;
+
+/// Reconstructs the actions of an attempt stored in the attempt history of the
+/// run. The attempt is identified by its index, as stored in the attempt
+/// history. The splits happen at the real time split times of the attempt.
+/// Skipped segments are skipped right after the previous split. If the attempt
+/// is unfinished, the recording continues until the point in time the attempt
+/// got reset, as far as it is known, without actually resetting the timer, so
+/// the final frames show the times of the attempt as they were when it ended.
+/// This is done by pausing the timer, so the final frames show the timer in the
+/// paused color rather than in the color it had when the attempt ended. Pauses
+/// can't be reconstructed, so the attempt is replayed without any. `None` is
+/// returned if there's no attempt with the index provided.
+pub fn actions_from_attempt(run: &Run, attempt_index: i32) -> Option<Vec<RecordedAction>> {
+    let attempt = run
+        .attempt_history()
+        .iter()
+        .find(|a| a.index() == attempt_index)?;
+
+    // The timer starts at the offset, so the clock is already ahead of the
+    // split times by that much.
+    let offset = run.offset();
+    let mut actions = vec![RecordedAction {
+        time: TimeSpan::zero(),
+        action: Action::Start,
+    }];
+    let mut split_time = TimeSpan::zero();
+    let mut finished = true;
+
+    for segment in run.segments() {
+        let time = match segment.segment_history().get(attempt_index) {
+            Some(time) => time,
+            None => {
+                finished = false;
+                break;
+            }
+        };
+        let action = if let Some(segment_time) = time.real_time {
+            split_time += segment_time;
+            Action::Split
+        } else {
+            Action::SkipSplit
+        };
+        actions.push(RecordedAction {
+            time: split_time - offset,
+            action,
+        });
+    }
+
+    if !finished {
+        // If the attempt's dates are known, its duration already includes the
+        // offset. Otherwise only its final time is known, which needs the same
+        // adjustment as the split times.
+        let duration = match (attempt.started(), attempt.ended()) {
+            (Some(started), Some(ended)) => Some(ended - started),
+            _ => attempt.time().real_time.map(|time| time - offset),
+        };
+        let end = actions.last().unwrap().time;
+        if let Some(duration) = duration.filter(|&d| d > end) {
+            // There is no action for the end of the recording, but pausing the
+            // timer right when it was reset keeps the final time on screen,
+            // albeit in the paused color.
+            actions.push(RecordedAction {
+                time: duration,
+                action: Action::Pause,
+            });
+        }
+    }
+
+    Some(actions)
+}
+
+/// The frame exporter replays an attempt on a timer that is driven by a virtual
+/// clock and renders it frame by frame with the software renderer. The first
+/// frame shows the state at the beginning of the recording, while the last
+/// frame shows the state after the last action. The animations of the layout
+/// and the dates the timer observes, such as the date the attempt started at,
+/// follow the virtual clock as well, so exporting the same recording always
+/// results in the same frames.
+pub struct FrameExporter {
+    timer: Timer,
+    layout: Layout,
+    layout_state: LayoutState,
+    renderer: Renderer,
+    actions: Vec<RecordedAction>,
+    next_action: usize,
+    origin: TimeStamp,
+    origin_date: AtomicDateTime,
+    frame_rate: u32,
+    dimensions: [u32; 2],
+    next_frame: u64,
+    frame_count: u64,
+}
+
+impl FrameExporter {
+    /// Creates a new frame exporter that replays the recorded actions on a
+    /// timer for the run provided. The actions don't need to be sorted. The
+    /// frames are rendered with the dimensions provided in pixels at the frame
+    /// rate provided in frames per second. The recording is considered to start
+    /// at the Unix epoch, as there is no date associated with the actions.
+    pub fn new(
+        run: Run,
+        layout: Layout,
+        actions: Vec<RecordedAction>,
+        frame_rate: u32,
+        dimensions: [u32; 2],
+    ) -> Result<Self> {
+        let origin_date = AtomicDateTime::new(DateTime::UNIX_EPOCH, false);
+        Self::with_origin_date(run, layout, actions, frame_rate, dimensions, origin_date)
+    }
+
+    fn with_origin_date(
+        run: Run,
+        layout: Layout,
+        mut actions: Vec<RecordedAction>,
+        frame_rate: u32,
+        dimensions: [u32; 2],
+        origin_date: AtomicDateTime,
+    ) -> Result<Self> {
+        if frame_rate == 0 {
+            return Err(Error::InvalidFrameRate);
+        }
+
+        let mut timer = Timer::new(run).map_err(|_| Error::EmptyRun)?;
+
+        actions.sort_by_key(|a| a.time);
+        let end = actions
+            .last()
+            .map_or(TimeSpan::zero(), |a| a.time)
+            .max(TimeSpan::zero());
+        let frame_count = nanoseconds(end) * frame_rate as u64 / 1_000_000_000 + 1;
+
+        let origin = TimeStamp::now();
+        timer.set_virtual_now(Some((origin, origin_date)));
+
+        Ok(Self {
+            timer,
+            layout,
+            layout_state: LayoutState::default(),
+            renderer: Renderer::new(),
+            actions,
+            next_action: 0,
+            origin,
+            origin_date,
+            frame_rate,
+            dimensions,
+            next_frame: 0,
+            frame_count,
+        })
+    }
+
+    /// Creates a new frame exporter that replays an attempt stored in the
+    /// attempt history of the run. The attempt is identified by its index, as
+    /// stored in the attempt history. Check [`actions_from_attempt`] for how
+    /// the attempt gets reconstructed. The recording is considered to start at
+    /// the date the attempt started at. If that is unknown, it starts at the
+    /// Unix epoch.
+    pub fn from_attempt(
+        run: Run,
+        layout: Layout,
+        attempt_index: i32,
+        frame_rate: u32,
+        dimensions: [u32; 2],
+    ) -> Result<Self> {
+        let actions = actions_from_attempt(&run, attempt_index).ok_or(Error::UnknownAttempt)?;
+        let origin_date = run
+            .attempt_history()
+            .iter()
+            .find(|a| a.index() == attempt_index)
+            .and_then(|a| a.started())
+            .unwrap_or(AtomicDateTime::new(DateTime::UNIX_EPOCH, false));
+        Self::with_origin_date(run, layout, actions, frame_rate, dimensions, origin_date)
+    }
+
+    /// Returns the total amount of frames the recording consists of.
+    pub const fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Accesses the timer the attempt is replayed on.
+    pub const fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    /// Renders the next frame and returns it as a byte slice of RGBA8 encoded
+    /// pixels (red, green, blue, alpha with each channel being an u8). `None`
+    /// is returned once all the frames are rendered.
+    pub fn render_next_frame(&mut self) -> Option<&[u8]> {
+        if self.next_frame >= self.frame_count {
+            return None;
+        }
+
+        let time = TimeSpan::from(Duration::nanoseconds(
+            (self.next_frame * 1_000_000_000 / self.frame_rate as u64) as i64,
+        ));
+        self.next_frame += 1;
+
+        while let Some(action) = self
+            .actions
+            .get(self.next_action)
+            .filter(|a| a.time <= time)
+        {
+            let now = self.virtual_now(action.time.max(TimeSpan::zero()));
+            self.timer.set_virtual_now(Some(now));
+            match action.action {
+                Action::Start => self.timer.start(),
+                Action::Split => self.timer.split(),
+                Action::SkipSplit => self.timer.skip_split(),
+                Action::UndoSplit => self.timer.undo_split(),
+                Action::Pause => self.timer.pause(),
+                Action::Resume => self.timer.resume(),
+                Action::Reset => self.timer.reset(false),
+            }
+            self.next_action += 1;
+        }
+
+        let now = self.virtual_now(time);
+        self.timer.set_virtual_now(Some(now));
+        self.renderer.set_virtual_now(Some(now.0));
+        self.layout
+            .update_state(&mut self.layout_state, &self.timer.snapshot());
+        self.renderer.render(&self.layout_state, self.dimensions);
+
+        Some(self.renderer.image_data())
+    }
+
+    /// The point in time and the date of the virtual clock at the time of the
+    /// recording provided.
+    fn virtual_now(&self, time: TimeSpan) -> (TimeStamp, AtomicDateTime) {
+        let date = AtomicDateTime::new(
+            self.origin_date.time + time.to_duration(),
+            self.origin_date.synced_with_atomic_clock,
+        );
+        (self.origin + time, date)
+    }
+
+    /// Renders all the remaining frames and writes them to the writer as raw
+    /// RGBA8 encoded frames without any padding in between. This is the format
+    /// ffmpeg expects when using `-f rawvideo -pixel_format rgba`.
+    pub fn write_raw(&mut self, mut writer: impl Write) -> Result {
+        while let Some(frame) = self.render_next_frame() {
+            writer.write_all(frame).context(Io)?;
+        }
+        writer.flush().context(Io)
+    }
+
+    /// Renders all the remaining frames and stores them as PNG files in the
+    /// directory provided. The files are named by their frame number, such as
+    /// `000000.png`, `000001.png` and so on.
+    pub fn write_png_sequence(&mut self, directory: impl AsRef<Path>) -> Result {
+        let directory = directory.as_ref();
+        while self.render_next_frame().is_some() {
+            let path = directory.join(format!("{:06}.png", self.next_frame - 1));
+            self.renderer.image().save(path).context(Png)?;
+        }
+        Ok(())
+    }
+}
+
+const fn nanoseconds(time: TimeSpan) -> u64 {
+    time.to_duration().whole_nanoseconds() as u64
+}
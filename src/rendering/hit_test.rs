@@ -0,0 +1,115 @@
+use super::Pos;
+use crate::platform::prelude::*;
+
+/// An axis-aligned rectangle in the backend coordinate space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bounds {
+    /// The top left corner of the rectangle.
+    pub top_left: Pos,
+    /// The bottom right corner of the rectangle.
+    pub bottom_right: Pos,
+}
+
+impl Bounds {
+    /// Checks if the position provided lies within the rectangle. The top and
+    /// left edges belong to the rectangle, while the bottom and right edges
+    /// don't, so that adjacent rectangles never both contain a position.
+    pub fn contains(&self, [x, y]: Pos) -> bool {
+        let ([left, top], [right, bottom]) = (self.top_left, self.bottom_right);
+        x >= left && x < right && y >= top && y < bottom
+    }
+}
+
+/// The bounds of a single row of a splits component, as it got placed by the
+/// last update of the scene.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SplitBounds {
+    /// The index of the splits component in the layout.
+    pub component_index: usize,
+    /// The index of the row in the state of the splits component.
+    pub row_index: usize,
+    /// The index of the segment the row is showing, based on all the segments
+    /// of the run.
+    pub segment_index: usize,
+    /// The bounds of the row.
+    pub bounds: Bounds,
+}
+
+/// The part of a component that is located at a certain position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HitElement {
+    /// The component as a whole, as it consists of no individually
+    /// distinguishable parts at this position.
+    Component,
+    /// A row of the splits component.
+    Split {
+        /// The index of the row in the state of the splits component.
+        row_index: usize,
+        /// The index of the segment the row is showing, based on all the
+        /// segments of the run.
+        segment_index: usize,
+    },
+}
+
+/// Describes what is located at a certain position of the rendered layout.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hit {
+    /// The index of the component in the layout.
+    pub component_index: usize,
+    /// The part of the component at the position.
+    pub element: HitElement,
+}
+
+/// Collects the bounds of everything that can be hit while the scene is being
+/// updated.
+#[derive(Default)]
+pub struct HitRegions {
+    components: Vec<Bounds>,
+    splits: Vec<SplitBounds>,
+}
+
+impl HitRegions {
+    pub fn clear(&mut self) {
+        self.components.clear();
+        self.splits.clear();
+    }
+
+    pub fn push_component(&mut self, bounds: Bounds) {
+        self.components.push(bounds);
+    }
+
+    pub fn push_split(&mut self, row_index: usize, segment_index: usize, bounds: Bounds) {
+        self.splits.push(SplitBounds {
+            component_index: self.components.len().saturating_sub(1),
+            row_index,
+            segment_index,
+            bounds,
+        });
+    }
+
+    pub fn components(&self) -> &[Bounds] {
+        &self.components
+    }
+
+    pub fn splits(&self) -> &[SplitBounds] {
+        &self.splits
+    }
+
+    pub fn hit_test(&self, position: Pos) -> Option<Hit> {
+        let component_index = self.components.iter().position(|b| b.contains(position))?;
+
+        let element = self
+            .splits
+            .iter()
+            .find(|s| s.component_index == component_index && s.bounds.contains(position))
+            .map_or(HitElement::Component, |s| HitElement::Split {
+                row_index: s.row_index,
+                segment_index: s.segment_index,
+            });
+
+        Some(Hit {
+            component_index,
+            element,
+        })
+    }
+}
@@ -9,7 +9,12 @@
 //! fonts and labels. An optional software renderer is available behind the
 //! `software-rendering` feature that uses tiny-skia to efficiently render the
 //! paths on the CPU. It is surprisingly fast and can be considered the default
-//! renderer.
+//! renderer. Based on it, attempts can also be exported as a sequence of
+//! frames. Additionally an SVG renderer is available behind the `svg-rendering`
+//! feature that turns the layout into a standalone SVG document. The scene
+//! manager also keeps track of where it placed the components, so positions
+//! such as the one of the mouse cursor can be mapped back to the components
+//! and the split rows they are pointing at.
 
 // # Coordinate spaces used in this module
 //
@@ -70,10 +75,12 @@
 // this height in horizontal mode. Separators have a thickness of 0.1, while
 // thin separators have half of this thickness.
 
+mod animation;
 mod component;
 mod consts;
 mod entity;
 mod font;
+mod hit_test;
 mod icon;
 mod resource;
 mod scene;
@@ -81,30 +88,38 @@ mod scene;
 #[cfg(feature = "path-based-text-engine")]
 pub mod path_based_text_engine;
 
+#[cfg(all(feature = "software-rendering", feature = "std"))]
+pub mod frame_export;
 #[cfg(feature = "software-rendering")]
 pub mod software;
+#[cfg(feature = "svg-rendering")]
+pub mod svg;
 
 use self::{
+    animation::Animator,
     consts::{
         DEFAULT_TEXT_SIZE, DEFAULT_VERTICAL_WIDTH, PADDING, TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP,
         TWO_ROW_HEIGHT,
     },
     font::{AbbreviatedLabel, CachedLabel, FontCache},
+    hit_test::HitRegions,
     icon::Icon,
     resource::Handles,
 };
 use crate::{
-    layout::{LayoutDirection, LayoutState},
+    layout::{ComponentState, LayoutDirection, LayoutGrid, LayoutState},
     platform::prelude::*,
     settings::{Color, Gradient},
+    TimeStamp,
 };
 use alloc::borrow::Cow;
 use bytemuck::{Pod, Zeroable};
-use core::iter;
+use core::{iter, mem};
 
 pub use self::{
     entity::Entity,
     font::{TEXT_FONT, TIMER_FONT},
+    hit_test::{Bounds, Hit, HitElement, SplitBounds},
     resource::{
         FontKind, Handle, Label, LabelHandle, PathBuilder, ResourceAllocator, SharedOwnership,
     },
@@ -164,6 +179,10 @@ pub struct SceneManager<P, I, F, L> {
     next_id: usize,
     cached_size: Option<CachedSize>,
     fonts: FontCache<F>,
+    hit_regions: HitRegions,
+    // Replaces the current time when driving the animations. This allows the
+    // frame exporter to render the animations at the exact time of each frame.
+    virtual_now: Option<TimeStamp>,
 }
 
 impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager<P, I, F, L> {
@@ -188,6 +207,8 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             scene: Scene::new(rectangle),
             cached_size: None,
             fonts,
+            hit_regions: HitRegions::default(),
+            virtual_now: None,
         }
     }
 
@@ -196,6 +217,34 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         &self.scene
     }
 
+    /// Accesses the bounds of each component, as they got placed by the last
+    /// update of the [`Scene`]. They are in the backend coordinate space and
+    /// are in the same order as the components of the [`LayoutState`].
+    pub fn component_bounds(&self) -> &[Bounds] {
+        self.hit_regions.components()
+    }
+
+    /// Accesses the bounds of the rows of all the splits components, as they
+    /// got placed by the last update of the [`Scene`]. They are in the backend
+    /// coordinate space. Rows that are hidden while the splits are scrolling
+    /// are not included.
+    pub fn split_bounds(&self) -> &[SplitBounds] {
+        self.hit_regions.splits()
+    }
+
+    /// Looks up the component and the part of it that is located at the
+    /// position provided in the backend coordinate space. This is based on
+    /// the last update of the [`Scene`]. If there is no component at the
+    /// position, [`None`] is returned.
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<Hit> {
+        self.hit_regions.hit_test([x, y])
+    }
+
+    #[cfg(all(feature = "software-rendering", feature = "std"))]
+    pub(crate) fn set_virtual_now(&mut self, now: Option<TimeStamp>) {
+        self.virtual_now = now;
+    }
+
     /// Updates the [`Scene`] by updating the [`Entities`](Entity) according to
     /// the [`LayoutState`] provided. The [`ResourceAllocator`] is used to
     /// allocate the resources necessary that the [`Entities`](Entity) use. A
@@ -204,6 +253,11 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
     /// change in the layout size is detected, a new more suitable resolution
     /// for subsequent updates is being returned. This is however merely a hint
     /// and can be completely ignored.
+    ///
+    /// Unless the layout disables them, changes such as the splits scrolling or
+    /// the color of a delta switching are animated over time. Check
+    /// [`Scene::needs_next_frame`] to see if the scene needs to be updated
+    /// again to continue the animations.
     pub fn update_scene<A: ResourceAllocator<Path = P, Image = I, Font = F, Label = L>>(
         &mut self,
         allocator: A,
@@ -211,6 +265,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         state: &LayoutState,
     ) -> Option<(f32, f32)> {
         self.scene.clear();
+        self.hit_regions.clear();
 
         self.scene
             .set_background(decode_gradient(&state.background));
@@ -223,12 +278,25 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             self.components.truncate(state.components.len());
         }
 
-        let new_dimensions = match state.direction {
-            LayoutDirection::Vertical => self.render_vertical(allocator, resolution, state),
-            LayoutDirection::Horizontal => self.render_horizontal(allocator, resolution, state),
+        let now = self.virtual_now.unwrap_or_else(TimeStamp::now);
+        let mut animator = Animator::new(now, state.animations);
+
+        let new_dimensions = if let Some(grid) = &state.grid {
+            self.render_grid(allocator, resolution, state, grid, &mut animator);
+            None
+        } else {
+            match state.direction {
+                LayoutDirection::Vertical => {
+                    self.render_vertical(allocator, resolution, state, &mut animator)
+                }
+                LayoutDirection::Horizontal => {
+                    self.render_horizontal(allocator, resolution, state, &mut animator)
+                }
+            }
         };
 
         self.scene.recalculate_if_bottom_layer_changed();
+        self.scene.set_needs_next_frame(animator.is_animating());
 
         new_dimensions
     }
@@ -238,8 +306,9 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         allocator: impl ResourceAllocator<Path = P, Image = I, Font = F, Label = L>,
         resolution: (f32, f32),
         state: &LayoutState,
+        animator: &mut Animator,
     ) -> Option<(f32, f32)> {
-        let total_height = component::layout_height(state);
+        let total_height = component::layout_height(&state.components);
 
         let cached_total_size = self
             .cached_size
@@ -265,39 +334,25 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             }
         }
 
-        let aspect_ratio = resolution.0 as f32 / resolution.1 as f32;
-
         let mut context = RenderContext {
             handles: Handles::new(self.next_id, allocator),
             transform: Transform::scale(resolution.0 as f32, resolution.1 as f32),
             scene: &mut self.scene,
             fonts: &mut self.fonts,
+            hit_regions: &mut self.hit_regions,
+            animator,
+            direction: LayoutDirection::Vertical,
         };
 
         context.fonts.maybe_reload(&mut context.handles, state);
 
-        // Now we transform the coordinate space to Renderer Coordinate Space by
-        // non-uniformly adjusting for the aspect ratio.
-        context.scale_non_uniform_x(aspect_ratio.recip());
-
-        // We scale the coordinate space uniformly such that we have the same
-        // scaling as the Component Coordinate Space. This also already is the
-        // Component Coordinate Space for the component at (0, 0).
-        context.scale(total_height.recip());
-
-        // Calculate the width of the components in component space. In vertical
-        // mode, all the components have the same width.
-        let width = aspect_ratio * total_height;
-
-        for (component, cache) in state.components.iter().zip(&mut self.components) {
-            let height = component::height(component);
-            let dim = [width, height];
-            component::render(cache, &mut context, component, state, dim);
-            // We translate the coordinate space to the Component Coordinate
-            // Space of the next component by shifting by the height of the
-            // current component in the Component Coordinate Space.
-            context.translate(0.0, height);
-        }
+        render_vertical(
+            &mut context,
+            &state.components,
+            &mut self.components,
+            state,
+            resolution,
+        );
 
         self.next_id = context.handles.into_next_id();
 
@@ -309,8 +364,9 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         allocator: impl ResourceAllocator<Path = P, Image = I, Font = F, Label = L>,
         resolution: (f32, f32),
         state: &LayoutState,
+        animator: &mut Animator,
     ) -> Option<(f32, f32)> {
-        let total_width = component::layout_width(state);
+        let total_width = component::layout_width(&state.components);
 
         let cached_total_size = self
             .cached_size
@@ -335,48 +391,187 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             }
         }
 
-        let aspect_ratio = resolution.0 as f32 / resolution.1 as f32;
-
         let mut context = RenderContext {
             handles: Handles::new(self.next_id, allocator),
             transform: Transform::scale(resolution.0 as f32, resolution.1 as f32),
             scene: &mut self.scene,
             fonts: &mut self.fonts,
+            hit_regions: &mut self.hit_regions,
+            animator,
+            direction: LayoutDirection::Horizontal,
         };
 
         context.fonts.maybe_reload(&mut context.handles, state);
 
-        // Now we transform the coordinate space to Renderer Coordinate Space by
-        // non-uniformly adjusting for the aspect ratio.
-        context.scale_non_uniform_x(aspect_ratio.recip());
-
-        // We scale the coordinate space uniformly such that we have the same
-        // scaling as the Component Coordinate Space. This also already is the
-        // Component Coordinate Space for the component at (0, 0). Since all the
-        // components use the two row height as their height, we scale by the
-        // reciprocal of that.
-        context.scale(TWO_ROW_HEIGHT.recip());
-
-        // We don't take the component width we calculate. Instead we use the
-        // component width as a ratio of how much of the total actual width to
-        // distribute to each of the components. This factor is this adjustment.
-        let width_scaling = TWO_ROW_HEIGHT * aspect_ratio / total_width;
-
-        for (component, cache) in state.components.iter().zip(&mut self.components) {
-            let width = component::width(component) * width_scaling;
-            let height = TWO_ROW_HEIGHT;
-            let dim = [width, height];
-            component::render(cache, &mut context, component, state, dim);
-            // We translate the coordinate space to the Component Coordinate
-            // Space of the next component by shifting by the width of the
-            // current component in the Component Coordinate Space.
-            context.translate(width, 0.0);
-        }
+        render_horizontal(
+            &mut context,
+            &state.components,
+            &mut self.components,
+            state,
+            resolution,
+        );
 
         self.next_id = context.handles.into_next_id();
 
         new_resolution
     }
+
+    fn render_grid(
+        &mut self,
+        allocator: impl ResourceAllocator<Path = P, Image = I, Font = F, Label = L>,
+        resolution: (f32, f32),
+        state: &LayoutState,
+        grid: &LayoutGrid,
+        animator: &mut Animator,
+    ) {
+        // The grid always fills the whole render target, so there's no
+        // better suited resolution to suggest.
+        self.cached_size = None;
+
+        let mut context = RenderContext {
+            handles: Handles::new(self.next_id, allocator),
+            transform: Transform::scale(resolution.0, resolution.1),
+            scene: &mut self.scene,
+            fonts: &mut self.fonts,
+            hit_regions: &mut self.hit_regions,
+            animator,
+            direction: LayoutDirection::Vertical,
+        };
+
+        context.fonts.maybe_reload(&mut context.handles, state);
+
+        let layout_transform = context.transform;
+        let total_width: f32 = grid.columns.iter().map(|c| c.size as f32).sum();
+
+        let mut components = &state.components[..];
+        let mut caches = &mut self.components[..];
+        let mut x = 0.0;
+
+        for column in &grid.columns {
+            let width = column.size as f32 / total_width;
+            let total_height: f32 = column.cells.iter().map(|c| c.size as f32).sum();
+            let mut y = 0.0;
+
+            for cell in &column.cells {
+                let height = cell.size as f32 / total_height;
+                let count = cell.components.min(components.len());
+                let (cell_components, rest) = components.split_at(count);
+                let (cell_caches, rest_caches) = mem::take(&mut caches).split_at_mut(count);
+                (components, caches) = (rest, rest_caches);
+
+                // The cell is placed by transforming the coordinate space such
+                // that the cell looks like a whole layout of its own.
+                context.transform = layout_transform
+                    .pre_translate(x, y)
+                    .pre_scale(width, height);
+                let cell_resolution = (resolution.0 * width, resolution.1 * height);
+                match cell.direction {
+                    LayoutDirection::Vertical => render_vertical(
+                        &mut context,
+                        cell_components,
+                        cell_caches,
+                        state,
+                        cell_resolution,
+                    ),
+                    LayoutDirection::Horizontal => render_horizontal(
+                        &mut context,
+                        cell_components,
+                        cell_caches,
+                        state,
+                        cell_resolution,
+                    ),
+                }
+
+                y += height;
+            }
+
+            x += width;
+        }
+
+        self.next_id = context.handles.into_next_id();
+    }
+}
+
+/// Renders the components on top of each other into the coordinate space of
+/// the context, which is expected to span the resolution provided.
+fn render_vertical<A: ResourceAllocator>(
+    context: &mut RenderContext<'_, A>,
+    components: &[ComponentState],
+    caches: &mut [component::Cache<A::Image, A::Label>],
+    state: &LayoutState,
+    resolution: (f32, f32),
+) {
+    context.direction = LayoutDirection::Vertical;
+
+    let total_height = component::layout_height(components);
+    let aspect_ratio = resolution.0 / resolution.1;
+
+    // Now we transform the coordinate space to Renderer Coordinate Space by
+    // non-uniformly adjusting for the aspect ratio.
+    context.scale_non_uniform_x(aspect_ratio.recip());
+
+    // We scale the coordinate space uniformly such that we have the same
+    // scaling as the Component Coordinate Space. This also already is the
+    // Component Coordinate Space for the component at (0, 0).
+    context.scale(total_height.recip());
+
+    // Calculate the width of the components in component space. In vertical
+    // mode, all the components have the same width.
+    let width = aspect_ratio * total_height;
+
+    for (component, cache) in components.iter().zip(caches) {
+        let height = component::height(component);
+        let dim = [width, height];
+        context.record_component_bounds(dim);
+        component::render(cache, context, component, state, dim);
+        // We translate the coordinate space to the Component Coordinate
+        // Space of the next component by shifting by the height of the
+        // current component in the Component Coordinate Space.
+        context.translate(0.0, height);
+    }
+}
+
+/// Renders the components next to each other into the coordinate space of the
+/// context, which is expected to span the resolution provided.
+fn render_horizontal<A: ResourceAllocator>(
+    context: &mut RenderContext<'_, A>,
+    components: &[ComponentState],
+    caches: &mut [component::Cache<A::Image, A::Label>],
+    state: &LayoutState,
+    resolution: (f32, f32),
+) {
+    context.direction = LayoutDirection::Horizontal;
+
+    let total_width = component::layout_width(components);
+    let aspect_ratio = resolution.0 / resolution.1;
+
+    // Now we transform the coordinate space to Renderer Coordinate Space by
+    // non-uniformly adjusting for the aspect ratio.
+    context.scale_non_uniform_x(aspect_ratio.recip());
+
+    // We scale the coordinate space uniformly such that we have the same
+    // scaling as the Component Coordinate Space. This also already is the
+    // Component Coordinate Space for the component at (0, 0). Since all the
+    // components use the two row height as their height, we scale by the
+    // reciprocal of that.
+    context.scale(TWO_ROW_HEIGHT.recip());
+
+    // We don't take the component width we calculate. Instead we use the
+    // component width as a ratio of how much of the total actual width to
+    // distribute to each of the components. This factor is this adjustment.
+    let width_scaling = TWO_ROW_HEIGHT * aspect_ratio / total_width;
+
+    for (component, cache) in components.iter().zip(caches) {
+        let width = component::width(component) * width_scaling;
+        let height = TWO_ROW_HEIGHT;
+        let dim = [width, height];
+        context.record_component_bounds(dim);
+        component::render(cache, context, component, state, dim);
+        // We translate the coordinate space to the Component Coordinate
+        // Space of the next component by shifting by the width of the
+        // current component in the Component Coordinate Space.
+        context.translate(width, 0.0);
+    }
 }
 
 struct RenderContext<'b, A: ResourceAllocator> {
@@ -384,6 +579,9 @@ struct RenderContext<'b, A: ResourceAllocator> {
     handles: Handles<A>,
     scene: &'b mut Scene<A::Path, A::Image, A::Label>,
     fonts: &'b mut FontCache<A::Font>,
+    hit_regions: &'b mut HitRegions,
+    animator: &'b mut Animator,
+    direction: LayoutDirection,
 }
 
 impl<A: ResourceAllocator> RenderContext<'_, A> {
@@ -443,6 +641,24 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         ));
     }
 
+    fn bounds(&self, [x1, y1]: Pos, [x2, y2]: Pos) -> Bounds {
+        Bounds {
+            top_left: self.transform.transform_point(x1, y1),
+            bottom_right: self.transform.transform_point(x2, y2),
+        }
+    }
+
+    fn record_component_bounds(&mut self, dim: Pos) {
+        let bounds = self.bounds([0.0, 0.0], dim);
+        self.hit_regions.push_component(bounds);
+    }
+
+    fn record_split_bounds(&mut self, row_index: usize, segment_index: usize, dim: Pos) {
+        let bounds = self.bounds([0.0, 0.0], dim);
+        self.hit_regions
+            .push_split(row_index, segment_index, bounds);
+    }
+
     fn create_icon(&mut self, image_data: &[u8]) -> Option<Icon<A::Image>> {
         let (image, aspect_ratio) = self.handles.create_image(image_data)?;
         Some(Icon {
@@ -496,6 +712,10 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             height = new_height;
         }
 
+        self.render_stretched_icon([x, y], [width, height], icon);
+    }
+
+    fn render_stretched_icon(&mut self, [x, y]: Pos, [width, height]: Pos, icon: &Icon<A::Image>) {
         let transform = self.transform.pre_translate(x, y).pre_scale(width, height);
 
         self.scene
@@ -799,8 +1019,7 @@ impl Transform {
         }
     }
 
-    #[cfg(feature = "software-rendering")]
-    fn transform_y(&self, y: f32) -> f32 {
-        self.y + self.scale_y * y
+    fn transform_point(&self, x: f32, y: f32) -> [f32; 2] {
+        [self.x + self.scale_x * x, self.y + self.scale_y * y]
     }
 }
@@ -4,6 +4,9 @@
 //! the text gets turned into paths.
 
 use crate::platform::{prelude::*, Arc, RwLock};
+use core::ops::Range;
+#[cfg(feature = "font-loading")]
+use std::path::PathBuf;
 
 #[cfg(feature = "font-loading")]
 use font_kit::{
@@ -13,8 +16,11 @@ use font_kit::{
     source::SystemSource,
 };
 use hashbrown::HashMap;
-use rustybuzz::{Face, Feature, Tag, UnicodeBuffer, Variation};
+use rustybuzz::{Direction, Face, Feature, Script, Tag, UnicodeBuffer, Variation};
 use ttf_parser::{GlyphId, OutlineBuilder};
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo};
+use unicode_script::{Script as UnicodeScript, UnicodeScript as _};
+use unicode_segmentation::UnicodeSegmentation;
 
 use super::{
     font::{TEXT_FONT, TIMER_FONT},
@@ -32,7 +38,12 @@ mod color_font;
 pub struct TextEngine {
     #[cfg(feature = "font-loading")]
     source: SystemSource,
+    #[cfg(feature = "font-loading")]
+    fallback_faces: HashMap<(FontStyle, FontWeight, FontStretch), Arc<[FontData]>>,
+    #[cfg(feature = "font-loading")]
+    font_files: HashMap<PathBuf, Arc<[u8]>>,
     buffer: Option<UnicodeBuffer>,
+    runs: Vec<TextRun>,
 }
 
 impl Default for TextEngine {
@@ -47,47 +58,145 @@ impl TextEngine {
         Self {
             #[cfg(feature = "font-loading")]
             source: SystemSource::new(),
+            #[cfg(feature = "font-loading")]
+            fallback_faces: HashMap::new(),
+            #[cfg(feature = "font-loading")]
+            font_files: HashMap::new(),
             buffer: None,
+            runs: Vec::new(),
         }
     }
 
     /// Creates a new font. You can call this directly from a
-    /// [`ResourceAllocator`](super::ResourceAllocator).
-    pub fn create_font<P>(
-        &mut self,
-        #[allow(unused)] font: Option<&settings::Font>,
-        kind: FontKind,
-    ) -> Font<P> {
+    /// [`ResourceAllocator`](super::ResourceAllocator). The font consists of a
+    /// chain of font faces. The font provided comes first, followed by the
+    /// fonts bundled for the kind of font. If the `font-loading` feature is
+    /// enabled, the fonts installed on the system are used as a last resort
+    /// for any characters none of the other font faces support. Those are
+    /// resolved right away, so laying out text never needs to load any fonts.
+    pub fn create_font<P>(&mut self, font: Option<&settings::Font>, kind: FontKind) -> Font<P> {
+        let (style, weight, stretch) = match font {
+            Some(font) => (font.style, font.weight, font.stretch),
+            None => match kind {
+                FontKind::Timer | FontKind::Times => {
+                    (FontStyle::Normal, FontWeight::Bold, FontStretch::Normal)
+                }
+                FontKind::Text => (FontStyle::Normal, FontWeight::Normal, FontStretch::Normal),
+            },
+        };
+        let variations = variations(style, weight, stretch);
+
+        let mut faces = Vec::new();
+
         #[cfg(feature = "font-loading")]
         if let Some(font) = font {
-            if let Some(font) = Font::try_load_font(&mut self.source, font, kind) {
-                return font;
-            }
+            faces.extend(
+                self.load_font_data(
+                    &[FamilyName::Title(font.family.clone())],
+                    font.style,
+                    font.weight,
+                    font.stretch,
+                )
+                .and_then(|data| FontFace::from_data(&data, &variations)),
+            );
         }
-        #[cfg(not(feature = "font-loading"))]
-        let _ = font;
-
-        let (font_data, style, weight, stretch) = match kind {
-            FontKind::Timer => (
-                TIMER_FONT,
-                FontStyle::Normal,
-                FontWeight::Bold,
-                FontStretch::Normal,
-            ),
-            FontKind::Times => (
-                TEXT_FONT,
-                FontStyle::Normal,
-                FontWeight::Bold,
-                FontStretch::Normal,
-            ),
-            FontKind::Text => (
-                TEXT_FONT,
-                FontStyle::Normal,
-                FontWeight::Normal,
-                FontStretch::Normal,
-            ),
-        };
-        Font::from_slice(font_data, 0, style, weight, stretch, kind).unwrap()
+
+        // The timer font only covers the characters necessary for showing
+        // times, so the text font serves as its fallback.
+        if kind == FontKind::Timer {
+            faces.extend(FontFace::from_slice(TIMER_FONT, 0, &variations));
+        }
+        faces.extend(FontFace::from_slice(TEXT_FONT, 0, &variations));
+
+        #[cfg(feature = "font-loading")]
+        faces.extend(
+            self.fallback_faces(style, weight, stretch)
+                .iter()
+                .filter_map(|data| FontFace::from_data(data, &variations)),
+        );
+
+        Font::new(faces, kind)
+    }
+
+    /// Resolves the fonts installed on the system that serve as the fallback
+    /// for the characters that none of the other font faces of a font support.
+    /// They are only resolved once for each combination of properties and then
+    /// shared by all the fonts with those properties.
+    #[cfg(feature = "font-loading")]
+    fn fallback_faces(
+        &mut self,
+        style: FontStyle,
+        weight: FontWeight,
+        stretch: FontStretch,
+    ) -> Arc<[FontData]> {
+        if let Some(faces) = self.fallback_faces.get(&(style, weight, stretch)) {
+            return faces.clone();
+        }
+
+        let faces: Arc<[FontData]> = FALLBACK_FAMILIES
+            .iter()
+            .filter_map(|family| {
+                self.load_font_data(
+                    &[FamilyName::Title((*family).to_owned())],
+                    style,
+                    weight,
+                    stretch,
+                )
+            })
+            .collect();
+        self.fallback_faces
+            .insert((style, weight, stretch), faces.clone());
+        faces
+    }
+
+    /// Loads the font installed on the system that matches the families and
+    /// properties best. The files are only read once, so fonts that get loaded
+    /// again share their data.
+    #[cfg(feature = "font-loading")]
+    fn load_font_data(
+        &mut self,
+        families: &[FamilyName],
+        style: FontStyle,
+        weight: FontWeight,
+        stretch: FontStretch,
+    ) -> Option<FontData> {
+        let handle = self
+            .source
+            .select_best_match(
+                families,
+                &Properties {
+                    style: match style {
+                        FontStyle::Normal => Style::Normal,
+                        FontStyle::Italic => Style::Italic,
+                    },
+                    weight: Weight(weight.value()),
+                    stretch: Stretch(stretch.factor()),
+                },
+            )
+            .ok()?;
+
+        Some(match handle {
+            Handle::Path { path, font_index } => {
+                let data = match self.font_files.get(&path) {
+                    Some(data) => data.clone(),
+                    None => {
+                        let data: Arc<[u8]> = std::fs::read(&path).ok()?.into();
+                        self.font_files.insert(path, data.clone());
+                        data
+                    }
+                };
+                FontData {
+                    data,
+                    index: font_index,
+                }
+            }
+            Handle::Memory { bytes, font_index } => FontData {
+                data: Arc::try_unwrap(bytes)
+                    .unwrap_or_else(|bytes| (*bytes).clone())
+                    .into(),
+                index: font_index,
+            },
+        })
     }
 
     /// Creates a new text label. You can call this directly from a
@@ -124,92 +233,50 @@ impl TextEngine {
         let mut label = label.write().unwrap();
         let label = &mut *label;
 
-        let mut buffer = self.buffer.take().unwrap_or_else(UnicodeBuffer::new);
-        buffer.push_str(text);
-
-        let features = font
-            .monotonic
-            .as_ref()
-            .map(|m| &m.features[..])
-            .unwrap_or_default();
-
-        let buffer = rustybuzz::shape(&font.face, features, buffer);
-
-        let iter = Iterator::zip(buffer.glyph_infos().iter(), buffer.glyph_positions().iter());
-
-        let (mut x, mut y) = (0.0, 0.0);
-
-        label.glyphs.clear();
-
-        if let Some(monotonic) = &font.monotonic {
-            iter.for_each(|(info, pos)| {
-                let glyph = GlyphId(info.glyph_id as _);
-                let layer_glyphs = font.glyph_cache.entry(glyph).or_insert_with(|| {
-                    let mut glyphs = Vec::new();
-                    iter_colored_glyphs(&font.color_tables, 0, glyph, |glyph, color| {
-                        let mut builder = GlyphBuilder(path_builder());
-                        font.face.outline_glyph(glyph, &mut builder);
-                        let path = builder.0.finish();
-                        glyphs.push((color.map(|c| c.to_array()), path));
-                    });
-                    glyphs
-                });
-                let (x_advance, x_offset) = if monotonic.digit_glyphs.contains(&glyph) {
-                    (
-                        monotonic.digit_width,
-                        0.5 * (monotonic.digit_width - pos.x_advance as f32) + pos.x_offset as f32,
-                    )
-                } else {
-                    (pos.x_advance as f32, pos.x_offset as f32)
-                };
-                let (glyph_x, glyph_y) = (x + x_offset, y + pos.y_offset as f32);
-                x += x_advance;
-                y += pos.y_advance as f32;
-                label
-                    .glyphs
-                    .extend(layer_glyphs.iter().map(|(color, path)| Glyph {
-                        color: *color,
-                        x: glyph_x,
-                        y: glyph_y,
-                        path: path.share(),
-                    }));
-            });
-        } else {
-            iter.for_each(|(info, pos)| {
-                let glyph = GlyphId(info.glyph_id as _);
-                let layer_glyphs = font.glyph_cache.entry(glyph).or_insert_with(|| {
-                    let mut glyphs = Vec::new();
-                    iter_colored_glyphs(&font.color_tables, 0, glyph, |glyph, color| {
-                        let mut builder = GlyphBuilder(path_builder());
-                        font.face.outline_glyph(glyph, &mut builder);
-                        let path = builder.0.finish();
-                        glyphs.push((color.map(|c| c.to_array()), path));
-                    });
-                    glyphs
-                });
-                let (glyph_x, glyph_y) = (x + pos.x_offset as f32, y + pos.y_offset as f32);
-                x += pos.x_advance as f32;
-                y += pos.y_advance as f32;
-                label
-                    .glyphs
-                    .extend(layer_glyphs.iter().map(|(color, path)| Glyph {
-                        color: *color,
-                        x: glyph_x,
-                        y: glyph_y,
-                        path: path.share(),
-                    }));
-            });
-        };
+        let (mut x, has_rtl) = self.lay_out(&mut path_builder, &mut label.glyphs, text, font);
 
         label.width_without_max_width = x * font.scale_factor;
 
         if let Some(max_width) = max_width {
             let max_width = max_width / font.scale_factor;
-            if x > max_width {
+            if x > max_width && has_rtl {
+                // With right-to-left text involved, the characters that are
+                // cut off are the ones at the logical end of the text, which
+                // may be anywhere visually. So the longest logical prefix of
+                // the text that still fits together with the ellipsis is
+                // searched for and then laid out on its own. The text is only
+                // ever cut between grapheme clusters, so no characters get
+                // separated from the marks that are combined with them.
+                let boundary = |index: usize| {
+                    text.grapheme_indices(true)
+                        .nth(index)
+                        .map_or(text.len(), |(i, _)| i)
+                };
+                let mut shortened = String::with_capacity(text.len() + '…'.len_utf8());
+                let (mut low, mut high) = (0, text.graphemes(true).count().saturating_sub(1));
+                while low < high {
+                    let mid = (low + high + 1) / 2;
+                    shortened.clear();
+                    shortened.push_str(&text[..boundary(mid)]);
+                    shortened.push('…');
+                    let (width, _) =
+                        self.lay_out(&mut path_builder, &mut label.glyphs, &shortened, font);
+                    if width <= max_width {
+                        low = mid;
+                    } else {
+                        high = mid - 1;
+                    }
+                }
+                shortened.clear();
+                shortened.push_str(&text[..boundary(low)]);
+                shortened.push('…');
+                (x, _) = self.lay_out(&mut path_builder, &mut label.glyphs, &shortened, font);
+            } else if x > max_width {
                 let (ellipsis, ellipsis_width) = font.ellipsis;
 
                 let x_to_look_for = max_width - ellipsis_width;
 
+                let mut y = 0.0;
                 let last_index = label
                     .glyphs
                     .iter()
@@ -222,137 +289,380 @@ impl TextEngine {
                     .map(|(i, _)| i)
                     .unwrap_or_default();
                 label.glyphs.drain(last_index..);
-                let layer_glyphs = font.glyph_cache.entry(ellipsis).or_insert_with(|| {
-                    let mut glyphs = Vec::new();
-                    iter_colored_glyphs(&font.color_tables, 0, ellipsis, |glyph, color| {
-                        let mut builder = GlyphBuilder(path_builder());
-                        font.face.outline_glyph(glyph, &mut builder);
-                        let path = builder.0.finish();
-                        glyphs.push((color.map(|c| c.to_array()), path));
-                    });
-                    glyphs
-                });
-                label
-                    .glyphs
-                    .extend(layer_glyphs.iter().map(|(color, path)| Glyph {
-                        color: *color,
-                        x,
-                        y,
-                        path: path.share(),
-                    }));
+                label.glyphs.extend(
+                    font.faces[0]
+                        .layer_glyphs(ellipsis, &mut path_builder)
+                        .iter()
+                        .map(|(color, path)| Glyph {
+                            color: *color,
+                            x,
+                            y,
+                            path: path.share(),
+                        }),
+                );
                 x += ellipsis_width;
             }
         }
 
-        self.buffer = Some(buffer.clear());
-
         label.width = x * font.scale_factor;
         label.scale = font.scale_factor;
     }
+
+    /// Lays out the text as glyphs from left to right. The text is split into
+    /// runs based on the Unicode Bidirectional Algorithm, the scripts of the
+    /// characters and the font faces supporting them. The runs are then shaped
+    /// one after another in visual order. Returns the width of the text in the
+    /// units of the font and whether any of the text is right-to-left.
+    fn lay_out<PB: PathBuilder>(
+        &mut self,
+        path_builder: &mut impl FnMut() -> PB,
+        glyphs: &mut Vec<Glyph<PB::Path>>,
+        text: &str,
+        font: &mut Font<PB::Path>,
+    ) -> (f32, bool) {
+        self.runs.clear();
+
+        // Most texts are entirely left-to-right, so the Unicode Bidirectional
+        // Algorithm only needs to run if there's any character that could
+        // introduce right-to-left text.
+        let bidi_info = text
+            .chars()
+            .any(|c| {
+                matches!(
+                    bidi_class(c),
+                    BidiClass::R
+                        | BidiClass::AL
+                        | BidiClass::RLE
+                        | BidiClass::RLO
+                        | BidiClass::RLI
+                        | BidiClass::LRE
+                        | BidiClass::LRO
+                        | BidiClass::LRI
+                        | BidiClass::FSI
+                        | BidiClass::PDF
+                        | BidiClass::PDI
+                )
+            })
+            .then(|| BidiInfo::new(text, None))
+            .filter(BidiInfo::has_rtl);
+        let has_rtl = bidi_info.is_some();
+        if let Some(bidi_info) = bidi_info {
+            for paragraph in &bidi_info.paragraphs {
+                let (levels, level_runs) =
+                    bidi_info.visual_runs(paragraph, paragraph.range.clone());
+                for level_run in level_runs {
+                    let is_rtl = levels[level_run.start].is_rtl();
+                    let first = self.runs.len();
+                    itemize(&mut self.runs, text, level_run, is_rtl, font);
+                    if is_rtl {
+                        self.runs[first..].reverse();
+                    }
+                }
+            }
+        } else {
+            itemize(&mut self.runs, text, 0..text.len(), false, font);
+        }
+
+        let mut buffer = self.buffer.take().unwrap_or_else(UnicodeBuffer::new);
+
+        let (mut x, mut y) = (0.0, 0.0);
+
+        glyphs.clear();
+
+        for run in &self.runs {
+            buffer.push_str(&text[run.range.clone()]);
+            buffer.set_direction(if run.is_rtl {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            });
+            if let Some(script) = run.script {
+                buffer.set_script(script);
+            }
+
+            // Only the primary font face is laid out as monospaced.
+            let monotonic = font.monotonic.as_ref().filter(|_| run.face_index == 0);
+            let features = monotonic.map(|m| &m.features[..]).unwrap_or_default();
+
+            let face = &mut font.faces[run.face_index];
+            let shaped = rustybuzz::shape(&face.face, features, buffer);
+
+            for (info, pos) in
+                Iterator::zip(shaped.glyph_infos().iter(), shaped.glyph_positions().iter())
+            {
+                let glyph = GlyphId(info.glyph_id as _);
+                let (x_advance, x_offset) = match monotonic {
+                    Some(monotonic) if monotonic.digit_glyphs.contains(&glyph) => (
+                        monotonic.digit_width,
+                        0.5 * (monotonic.digit_width - pos.x_advance as f32) + pos.x_offset as f32,
+                    ),
+                    _ => (
+                        pos.x_advance as f32 * face.scale,
+                        pos.x_offset as f32 * face.scale,
+                    ),
+                };
+                let (glyph_x, glyph_y) = (x + x_offset, y + pos.y_offset as f32 * face.scale);
+                x += x_advance;
+                y += pos.y_advance as f32 * face.scale;
+                glyphs.extend(face.layer_glyphs(glyph, path_builder).iter().map(
+                    |(color, path)| Glyph {
+                        color: *color,
+                        x: glyph_x,
+                        y: glyph_y,
+                        path: path.share(),
+                    },
+                ));
+            }
+
+            buffer = shaped.clear();
+        }
+
+        self.buffer = Some(buffer);
+
+        (x, has_rtl)
+    }
+}
+
+/// A run of text that is shaped as a whole.
+struct TextRun {
+    range: Range<usize>,
+    face_index: usize,
+    script: Option<Script>,
+    is_rtl: bool,
+}
+
+/// Splits a run of text of a single direction into runs of characters that
+/// share the same script and are supported by the same font face. The runs are
+/// added in logical order.
+fn itemize<P>(
+    runs: &mut Vec<TextRun>,
+    text: &str,
+    range: Range<usize>,
+    is_rtl: bool,
+    font: &Font<P>,
+) {
+    let mut current: Option<(TextRun, unicode_script::Script)> = None;
+
+    for (index, c) in text[range.clone()].char_indices() {
+        let index = range.start + index;
+        let end = index + c.len_utf8();
+
+        // Characters such as spaces and punctuation are used by many scripts,
+        // so they belong to whatever script surrounds them.
+        let script = c.script();
+        let script = match script {
+            UnicodeScript::Common | UnicodeScript::Inherited | UnicodeScript::Unknown => None,
+            _ => Some(script),
+        };
+
+        // Spaces and punctuation stay with the font face of the surrounding
+        // text if it supports them, so the run doesn't get split up.
+        let face_index = match &current {
+            Some((run, _))
+                if continues_run(c)
+                    || (script.is_none()
+                        && !c.is_alphanumeric()
+                        && font.faces[run.face_index].face.glyph_index(c).is_some()) =>
+            {
+                run.face_index
+            }
+            _ => font.face_index_for(c),
+        };
+
+        if let Some((run, run_script)) = &mut current {
+            let same_script = match script {
+                Some(script) => {
+                    if *run_script == UnicodeScript::Unknown {
+                        *run_script = script;
+                        run.script = to_script(script);
+                    }
+                    *run_script == script
+                }
+                None => true,
+            };
+            if same_script && run.face_index == face_index {
+                run.range.end = end;
+                continue;
+            }
+        }
+
+        if let Some((run, _)) = current.take() {
+            runs.push(run);
+        }
+        current = Some((
+            TextRun {
+                range: index..end,
+                face_index,
+                script: script.and_then(to_script),
+                is_rtl,
+            },
+            script.unwrap_or(UnicodeScript::Unknown),
+        ));
+    }
+
+    runs.extend(current.map(|(run, _)| run));
+}
+
+fn to_script(script: UnicodeScript) -> Option<Script> {
+    Script::from_iso15924_tag(Tag::from_bytes_lossy(script.short_name().as_bytes()))
 }
 
+const fn variations(style: FontStyle, weight: FontWeight, stretch: FontStretch) -> [Variation; 3] {
+    [
+        Variation {
+            tag: Tag::from_bytes(b"ital"),
+            value: style.value_for_italic(),
+        },
+        Variation {
+            tag: Tag::from_bytes(b"wght"),
+            value: weight.value(),
+        },
+        Variation {
+            tag: Tag::from_bytes(b"wdth"),
+            value: stretch.percentage(),
+        },
+    ]
+}
+
+/// Characters that modify the character in front of them, such as combining
+/// marks, variation selectors and zero width joiners. They are kept in the same
+/// run, so they are shaped together with the character they belong to.
+const fn continues_run(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{200C}'..='\u{200D}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+/// The families of fonts installed on the various operating systems that are
+/// tried in order when none of the font faces of a font support a character.
+#[cfg(feature = "font-loading")]
+const FALLBACK_FAMILIES: [&str; 16] = [
+    "Segoe UI",
+    "Segoe UI Symbol",
+    "Segoe UI Emoji",
+    "Microsoft YaHei",
+    "Yu Gothic",
+    "Malgun Gothic",
+    "Hiragino Sans",
+    "PingFang SC",
+    "Apple SD Gothic Neo",
+    "Apple Symbols",
+    "Noto Sans",
+    "Noto Sans CJK JP",
+    "Noto Sans Symbols",
+    "Noto Sans Symbols2",
+    "Noto Color Emoji",
+    "DejaVu Sans",
+];
+
 struct MonotonicInfo {
     digit_glyphs: [GlyphId; 10],
     digit_width: f32,
     features: [Feature; 1],
 }
 
-/// The font to use in the [`ResourceAllocator`](super::ResourceAllocator).
-pub struct Font<P> {
+/// The data of a font installed on the system. The data is shared by all the
+/// font faces that are loaded from it.
+#[cfg(feature = "font-loading")]
+struct FontData {
+    data: Arc<[u8]>,
+    index: u32,
+}
+
+/// A single font face of the fallback chain of a [`Font`].
+struct FontFace<P> {
     face: Face<'static>,
     color_tables: Option<ColorTables<'static>>,
-    scale_factor: f32,
-    monotonic: Option<MonotonicInfo>,
-    /// The `GlyphId` and width of `…`.
-    ellipsis: (GlyphId, f32),
+    /// Converts from the units of this face to the units of the primary face
+    /// of the font.
+    scale: f32,
     glyph_cache: HashMap<GlyphId, Vec<(Option<Rgba>, P)>>,
     #[cfg(feature = "font-loading")]
-    _buf: Option<Box<[u8]>>,
+    _buf: Option<Arc<[u8]>>,
 }
 
-impl<P> Font<P> {
+impl<P> FontFace<P> {
     #[cfg(feature = "font-loading")]
-    fn try_load_font(
-        source: &mut SystemSource,
-        font: &settings::Font,
-        kind: FontKind,
-    ) -> Option<Self> {
-        let handle = source
-            .select_best_match(
-                &[FamilyName::Title(font.family.clone())],
-                &Properties {
-                    style: match font.style {
-                        FontStyle::Normal => Style::Normal,
-                        FontStyle::Italic => Style::Italic,
-                    },
-                    weight: Weight(font.weight.value()),
-                    stretch: Stretch(font.stretch.factor()),
-                },
-            )
-            .ok()?;
-
-        let (buf, font_index) = match handle {
-            Handle::Path { path, font_index } => (std::fs::read(path).ok()?, font_index),
-            Handle::Memory { bytes, font_index } => (
-                Arc::try_unwrap(bytes).unwrap_or_else(|bytes| (*bytes).clone()),
-                font_index,
-            ),
-        };
-        let buf = buf.into_boxed_slice();
-
-        // Safety: We store our own buffer. If we never modify it and drop it
-        // last, this is fine. It also needs to be heap allocated, so it's a
-        // stable pointer. This is guaranteed by the boxed slice.
+    fn from_data(data: &FontData, variations: &[Variation]) -> Option<Self> {
+        // Safety: We keep a reference to the buffer for as long as the face
+        // lives. The buffer is never modified and it is heap allocated, so it's
+        // a stable pointer. This is guaranteed by the Arc.
         unsafe {
-            let slice: *const [u8] = &*buf;
-            let mut font = Font::from_slice(
-                &*slice,
-                font_index,
-                font.style,
-                font.weight,
-                font.stretch,
-                kind,
-            )?;
-            font._buf = Some(buf);
-            Some(font)
+            let slice: *const [u8] = &*data.data;
+            let mut face = FontFace::from_slice(&*slice, data.index, variations)?;
+            face._buf = Some(data.data.clone());
+            Some(face)
         }
     }
 
-    fn from_slice(
-        data: &'static [u8],
-        index: u32,
-        style: FontStyle,
-        weight: FontWeight,
-        stretch: FontStretch,
-        kind: FontKind,
-    ) -> Option<Self> {
+    fn from_slice(data: &'static [u8], index: u32, variations: &[Variation]) -> Option<Self> {
         let mut face = Face::from_slice(data, index)?;
+        face.set_variations(variations);
 
-        let italic = style.value_for_italic();
-        let weight = weight.value();
-        let stretch = stretch.percentage();
+        Some(Self {
+            color_tables: ColorTables::new(&face),
+            face,
+            scale: 1.0,
+            glyph_cache: HashMap::new(),
+            #[cfg(feature = "font-loading")]
+            _buf: None,
+        })
+    }
 
-        face.set_variations(&[
-            Variation {
-                tag: Tag::from_bytes(b"ital"),
-                value: italic,
-            },
-            Variation {
-                tag: Tag::from_bytes(b"wght"),
-                value: weight,
-            },
-            Variation {
-                tag: Tag::from_bytes(b"wdth"),
-                value: stretch,
-            },
-        ]);
+    fn layer_glyphs<PB: PathBuilder<Path = P>>(
+        &mut self,
+        glyph: GlyphId,
+        path_builder: &mut impl FnMut() -> PB,
+    ) -> &[(Option<Rgba>, P)] {
+        let Self {
+            face,
+            color_tables,
+            scale,
+            glyph_cache,
+            ..
+        } = self;
+        glyph_cache.entry(glyph).or_insert_with(|| {
+            let mut glyphs = Vec::new();
+            iter_colored_glyphs(color_tables, 0, glyph, |glyph, color| {
+                let mut builder = GlyphBuilder(path_builder(), *scale);
+                face.outline_glyph(glyph, &mut builder);
+                let path = builder.0.finish();
+                glyphs.push((color.map(|c| c.to_array()), path));
+            });
+            glyphs
+        })
+    }
+}
+
+/// The font to use in the [`ResourceAllocator`](super::ResourceAllocator). It
+/// consists of a chain of font faces, where each character is rendered with the
+/// first font face that supports it.
+pub struct Font<P> {
+    faces: Vec<FontFace<P>>,
+    scale_factor: f32,
+    monotonic: Option<MonotonicInfo>,
+    /// The `GlyphId` and width of `…`.
+    ellipsis: (GlyphId, f32),
+}
+
+impl<P> Font<P> {
+    fn new(faces: Vec<FontFace<P>>, kind: FontKind) -> Self {
+        // The bundled fonts are always valid.
+        let face = &faces[0].face;
 
         let monotonic = kind.is_monospaced().then(|| {
             let mut digit_glyphs = [GlyphId(0); 10];
             let mut digit_width = 0;
             for (digit, glyph) in digit_glyphs.iter_mut().enumerate() {
                 let (glyph_id, width) =
-                    glyph_width(&face, char::from(digit as u8 + b'0')).unwrap_or_default();
+                    glyph_width(face, char::from(digit as u8 + b'0')).unwrap_or_default();
                 *glyph = glyph_id;
                 if width > digit_width {
                     digit_width = width;
@@ -377,35 +687,58 @@ impl<P> Font<P> {
             }
         });
 
-        let (ellipsis, ellipsis_width) = glyph_width(&face, '…').unwrap_or_default();
+        let (ellipsis, ellipsis_width) = glyph_width(face, '…').unwrap_or_default();
 
-        Some(Self {
+        let mut font = Self {
             scale_factor: 1.0 / face.height() as f32,
-            color_tables: ColorTables::new(&face),
-            face,
-            #[cfg(feature = "font-loading")]
-            _buf: None,
             monotonic,
             ellipsis: (ellipsis, ellipsis_width as f32),
-            glyph_cache: HashMap::new(),
-        })
+            faces: Vec::with_capacity(faces.len()),
+        };
+        for face in faces {
+            font.push_face(face);
+        }
+        font
+    }
+
+    fn push_face(&mut self, mut face: FontFace<P>) {
+        if let Some(primary) = self.faces.first() {
+            face.scale = primary.face.height() as f32 / face.face.height() as f32;
+        }
+        self.faces.push(face);
+    }
+
+    /// Looks up the first font face that supports the character. If none of
+    /// them do, the primary font face is used, which renders the character as
+    /// missing.
+    fn face_index_for(&self, c: char) -> usize {
+        self.faces
+            .iter()
+            .position(|f| f.face.glyph_index(c).is_some())
+            .unwrap_or_default()
     }
 }
 
-struct GlyphBuilder<PB>(PB);
+/// Builds the path of a glyph. The outline is scaled by the factor provided.
+struct GlyphBuilder<PB>(PB, f32);
 
 impl<PB: PathBuilder> OutlineBuilder for GlyphBuilder<PB> {
     fn move_to(&mut self, x: f32, y: f32) {
-        self.0.move_to(x, -y);
+        let s = self.1;
+        self.0.move_to(s * x, s * -y);
     }
     fn line_to(&mut self, x: f32, y: f32) {
-        self.0.line_to(x, -y);
+        let s = self.1;
+        self.0.line_to(s * x, s * -y);
     }
     fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
-        self.0.quad_to(x1, -y1, x, -y);
+        let s = self.1;
+        self.0.quad_to(s * x1, s * -y1, s * x, s * -y);
     }
     fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
-        self.0.curve_to(x1, -y1, x2, -y2, x, -y);
+        let s = self.1;
+        self.0
+            .curve_to(s * x1, s * -y1, s * x2, s * -y2, s * x, s * -y);
     }
     fn close(&mut self) {
         self.0.close();
@@ -33,8 +33,10 @@ impl Layer {
 /// rerendered for most frames. The top [`Layer`] contains all the per frame
 /// changes and needs to be rerendered for every frame. If however it is empty
 /// and both the bottom layer didn't change, then no new frame needs to be
-/// rendered. While the top [`Layer`] is inherently transparent, the bottom
-/// [`Layer`] has a background that needs to be considered.
+/// rendered. While an animation is running, the scene reports that it
+/// [needs the next frame](Self::needs_next_frame) though. While the top
+/// [`Layer`] is inherently transparent, the bottom [`Layer`] has a background
+/// that needs to be considered.
 pub struct Scene<P, I, L> {
     rectangle: Handle<P>,
     background: Option<FillShader>,
@@ -42,6 +44,7 @@ pub struct Scene<P, I, L> {
     bottom_layer_changed: bool,
     bottom_layer: Vec<Entity<P, I, L>>,
     top_layer: Vec<Entity<P, I, L>>,
+    needs_next_frame: bool,
 }
 
 impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L> {
@@ -55,6 +58,7 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
             bottom_layer_changed: false,
             bottom_layer: Vec::new(),
             top_layer: Vec::new(),
+            needs_next_frame: false,
         }
     }
 
@@ -73,6 +77,14 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.bottom_layer_changed
     }
 
+    /// Check if the scene is in the middle of an animation. In that case the
+    /// scene needs to be updated and rendered again as soon as possible, even
+    /// if the state of the layout doesn't change. Otherwise there's no need to
+    /// render another frame until the state changes.
+    pub const fn needs_next_frame(&self) -> bool {
+        self.needs_next_frame
+    }
+
     /// Get a reference to the scene's bottom [`Layer`]. This [`Layer`] is
     /// intended to infrequently change, so it doesn't need to be rerendered
     /// every frame.
@@ -95,6 +107,12 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.background = background;
     }
 
+    /// Set whether the scene is in the middle of an animation and needs another
+    /// frame.
+    pub fn set_needs_next_frame(&mut self, needs_next_frame: bool) {
+        self.needs_next_frame = needs_next_frame;
+    }
+
     /// Get a mutable reference to the scene's bottom [`Layer`].
     pub fn bottom_layer_mut(&mut self) -> &mut Vec<Entity<P, I, L>> {
         &mut self.bottom_layer
@@ -6,10 +6,10 @@ use alloc::rc::Rc;
 use core::{mem, ops::Deref};
 
 use super::{
-    entity::Entity,
+    entity::{calculate_entity_hash, Entity},
     path_based_text_engine::{Font, Label, TextEngine},
     resource::{self, ResourceAllocator},
-    FillShader, FontKind, Scene, SceneManager, SharedOwnership, Transform,
+    FillShader, FontKind, Hit, Scene, SceneManager, SharedOwnership, Transform,
 };
 use crate::{layout::LayoutState, settings};
 #[cfg(feature = "image")]
@@ -149,10 +149,37 @@ pub struct BorrowedRenderer {
     allocator: SkiaAllocator,
     scene_manager: SceneManager<SkiaPath, SkiaImage, SkiaFont, SkiaLabel>,
     background: Pixmap,
-    min_y: f32,
-    max_y: f32,
+    rendered_entities: Vec<RenderedEntity>,
+    dirty_regions: Vec<DirtyRegion>,
+    region: Vec<u8>,
 }
 
+/// A rectangular region of the image that changed when rendering the last
+/// frame. Window backends can use these to only present the parts of the image
+/// that actually changed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DirtyRegion {
+    /// The x coordinate of the left edge of the region in pixels.
+    pub x: u32,
+    /// The y coordinate of the top edge of the region in pixels.
+    pub y: u32,
+    /// The width of the region in pixels.
+    pub width: u32,
+    /// The height of the region in pixels.
+    pub height: u32,
+}
+
+/// An entity of the top layer as it was rendered in the previous frame. If an
+/// entity with the same hash is rendered again, the pixels it covers don't need
+/// to be rerendered.
+struct RenderedEntity {
+    hash: u64,
+    bounds: Bounds,
+}
+
+/// The bounds of an entity in pixels as `[left, top, right, bottom]`.
+type Bounds = [f32; 4];
+
 struct UnsafeRc<T>(Rc<T>);
 
 impl<T: Send + Sync> Deref for UnsafeRc<T> {
@@ -208,8 +235,9 @@ impl BorrowedRenderer {
             allocator,
             scene_manager,
             background: Pixmap::new(1, 1).unwrap(),
-            min_y: f32::INFINITY,
-            max_y: f32::NEG_INFINITY,
+            rendered_entities: Vec::new(),
+            dirty_regions: Vec::new(),
+            region: Vec::new(),
         }
     }
 
@@ -223,8 +251,10 @@ impl BorrowedRenderer {
     /// that the layout got resized. In that case it returns the new ideal size.
     /// This is just a hint and can be ignored entirely. The image is always
     /// rendered with the resolution provided. By default the renderer will try
-    /// not to redraw parts of the image that haven't changed. You can force a
-    /// redraw in case the image provided or its contents have changed.
+    /// not to redraw parts of the image that haven't changed. The regions that
+    /// got redrawn can be queried with [`dirty_regions`](Self::dirty_regions)
+    /// afterwards. You can force a redraw in case the image provided or its
+    /// contents have changed.
     pub fn render(
         &mut self,
         state: &LayoutState,
@@ -235,7 +265,8 @@ impl BorrowedRenderer {
     ) -> Option<(f32, f32)> {
         let mut frame_buffer = PixmapMut::from_bytes(image, stride, height).unwrap();
 
-        if stride != self.background.width() || height != self.background.height() {
+        let resized = stride != self.background.width() || height != self.background.height();
+        if resized {
             self.background = Pixmap::new(stride, height).unwrap();
         }
 
@@ -247,38 +278,116 @@ impl BorrowedRenderer {
         let rectangle = scene.rectangle();
         let rectangle = rectangle.as_deref().unwrap();
 
-        let bottom_layer_changed = scene.bottom_layer_changed();
+        let bottom_layer_changed = scene.bottom_layer_changed() || resized;
 
         let mut background = self.background.as_mut();
 
         if bottom_layer_changed {
             fill_background(scene, &mut background, width, height);
-            render_layer(&mut background, scene.bottom_layer(), rectangle);
+            render_layer(&mut background, scene.bottom_layer(), rectangle, [0, 0]);
         }
 
         let top_layer = scene.top_layer();
 
-        let (min_y, max_y) = calculate_bounds(top_layer);
-        let min_y = mem::replace(&mut self.min_y, min_y).min(min_y);
-        let max_y = mem::replace(&mut self.max_y, max_y).max(max_y);
+        let previous_entities = mem::take(&mut self.rendered_entities);
+        self.rendered_entities
+            .extend(top_layer.iter().map(|entity| RenderedEntity {
+                hash: calculate_entity_hash(entity),
+                bounds: calculate_bounds(entity),
+            }));
+
+        self.dirty_regions.clear();
 
         if force_redraw || bottom_layer_changed {
             frame_buffer
                 .data_mut()
                 .copy_from_slice(background.data_mut());
-        } else if min_y <= max_y {
-            let stride = 4 * stride as usize;
-            let min_y = stride * (min_y - 1.0) as usize;
-            let max_y = stride * ((max_y + 2.0) as usize).min(height as usize);
-
-            frame_buffer.data_mut()[min_y..max_y]
-                .copy_from_slice(&background.data_mut()[min_y..max_y]);
+            render_layer(&mut frame_buffer, top_layer, rectangle, [0, 0]);
+            self.dirty_regions.push(DirtyRegion {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            });
+            return new_resolution;
         }
 
-        render_layer(&mut frame_buffer, top_layer, rectangle);
+        let damage = merge_overlapping(changed_bounds(&previous_entities, &self.rendered_entities));
+
+        let stride = stride as usize;
+        let frame_buffer = frame_buffer.data_mut();
+        let background = background.data_mut();
+
+        for damaged in damage {
+            let [left, top, right, bottom] = damaged;
+            let top = (top.max(0.0) as u32).min(height);
+            let bottom = (bottom.max(0.0) as u32).min(height);
+            let left = (left.max(0.0) as u32).min(width);
+            let right = (right.max(0.0) as u32).min(width);
+            if top >= bottom || left >= right {
+                continue;
+            }
+
+            // The damaged area is rendered as its own image, starting out with
+            // the background, and then copied into the frame buffer. Only the
+            // entities that reach into the area need to be rendered.
+            let row_len = 4 * (right - left) as usize;
+            self.region.clear();
+            for row in top as usize..bottom as usize {
+                let start = 4 * (row * stride + left as usize);
+                self.region
+                    .extend_from_slice(&background[start..start + row_len]);
+            }
+            let mut region =
+                PixmapMut::from_bytes(&mut self.region, right - left, bottom - top).unwrap();
+            let entities = top_layer
+                .iter()
+                .zip(&self.rendered_entities)
+                .filter(|(_, rendered)| overlap(&rendered.bounds, &damaged).is_some())
+                .map(|(entity, _)| entity);
+            render_layer(&mut region, entities, rectangle, [left, top]);
+
+            for (row, pixels) in (top as usize..bottom as usize).zip(self.region.chunks(row_len)) {
+                let start = 4 * (row * stride + left as usize);
+                frame_buffer[start..start + row_len].copy_from_slice(pixels);
+            }
+
+            self.dirty_regions.push(DirtyRegion {
+                x: left,
+                y: top,
+                width: right - left,
+                height: bottom - top,
+            });
+        }
 
         new_resolution
     }
+
+    /// Accesses the regions of the image that changed when rendering the last
+    /// frame. If the whole image got redrawn, this is a single region covering
+    /// the whole image. If nothing changed, there are no regions at all.
+    pub fn dirty_regions(&self) -> &[DirtyRegion] {
+        &self.dirty_regions
+    }
+
+    /// Checks if the layout is in the middle of an animation. In that case
+    /// another frame should be rendered as soon as possible, even if the state
+    /// of the layout doesn't change.
+    pub const fn needs_next_frame(&self) -> bool {
+        self.scene_manager.scene().needs_next_frame()
+    }
+
+    /// Looks up the component and the part of it that is located at the pixel
+    /// provided. This is based on the last frame that got rendered. If there
+    /// is no component at the pixel, [`None`] is returned.
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<Hit> {
+        self.scene_manager.hit_test(x, y)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn set_virtual_now(&mut self, now: Option<crate::TimeStamp>) {
+        self.scene_manager.set_virtual_now(now);
+    }
 }
 
 /// The software renderer allows rendering layouts entirely on the CPU. This is
@@ -323,6 +432,32 @@ impl Renderer {
         )
     }
 
+    /// Accesses the regions of the image that changed when rendering the last
+    /// frame. If the whole image got redrawn, this is a single region covering
+    /// the whole image. If nothing changed, there are no regions at all.
+    pub fn dirty_regions(&self) -> &[DirtyRegion] {
+        self.renderer.dirty_regions()
+    }
+
+    /// Checks if the layout is in the middle of an animation. In that case
+    /// another frame should be rendered as soon as possible, even if the state
+    /// of the layout doesn't change.
+    pub const fn needs_next_frame(&self) -> bool {
+        self.renderer.needs_next_frame()
+    }
+
+    /// Looks up the component and the part of it that is located at the pixel
+    /// provided. This is based on the last frame that got rendered. If there
+    /// is no component at the pixel, [`None`] is returned.
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<Hit> {
+        self.renderer.hit_test(x, y)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn set_virtual_now(&mut self, now: Option<crate::TimeStamp>) {
+        self.renderer.set_virtual_now(now);
+    }
+
     /// Accesses the image as a byte slice of RGBA8 encoded pixels (red, green,
     /// blue, alpha with each channel being an u8).
     pub fn image_data(&self) -> &[u8] {
@@ -358,11 +493,18 @@ impl Renderer {
     }
 }
 
-fn render_layer(
+/// Renders the layer into the canvas. The canvas may only be a region of the
+/// whole image, with its top left corner at the pixel provided.
+fn render_layer<'e>(
     canvas: &mut PixmapMut<'_>,
-    layer: &[Entity<SkiaPath, SkiaImage, SkiaLabel>],
+    layer: impl IntoIterator<Item = &'e Entity<SkiaPath, SkiaImage, SkiaLabel>>,
     rectangle: &Path,
+    [x, y]: [u32; 2],
 ) {
+    let convert_transform = |transform: &Transform| {
+        convert_transform(transform).post_translate(-(x as f32), -(y as f32))
+    };
+
     for entity in layer {
         match entity {
             Entity::FillPath(path, shader, transform) => {
@@ -606,52 +748,165 @@ fn fill_background(
     }
 }
 
-fn calculate_bounds(layer: &[Entity<SkiaPath, SkiaImage, SkiaLabel>]) -> (f32, f32) {
-    let (mut min_y, mut max_y) = (f32::INFINITY, f32::NEG_INFINITY);
-    for entity in layer.iter() {
-        match entity {
-            Entity::FillPath(path, _, transform) => {
-                if let Some(path) = &**path {
-                    let bounds = path.bounds();
-                    for y in [bounds.top(), bounds.bottom()] {
-                        let transformed_y = transform.transform_y(y);
-                        min_y = min_y.min(transformed_y);
-                        max_y = max_y.max(transformed_y);
-                    }
-                }
+/// Calculates the bounds of all the pixels the entity may touch.
+fn calculate_bounds(entity: &Entity<SkiaPath, SkiaImage, SkiaLabel>) -> Bounds {
+    let mut bounds = [
+        f32::INFINITY,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NEG_INFINITY,
+    ];
+    let mut include = |rect: Rect, transform: &Transform, margin: f32| {
+        for [x, y] in [
+            transform.transform_point(rect.left(), rect.top()),
+            transform.transform_point(rect.right(), rect.bottom()),
+        ] {
+            bounds[0] = bounds[0].min(x - margin);
+            bounds[1] = bounds[1].min(y - margin);
+            bounds[2] = bounds[2].max(x + margin);
+            bounds[3] = bounds[3].max(y + margin);
+        }
+    };
+
+    match entity {
+        Entity::FillPath(path, _, transform) => {
+            if let Some(path) = &**path {
+                include(path.bounds(), transform, 0.0);
             }
-            Entity::StrokePath(path, radius, _, transform) => {
-                if let Some(path) = &**path {
-                    let radius = transform.scale_y * radius;
-                    let bounds = path.bounds();
-                    for y in [bounds.top(), bounds.bottom()] {
-                        let transformed_y = transform.transform_y(y);
-                        min_y = min_y.min(transformed_y - radius);
-                        max_y = max_y.max(transformed_y + radius);
-                    }
-                }
+        }
+        Entity::StrokePath(path, stroke_width, _, transform) => {
+            if let Some(path) = &**path {
+                // Joins may extend past half the stroke width, so we stay on
+                // the safe side here.
+                let margin = transform.scale_x.abs().max(transform.scale_y.abs()) * stroke_width;
+                include(path.bounds(), transform, margin);
             }
-            Entity::Image(_, transform) => {
-                for y in [0.0, 1.0] {
-                    let transformed_y = transform.transform_y(y);
-                    min_y = min_y.min(transformed_y);
-                    max_y = max_y.max(transformed_y);
+        }
+        Entity::Image(_, transform) => {
+            include(Rect::from_ltrb(0.0, 0.0, 1.0, 1.0).unwrap(), transform, 0.0);
+        }
+        Entity::Label(label, _, transform) => {
+            let label = label.read().unwrap();
+            let transform = transform.pre_scale(label.scale(), label.scale());
+            for glyph in label.glyphs() {
+                if let Some(path) = &glyph.path {
+                    include(
+                        path.bounds(),
+                        &transform.pre_translate(glyph.x, glyph.y),
+                        0.0,
+                    );
                 }
             }
-            Entity::Label(label, _, transform) => {
-                for glyph in label.read().unwrap().glyphs() {
-                    if let Some(path) = &glyph.path {
-                        let transform = transform.pre_translate(glyph.x, glyph.y);
-                        let bounds = path.bounds();
-                        for y in [bounds.top(), bounds.bottom()] {
-                            let transformed_y = transform.transform_y(y);
-                            min_y = min_y.min(transformed_y);
-                            max_y = max_y.max(transformed_y);
-                        }
-                    }
+        }
+    }
+
+    // Anti-aliasing may touch the pixels surrounding the bounds. The bounds
+    // also get truncated to whole pixels later on, so we need to account for
+    // that as well.
+    let [left, top, right, bottom] = bounds;
+    [left - 1.0, top - 1.0, right + 2.0, bottom + 2.0]
+}
+
+/// Determines the bounds of all the entities that either disappeared or newly
+/// appeared since the previous frame. Both the previous and the current
+/// entities are matched up by their hashes. Entities that merely got reordered
+/// only change the pixels where they overlap with each other, as that's where
+/// the order decides which of them ends up on top.
+fn changed_bounds(previous: &[RenderedEntity], current: &[RenderedEntity]) -> Vec<Bounds> {
+    // The sorts are stable, so entities with the same hash are matched up in
+    // the order they are rendered in.
+    let mut previous_order: Vec<usize> = (0..previous.len()).collect();
+    let mut current_order: Vec<usize> = (0..current.len()).collect();
+    previous_order.sort_by_key(|&i| previous[i].hash);
+    current_order.sort_by_key(|&i| current[i].hash);
+
+    let mut changed = Vec::new();
+    let mut previous_indices = vec![None; current.len()];
+    let (mut previous_order, mut current_order) = (
+        previous_order.into_iter().peekable(),
+        current_order.into_iter().peekable(),
+    );
+    loop {
+        match (previous_order.peek(), current_order.peek()) {
+            (Some(&p), Some(&c)) if previous[p].hash == current[c].hash => {
+                previous_indices[c] = Some(p);
+                previous_order.next();
+                current_order.next();
+            }
+            (Some(&p), Some(&c)) if previous[p].hash < current[c].hash => {
+                changed.push(previous[p].bounds);
+                previous_order.next();
+            }
+            (Some(&p), None) => {
+                changed.push(previous[p].bounds);
+                previous_order.next();
+            }
+            (_, Some(&c)) => {
+                changed.push(current[c].bounds);
+                current_order.next();
+            }
+            (None, None) => break,
+        }
+    }
+
+    let kept: Vec<(usize, usize)> = previous_indices
+        .into_iter()
+        .enumerate()
+        .filter_map(|(c, p)| Some((c, p?)))
+        .collect();
+    if kept.windows(2).any(|pair| pair[0].1 > pair[1].1) {
+        for (i, &(c1, p1)) in kept.iter().enumerate() {
+            for &(c2, p2) in &kept[i + 1..] {
+                if p1 > p2 {
+                    changed.extend(overlap(&current[c1].bounds, &current[c2].bounds));
                 }
             }
         }
     }
-    (min_y, max_y)
+
+    changed.retain(|&[left, top, right, bottom]| left < right && top < bottom);
+    changed
+}
+
+/// Calculates the area where both of the bounds provided overlap, if there is
+/// any.
+fn overlap(
+    &[left, top, right, bottom]: &Bounds,
+    &[other_left, other_top, other_right, other_bottom]: &Bounds,
+) -> Option<Bounds> {
+    let overlap = [
+        left.max(other_left),
+        top.max(other_top),
+        right.min(other_right),
+        bottom.min(other_bottom),
+    ];
+    if overlap[0] < overlap[2] && overlap[1] < overlap[3] {
+        Some(overlap)
+    } else {
+        None
+    }
+}
+
+/// Merges all the damaged areas that overlap into a single area that covers
+/// all of them, so no pixel is rendered more than once.
+fn merge_overlapping(mut damage: Vec<Bounds>) -> Vec<Bounds> {
+    let mut merged: Vec<Bounds> = Vec::with_capacity(damage.len());
+    while let Some(mut bounds) = damage.pop() {
+        // Growing the area may make it overlap with other areas that were
+        // merged already, so we need to keep going until it doesn't.
+        while let Some(i) = merged
+            .iter()
+            .position(|other| overlap(&bounds, other).is_some())
+        {
+            let [left, top, right, bottom] = merged.swap_remove(i);
+            bounds = [
+                bounds[0].min(left),
+                bounds[1].min(top),
+                bounds[2].max(right),
+                bounds[3].max(bottom),
+            ];
+        }
+        merged.push(bounds);
+    }
+    merged
 }
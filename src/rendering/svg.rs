@@ -0,0 +1,453 @@
+//! Provides a renderer that emits the layout as a standalone SVG document. As
+//! the document consists of vector graphics only, it can be scaled to any size
+//! without losing any quality, which makes it suitable for exporting layouts as
+//! thumbnails, for documentation or for embedding them into websites.
+
+use crate::platform::prelude::*;
+use alloc::sync::Arc;
+use core::fmt::{self, Write};
+
+use super::{
+    entity::Entity,
+    path_based_text_engine::{Font, Label, TextEngine},
+    resource::{self, ResourceAllocator},
+    FillShader, FontKind, Rgba, Scene, SceneManager, Transform,
+};
+use crate::{layout::LayoutState, settings};
+
+type SvgPath = Arc<Path>;
+type SvgImage = Arc<Image>;
+type SvgFont = Font<SvgPath>;
+type SvgLabel = Label<SvgPath>;
+
+/// A path in the form of SVG path data, along with the bounds of all the
+/// points the path consists of.
+struct Path {
+    data: String,
+    bounds: Bounds,
+}
+
+/// An image encoded as a data URL that can directly be referenced by the
+/// document.
+struct Image {
+    data_url: String,
+}
+
+#[derive(Copy, Clone)]
+struct Bounds {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+}
+
+impl Bounds {
+    const EMPTY: Self = Self {
+        left: f32::INFINITY,
+        top: f32::INFINITY,
+        right: f32::NEG_INFINITY,
+        bottom: f32::NEG_INFINITY,
+    };
+
+    fn include(&mut self, x: f32, y: f32) {
+        self.left = self.left.min(x);
+        self.top = self.top.min(y);
+        self.right = self.right.max(x);
+        self.bottom = self.bottom.max(y);
+    }
+
+    fn union(&mut self, other: &Bounds) {
+        if other.left <= other.right {
+            self.include(other.left, other.top);
+            self.include(other.right, other.bottom);
+        }
+    }
+
+    fn top_bottom(&self) -> (f32, f32) {
+        if self.bottom < self.top {
+            (0.0, 0.0)
+        } else {
+            (self.top, self.bottom)
+        }
+    }
+
+    fn left_right(&self) -> (f32, f32) {
+        if self.right < self.left {
+            (0.0, 0.0)
+        } else {
+            (self.left, self.right)
+        }
+    }
+}
+
+struct SvgBuilder {
+    data: String,
+    bounds: Bounds,
+}
+
+impl resource::PathBuilder for SvgBuilder {
+    type Path = SvgPath;
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.bounds.include(x, y);
+        let _ = write!(self.data, "M{x} {y}");
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.bounds.include(x, y);
+        let _ = write!(self.data, "L{x} {y}");
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.bounds.include(x1, y1);
+        self.bounds.include(x, y);
+        let _ = write!(self.data, "Q{x1} {y1} {x} {y}");
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.bounds.include(x1, y1);
+        self.bounds.include(x2, y2);
+        self.bounds.include(x, y);
+        let _ = write!(self.data, "C{x1} {y1} {x2} {y2} {x} {y}");
+    }
+
+    fn close(&mut self) {
+        self.data.push('Z');
+    }
+
+    fn finish(self) -> Self::Path {
+        Arc::new(Path {
+            data: self.data,
+            bounds: self.bounds,
+        })
+    }
+}
+
+const fn path_builder() -> SvgBuilder {
+    SvgBuilder {
+        data: String::new(),
+        bounds: Bounds::EMPTY,
+    }
+}
+
+struct SvgAllocator {
+    text_engine: TextEngine,
+}
+
+impl ResourceAllocator for SvgAllocator {
+    type PathBuilder = SvgBuilder;
+    type Path = SvgPath;
+    type Image = SvgImage;
+    type Font = SvgFont;
+    type Label = SvgLabel;
+
+    fn path_builder(&mut self) -> Self::PathBuilder {
+        path_builder()
+    }
+
+    fn create_image(&mut self, data: &[u8]) -> Option<(Self::Image, f32)> {
+        #[cfg(feature = "image")]
+        {
+            // The image gets reencoded as a PNG, as the original file format
+            // may not be supported by the applications displaying the SVG.
+            let image = image::load_from_memory(data).ok()?;
+            let (width, height) = (image.width(), image.height());
+            let mut png = Vec::new();
+            image
+                .write_to(
+                    &mut std::io::Cursor::new(&mut png),
+                    image::ImageOutputFormat::Png,
+                )
+                .ok()?;
+            let data_url = format!(
+                "data:image/png;base64,{}",
+                base64::display::Base64Display::with_config(&png, base64::STANDARD),
+            );
+            Some((Arc::new(Image { data_url }), width as f32 / height as f32))
+        }
+        #[cfg(not(feature = "image"))]
+        {
+            let _ = data;
+            None
+        }
+    }
+
+    fn create_font(&mut self, font: Option<&settings::Font>, kind: FontKind) -> Self::Font {
+        self.text_engine.create_font(font, kind)
+    }
+
+    fn create_label(
+        &mut self,
+        text: &str,
+        font: &mut Self::Font,
+        max_width: Option<f32>,
+    ) -> Self::Label {
+        self.text_engine
+            .create_label(path_builder, text, font, max_width)
+    }
+
+    fn update_label(
+        &mut self,
+        label: &mut Self::Label,
+        text: &str,
+        font: &mut Self::Font,
+        max_width: Option<f32>,
+    ) {
+        self.text_engine
+            .update_label(path_builder, label, text, font, max_width)
+    }
+}
+
+/// The SVG renderer renders layouts into standalone SVG documents. All the
+/// texts are turned into paths, so the documents look the same regardless of
+/// the fonts that are installed on the system displaying them.
+pub struct Renderer {
+    allocator: SvgAllocator,
+    scene_manager: SceneManager<SvgPath, SvgImage, SvgFont, SvgLabel>,
+    document: String,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer {
+    /// Creates a new SVG renderer.
+    pub fn new() -> Self {
+        let mut allocator = SvgAllocator {
+            text_engine: TextEngine::new(),
+        };
+        let scene_manager = SceneManager::new(&mut allocator);
+        Self {
+            allocator,
+            scene_manager,
+            document: String::new(),
+        }
+    }
+
+    /// Renders the layout state provided into an SVG document with the chosen
+    /// dimensions. It may detect that the layout got resized. In that case it
+    /// returns the new ideal size. This is just a hint and can be ignored
+    /// entirely. The document is always rendered with the dimensions provided.
+    pub fn render(&mut self, state: &LayoutState, [width, height]: [f32; 2]) -> Option<(f32, f32)> {
+        let new_resolution =
+            self.scene_manager
+                .update_scene(&mut self.allocator, (width, height), state);
+
+        self.document.clear();
+        let _ = write_document(
+            &mut self.document,
+            self.scene_manager.scene(),
+            width,
+            height,
+        );
+
+        new_resolution
+    }
+
+    /// Accesses the SVG document that was rendered last.
+    pub fn document(&self) -> &str {
+        &self.document
+    }
+
+    /// Turns the whole renderer into the SVG document that was rendered last.
+    pub fn into_document(self) -> String {
+        self.document
+    }
+}
+
+fn write_document(
+    w: &mut String,
+    scene: &Scene<SvgPath, SvgImage, SvgLabel>,
+    width: f32,
+    height: f32,
+) -> fmt::Result {
+    writeln!(
+        w,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+    )?;
+
+    let mut gradients = 0;
+
+    if let Some(shader) = scene.background() {
+        let bounds = Bounds {
+            left: 0.0,
+            top: 0.0,
+            right: width,
+            bottom: height,
+        };
+        let fill = write_paint(w, &mut gradients, shader, &bounds)?;
+        writeln!(
+            w,
+            r#"<rect width="{width}" height="{height}" fill="{fill}"{}/>"#,
+            Opacity("fill-opacity", &fill),
+        )?;
+    }
+
+    for layer in [scene.bottom_layer(), scene.top_layer()] {
+        write_layer(w, &mut gradients, layer)?;
+    }
+
+    writeln!(w, "</svg>")
+}
+
+fn write_layer(
+    w: &mut String,
+    gradients: &mut usize,
+    layer: &[Entity<SvgPath, SvgImage, SvgLabel>],
+) -> fmt::Result {
+    for entity in layer {
+        match entity {
+            Entity::FillPath(path, shader, transform) => {
+                // The gradient is specified in the user space of the path,
+                // which already has the transform applied.
+                let fill = write_paint(w, gradients, shader, &path.bounds)?;
+                writeln!(
+                    w,
+                    r#"<path transform="{}" d="{}" fill="{fill}"{}/>"#,
+                    Matrix(transform),
+                    path.data,
+                    Opacity("fill-opacity", &fill),
+                )?;
+            }
+            Entity::StrokePath(path, stroke_width, color, transform) => {
+                let stroke = Paint::Color(*color);
+                writeln!(
+                    w,
+                    r#"<path transform="{}" d="{}" fill="none" stroke="{stroke}" stroke-width="{stroke_width}"{}/>"#,
+                    Matrix(transform),
+                    path.data,
+                    Opacity("stroke-opacity", &stroke),
+                )?;
+            }
+            Entity::Image(image, transform) => {
+                writeln!(
+                    w,
+                    r#"<image transform="{}" width="1" height="1" preserveAspectRatio="none" href="{}"/>"#,
+                    Matrix(transform),
+                    image.data_url,
+                )?;
+            }
+            Entity::Label(label, shader, transform) => {
+                let label = label.read().unwrap();
+                let transform = transform.pre_scale(label.scale(), label.scale());
+
+                // Each glyph is painted in its own user space, so the gradient
+                // spans the bounds of the glyphs without their positions
+                // applied, just like it does in the software renderer.
+                let mut bounds = Bounds::EMPTY;
+                for glyph in label.glyphs() {
+                    bounds.union(&glyph.path.bounds);
+                }
+                let fill = write_paint(w, gradients, shader, &bounds)?;
+
+                writeln!(w, r#"<g fill="{fill}"{}>"#, Opacity("fill-opacity", &fill))?;
+                for glyph in label.glyphs() {
+                    if glyph.path.data.is_empty() {
+                        continue;
+                    }
+                    let transform = transform.pre_translate(glyph.x, glyph.y);
+                    write!(
+                        w,
+                        r#"<path transform="{}" d="{}""#,
+                        Matrix(&transform),
+                        glyph.path.data,
+                    )?;
+                    if let Some(color) = glyph.color {
+                        let fill = Paint::Color(color);
+                        write!(w, r#" fill="{fill}"{}"#, Opacity("fill-opacity", &fill))?;
+                    }
+                    writeln!(w, "/>")?;
+                }
+                writeln!(w, "</g>")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes the definition of the gradient if the shader needs one and returns
+/// the paint that refers to it.
+fn write_paint(
+    w: &mut String,
+    gradients: &mut usize,
+    shader: &FillShader,
+    bounds: &Bounds,
+) -> Result<Paint, fmt::Error> {
+    let (start, end, [x1, y1, x2, y2]) = match *shader {
+        FillShader::SolidColor(color) => return Ok(Paint::Color(color)),
+        FillShader::VerticalGradient(top, bottom) => {
+            let (top_y, bottom_y) = bounds.top_bottom();
+            (top, bottom, [0.0, top_y, 0.0, bottom_y])
+        }
+        FillShader::HorizontalGradient(left, right) => {
+            let (left_x, right_x) = bounds.left_right();
+            (left, right, [left_x, 0.0, right_x, 0.0])
+        }
+    };
+
+    let id = *gradients;
+    *gradients += 1;
+
+    writeln!(
+        w,
+        r#"<defs><linearGradient id="g{id}" gradientUnits="userSpaceOnUse" x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}">"#,
+    )?;
+    for (offset, color) in [(0, start), (1, end)] {
+        let stop = Paint::Color(color);
+        writeln!(
+            w,
+            r#"<stop offset="{offset}" stop-color="{stop}"{}/>"#,
+            Opacity("stop-opacity", &stop),
+        )?;
+    }
+    writeln!(w, "</linearGradient></defs>")?;
+
+    Ok(Paint::Gradient(id))
+}
+
+/// A paint as it can be referred to in a `fill` or `stroke` attribute.
+enum Paint {
+    Color(Rgba),
+    Gradient(usize),
+}
+
+impl fmt::Display for Paint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Paint::Color([r, g, b, _]) => {
+                let [r, g, b] = [r, g, b].map(|c| (c.clamp(0.0, 1.0) * 255.0 + 0.5) as u8);
+                write!(f, "#{r:02x}{g:02x}{b:02x}")
+            }
+            Paint::Gradient(id) => write!(f, "url(#g{id})"),
+        }
+    }
+}
+
+/// Writes the opacity attribute of a paint if it isn't fully opaque.
+struct Opacity<'a>(&'static str, &'a Paint);
+
+impl fmt::Display for Opacity<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.1 {
+            Paint::Color([.., a]) if *a < 1.0 => write!(f, r#" {}="{a}""#, self.0),
+            _ => Ok(()),
+        }
+    }
+}
+
+struct Matrix<'a>(&'a Transform);
+
+impl fmt::Display for Matrix<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Transform {
+            scale_x,
+            scale_y,
+            x,
+            y,
+        } = self.0;
+        write!(f, "matrix({scale_x} 0 0 {scale_y} {x} {y})")
+    }
+}
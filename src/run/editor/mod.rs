@@ -6,12 +6,12 @@
 
 use super::{ComparisonError, ComparisonResult};
 use crate::{
-    comparison,
+    comparison::{self, ComparisonGenerator, GeneratorSettings, PastAttempt},
     platform::prelude::*,
     settings::{CachedImageId, Image},
     timing::ParseError as ParseTimeSpanError,
     util::PopulateString,
-    Run, Segment, Time, TimeSpan, TimingMethod,
+    DateTime, Run, Segment, Time, TimeSpan, TimingMethod,
 };
 use core::{mem::swap, num::ParseIntError};
 use snafu::{OptionExt, ResultExt};
@@ -67,6 +67,22 @@ pub enum RenameError {
     },
 }
 
+/// Error type for a failed addition of an attempt comparison.
+#[derive(PartialEq, Eq, Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum AttemptComparisonError {
+    /// There is no attempt in the Attempt History that is known to have been
+    /// started.
+    NoAttemptFound,
+    /// There is no attempt with the index provided in the Attempt History.
+    UnknownAttempt,
+    /// Name was invalid.
+    InvalidAttemptName {
+        /// The underlying error.
+        source: ComparisonError,
+    },
+}
+
 /// The Run Editor allows modifying Runs while ensuring that all the different
 /// invariants of the Run objects are upheld no matter what kind of operations
 /// are being applied to the Run. It provides the current state of the editor as
@@ -431,7 +447,12 @@ impl Editor {
             .personal_best_split_time();
 
         if pb_split_time != self.previous_personal_best_time {
+            // The Personal Best is a different run now, so the variables
+            // recorded for the previous one don't apply anymore.
             self.run.clear_run_id();
+            for segment in self.run.segments_mut() {
+                segment.personal_best_variables_mut().clear();
+            }
             self.previous_personal_best_time = pb_split_time;
         }
 
@@ -775,8 +796,62 @@ impl Editor {
         Ok(())
     }
 
-    /// Removes the chosen custom comparison. You can't remove a Comparison
-    /// Generator's Comparison or the Personal Best.
+    /// Adds a comparison that reconstructs the splits of the attempt with the
+    /// given index in the Attempt History, so that the runner can race against
+    /// any previous attempt. The comparison is named after the index of the
+    /// attempt and is kept up to date by a Comparison Generator that is stored
+    /// in the splits file. The comparison can't be added if there is no attempt
+    /// with the index provided or if the comparison already exists.
+    pub fn add_attempt_comparison(
+        &mut self,
+        attempt_index: i32,
+    ) -> Result<(), AttemptComparisonError> {
+        if !self
+            .run
+            .attempt_history()
+            .iter()
+            .any(|a| a.index() == attempt_index)
+        {
+            return Err(AttemptComparisonError::UnknownAttempt);
+        }
+        let generator = comparison::PastAttempt::new(attempt_index);
+        self.run
+            .validate_comparison_name(generator.name())
+            .context(InvalidAttemptName)?;
+        self.run
+            .comparison_generators_mut()
+            .push(Box::new(generator));
+        self.run.regenerate_comparisons();
+        self.raise_run_edited();
+        Ok(())
+    }
+
+    /// Adds a comparison that reconstructs the splits of the attempt that was
+    /// started closest to the point in time provided. This allows choosing the
+    /// attempt by its date rather than its index. Otherwise this works just
+    /// like [`add_attempt_comparison`](Self::add_attempt_comparison). The
+    /// comparison can't be added if there is no attempt that is known to have
+    /// been started or if the comparison already exists.
+    pub fn add_attempt_comparison_by_date(
+        &mut self,
+        date_time: DateTime,
+    ) -> Result<(), AttemptComparisonError> {
+        let attempt_index = self
+            .run
+            .attempt_history()
+            .iter()
+            .filter_map(|a| Some((a.index(), a.started()?.time)))
+            .min_by_key(|&(_, started)| (started - date_time).abs())
+            .context(NoAttemptFound)?
+            .0;
+        self.add_attempt_comparison(attempt_index)
+    }
+
+    /// Removes the chosen custom comparison. Comparisons of Comparison
+    /// Generators that were added to the Run, like the ones added by
+    /// [`add_attempt_comparison`](Self::add_attempt_comparison), are removed
+    /// as well. You can't remove the comparison of a default Comparison
+    /// Generator or the Personal Best.
     pub fn remove_comparison(&mut self, comparison: &str) {
         if comparison == comparison::personal_best::NAME {
             return;
@@ -786,6 +861,10 @@ impl Editor {
             .custom_comparisons_mut()
             .retain(|c| c != comparison);
 
+        self.run
+            .comparison_generators_mut()
+            .retain(|g| g.settings().is_none() || g.name() != comparison);
+
         if self.run.comparisons().any(|c| c == comparison) {
             return;
         }
@@ -808,16 +887,27 @@ impl Editor {
             .validate_comparison_name(new)
             .context(InvalidName)?;
 
+        if let Some(comparison_name) = self
+            .run
+            .custom_comparisons_mut()
+            .iter_mut()
+            .find(|c| *c == old)
         {
-            let comparison_name = self
+            comparison_name.clear();
+            comparison_name.push_str(new);
+        } else {
+            let generator = self
                 .run
-                .custom_comparisons_mut()
+                .comparison_generators_mut()
                 .iter_mut()
-                .find(|c| *c == old)
+                .find(|g| g.name() == old)
                 .context(OldNameNotFound)?;
 
-            comparison_name.clear();
-            comparison_name.push_str(new);
+            let attempt_index = match generator.settings() {
+                Some(GeneratorSettings::PastAttempt(past_attempt)) => past_attempt.attempt_index(),
+                _ => return Err(RenameError::OldNameNotFound),
+            };
+            *generator = Box::new(PastAttempt::with_name(attempt_index, new));
         }
 
         for segment in self.run.segments_mut() {
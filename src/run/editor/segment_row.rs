@@ -56,10 +56,9 @@ impl<'a> SegmentRow<'a> {
     /// Sets the split time of the segment for the active timing method.
     pub fn set_split_time(&mut self, time: Option<TimeSpan>) {
         let method = self.editor.selected_method;
-        self.editor
-            .run
-            .segment_mut(self.index)
-            .personal_best_split_time_mut()[method] = time;
+        let segment = self.editor.run.segment_mut(self.index);
+        segment.personal_best_split_time_mut()[method] = time;
+        segment.personal_best_variables_mut().clear();
         self.editor.times_modified();
         self.editor.fix();
     }
@@ -80,6 +79,11 @@ impl<'a> SegmentRow<'a> {
     pub fn set_segment_time(&mut self, time: Option<TimeSpan>) {
         self.editor.segment_times[self.index] = time;
         self.editor.fix_splits_from_segments();
+        self.editor
+            .run
+            .segment_mut(self.index)
+            .personal_best_variables_mut()
+            .clear();
         self.editor.times_modified();
         self.editor.fix();
     }
@@ -1,6 +1,6 @@
 use super::{Editor, SegmentRow, TimingMethod};
 use crate::{
-    comparison::personal_best,
+    comparison::{personal_best, GeneratorSettings},
     platform::prelude::*,
     run::RunMetadata,
     settings::{CachedImageId, ImageData},
@@ -33,6 +33,10 @@ pub struct State {
     pub segments: Vec<Segment>,
     /// The names of all the custom comparisons that exist for this Run.
     pub comparison_names: Vec<String>,
+    /// The names of all the comparisons that race against a past attempt.
+    /// Their times are generated from the Segment History, so they can't be
+    /// edited, but they can be renamed and removed like custom comparisons.
+    pub attempt_comparison_names: Vec<String>,
     /// Describes which actions are currently available.
     pub buttons: Buttons,
     /// Additional metadata of this Run, like the platform and region of the
@@ -131,6 +135,13 @@ impl Editor {
             .filter(|&n| n != personal_best::NAME)
             .cloned()
             .collect::<Vec<_>>();
+        let attempt_comparison_names = self
+            .run
+            .comparison_generators()
+            .iter()
+            .filter(|g| matches!(g.settings(), Some(GeneratorSettings::PastAttempt(_))))
+            .map(|g| g.name().to_owned())
+            .collect();
 
         let buttons = Buttons {
             can_remove: self.can_remove_segments(),
@@ -188,6 +199,7 @@ impl Editor {
             timing_method,
             segments,
             comparison_names,
+            attempt_comparison_names,
             buttons,
             metadata: self.run.metadata().clone(),
         }
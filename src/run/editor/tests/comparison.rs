@@ -1,5 +1,6 @@
+use crate::run::editor::AttemptComparisonError;
 use crate::run::{ComparisonError, Editor, RenameError};
-use crate::{Run, Segment};
+use crate::{AtomicDateTime, DateTime, Run, Segment, Time};
 
 #[test]
 fn adding_a_new_comparison_works() {
@@ -107,3 +108,100 @@ fn reordering_works() {
         ["D", "A", "C", "B"]
     );
 }
+
+fn editor_with_attempt(attempt_index: i32) -> Editor {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    run.add_attempt_with_index(Time::default(), attempt_index, None, None, None);
+    Editor::new(run).unwrap()
+}
+
+#[test]
+fn adding_an_attempt_comparison_works() {
+    let mut editor = editor_with_attempt(3);
+    assert_eq!(editor.add_attempt_comparison(3), Ok(()));
+    assert!(editor.run().comparisons().any(|c| c == "Attempt #3"));
+    assert_eq!(
+        editor.add_attempt_comparison(3),
+        Err(AttemptComparisonError::InvalidAttemptName {
+            source: ComparisonError::DuplicateName
+        })
+    );
+}
+
+#[test]
+fn attempt_comparisons_need_an_existing_attempt() {
+    let mut editor = editor_with_attempt(3);
+    assert_eq!(
+        editor.add_attempt_comparison(4),
+        Err(AttemptComparisonError::UnknownAttempt)
+    );
+    assert!(!editor.run().comparisons().any(|c| c == "Attempt #4"));
+}
+
+#[test]
+fn removing_an_attempt_comparison_removes_its_generator() {
+    let mut editor = editor_with_attempt(3);
+    editor.add_attempt_comparison(3).unwrap();
+    editor.remove_comparison("Attempt #3");
+    assert!(!editor.run().comparisons().any(|c| c == "Attempt #3"));
+    editor.remove_comparison("Best Segments");
+    assert!(editor.run().comparisons().any(|c| c == "Best Segments"));
+}
+
+#[test]
+fn attempt_comparisons_are_listed_and_can_be_renamed() {
+    let mut editor = editor_with_attempt(3);
+    editor.add_comparison("Custom").unwrap();
+    editor.add_attempt_comparison(3).unwrap();
+
+    let state = editor.state();
+    assert_eq!(state.comparison_names, ["Custom"]);
+    assert_eq!(state.attempt_comparison_names, ["Attempt #3"]);
+
+    assert_eq!(editor.rename_comparison("Attempt #3", "Ghost"), Ok(()));
+    assert!(editor.run().comparisons().any(|c| c == "Ghost"));
+    assert!(!editor.run().comparisons().any(|c| c == "Attempt #3"));
+    assert_eq!(editor.state().attempt_comparison_names, ["Ghost"]);
+    assert_eq!(
+        editor.rename_comparison("Best Segments", "Golds"),
+        Err(RenameError::OldNameNotFound)
+    );
+
+    editor.remove_comparison("Ghost");
+    assert!(editor.state().attempt_comparison_names.is_empty());
+}
+
+#[test]
+fn attempt_comparisons_can_be_chosen_by_date() {
+    let date = |timestamp| {
+        Some(AtomicDateTime::new(
+            DateTime::from_unix_timestamp(timestamp).unwrap(),
+            false,
+        ))
+    };
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    run.add_attempt_with_index(Time::default(), 1, None, None, None);
+    run.add_attempt_with_index(Time::default(), 2, date(1_000_000), None, None);
+    run.add_attempt_with_index(Time::default(), 3, date(2_000_000), None, None);
+    let mut editor = Editor::new(run).unwrap();
+
+    let date_time = DateTime::from_unix_timestamp(1_900_000).unwrap();
+    assert_eq!(editor.add_attempt_comparison_by_date(date_time), Ok(()));
+    assert_eq!(editor.state().attempt_comparison_names, ["Attempt #3"]);
+    assert!(matches!(
+        editor.add_attempt_comparison_by_date(date_time),
+        Err(AttemptComparisonError::InvalidAttemptName { .. })
+    ));
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    run.add_attempt_with_index(Time::default(), 1, None, None, None);
+    let mut editor = Editor::new(run).unwrap();
+    assert_eq!(
+        editor.add_attempt_comparison_by_date(date_time),
+        Err(AttemptComparisonError::NoAttemptFound)
+    );
+}
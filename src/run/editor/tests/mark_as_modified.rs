@@ -1,5 +1,5 @@
 use super::super::Editor;
-use crate::{Run, Segment, TimeSpan, TimingMethod};
+use crate::{Run, Segment, Time, TimeSpan, TimingMethod};
 
 fn base() -> Editor {
     let mut run = Run::new();
@@ -267,6 +267,16 @@ fn when_importing_comparison() {
     assert!(editor.run().has_been_modified());
 }
 
+#[test]
+fn when_adding_attempt_comparison() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new(""));
+    run.add_attempt_with_index(Time::default(), 1, None, None, None);
+    let mut editor = Editor::new(run).unwrap();
+    editor.add_attempt_comparison(1).unwrap();
+    assert!(editor.run().has_been_modified());
+}
+
 #[test]
 fn when_removing_comparison() {
     let mut editor = base();
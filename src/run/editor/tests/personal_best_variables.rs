@@ -0,0 +1,85 @@
+use super::super::Editor;
+use crate::{Run, Segment};
+
+fn base() -> Editor {
+    let mut run = Run::new();
+    run.push_segment(Segment::new(""));
+    run.push_segment(Segment::new(""));
+
+    let mut editor = Editor::new(run).unwrap();
+
+    editor.select_only(0);
+    editor
+        .active_segment()
+        .parse_and_set_split_time("1")
+        .unwrap();
+    editor.select_only(1);
+    editor
+        .active_segment()
+        .parse_and_set_split_time("2")
+        .unwrap();
+
+    let mut run = editor.close();
+    for (segment, deaths) in run.segments_mut().iter_mut().zip(["1", "3"]) {
+        segment
+            .personal_best_variables_mut()
+            .insert("Deaths".into(), deaths.into());
+    }
+
+    Editor::new(run).unwrap()
+}
+
+fn deaths(editor: &Editor, index: usize) -> Option<&str> {
+    editor
+        .run()
+        .segment(index)
+        .personal_best_variables()
+        .get("Deaths")
+        .map(String::as_str)
+}
+
+#[test]
+fn are_cleared_when_changing_the_final_time() {
+    let mut editor = base();
+    editor.select_only(1);
+    editor
+        .active_segment()
+        .parse_and_set_split_time("3")
+        .unwrap();
+    assert_eq!(deaths(&editor, 0), None);
+    assert_eq!(deaths(&editor, 1), None);
+}
+
+#[test]
+fn are_cleared_for_the_changed_split_time() {
+    let mut editor = base();
+    editor.select_only(0);
+    editor
+        .active_segment()
+        .parse_and_set_split_time("1.5")
+        .unwrap();
+    assert_eq!(deaths(&editor, 0), None);
+    assert_eq!(deaths(&editor, 1), Some("3"));
+}
+
+#[test]
+fn are_cleared_for_the_changed_segment_time() {
+    let mut editor = base();
+    editor.select_only(0);
+    editor
+        .active_segment()
+        .parse_and_set_segment_time("0.5")
+        .unwrap();
+    assert_eq!(deaths(&editor, 0), None);
+}
+
+#[test]
+fn are_kept_when_changing_other_times() {
+    let mut editor = base();
+    editor
+        .active_segment()
+        .parse_and_set_best_segment_time("0.5")
+        .unwrap();
+    assert_eq!(deaths(&editor, 0), Some("1"));
+    assert_eq!(deaths(&editor, 1), Some("3"));
+}
@@ -42,9 +42,15 @@ use crate::{
     AtomicDateTime, Time, TimeSpan, TimingMethod,
 };
 use alloc::borrow::Cow;
-use core::{cmp::max, fmt};
+use core::{
+    cmp::max,
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use hashbrown::HashSet;
 
+static LAST_HISTORY_ID: AtomicUsize = AtomicUsize::new(0);
+
 /// A Run stores the split times for a specific game and category of a runner.
 ///
 /// # Examples
@@ -78,6 +84,32 @@ pub struct Run {
     custom_comparisons: Vec<String>,
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: String,
+    history_id: HistoryId,
+}
+
+/// Identifies the state of the Segment Histories and the Attempt History. It
+/// is not part of comparing runs, as it only tracks changes.
+#[derive(Copy, Clone, Debug)]
+struct HistoryId(usize);
+
+impl HistoryId {
+    fn new() -> Self {
+        cfg_if::cfg_if! {
+            if #[cfg(target_has_atomic = "ptr")] {
+                Self(LAST_HISTORY_ID.fetch_add(1, Ordering::Relaxed))
+            } else {
+                let id = LAST_HISTORY_ID.load(Ordering::SeqCst) + 1;
+                LAST_HISTORY_ID.store(id, Ordering::SeqCst);
+                Self(id)
+            }
+        }
+    }
+}
+
+impl PartialEq for HistoryId {
+    fn eq(&self, _: &HistoryId) -> bool {
+        true
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -123,6 +155,7 @@ impl Run {
             custom_comparisons: vec![personal_best::NAME.to_string()],
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: String::new(),
+            history_id: HistoryId::new(),
         }
     }
 
@@ -234,12 +267,14 @@ impl Run {
     /// Grants mutable access to the Segments of this Run object.
     #[inline]
     pub fn segments_mut(&mut self) -> &mut Vec<Segment> {
+        self.history_changed();
         &mut self.segments
     }
 
     /// Pushes the segment provided to the end of the list of segments of this Run.
     #[inline]
     pub fn push_segment(&mut self, segment: Segment) {
+        self.history_changed();
         self.segments.push(segment);
     }
 
@@ -260,6 +295,7 @@ impl Run {
     /// Panics if the index is out of bounds.
     #[inline]
     pub fn segment_mut(&mut self, index: usize) -> &mut Segment {
+        self.history_changed();
         &mut self.segments[index]
     }
 
@@ -272,6 +308,20 @@ impl Run {
         &self.attempt_history
     }
 
+    /// Accesses an ID that identifies the current state of the Attempt History
+    /// and the Segment Histories of this Run. The ID changes whenever either of
+    /// them may have been modified. IDs are unique across different runs, so
+    /// this can be used for caching information that is calculated from the
+    /// histories.
+    #[inline]
+    pub const fn history_id(&self) -> usize {
+        self.history_id.0
+    }
+
+    fn history_changed(&mut self) {
+        self.history_id = HistoryId::new();
+    }
+
     /// Accesses the custom comparisons that are stored in this Run. This
     /// includes `Personal Best` but excludes all the other Comparison
     /// Generators.
@@ -400,6 +450,7 @@ impl Run {
         pause_time: Option<TimeSpan>,
     ) {
         let attempt = Attempt::new(index, time, started, ended, pause_time);
+        self.history_changed();
         self.attempt_history.push(attempt);
     }
 
@@ -504,6 +555,7 @@ impl Run {
     /// comparison times and history, removing duplicates in the segment
     /// histories and removing empty times.
     pub fn fix_splits(&mut self) {
+        self.history_changed();
         for method in TimingMethod::all() {
             self.fix_comparison_times_and_history(method);
         }
@@ -514,6 +566,7 @@ impl Run {
 
     /// Clears out the Attempt History and the Segment Histories of all the segments.
     pub fn clear_history(&mut self) {
+        self.history_changed();
         self.attempt_history.clear();
         for segment in &mut self.segments {
             segment.segment_history_mut().clear();
@@ -529,6 +582,7 @@ impl Run {
         self.custom_comparisons.retain(|c| c == personal_best::NAME);
         for segment in &mut self.segments {
             segment.comparisons_mut().clear();
+            segment.personal_best_variables_mut().clear();
             segment.set_best_segment_time(Time::default());
         }
         self.attempt_count = 0;
@@ -685,6 +739,7 @@ impl Run {
     ///
     /// This panics if the segment index provided is out of bounds.
     pub fn import_best_segment(&mut self, segment_index: usize) {
+        self.history_changed();
         let best_segment_time = self.segments[segment_index].best_segment_time();
         if best_segment_time.real_time.is_some() || best_segment_time.game_time.is_some() {
             // We can unwrap here because due to the fact that we can access the
@@ -703,6 +758,7 @@ impl Run {
     ///
     /// This panics if there is no attempt in the Attempt History.
     pub fn update_segment_history(&mut self, current_split_index: usize) {
+        self.history_changed();
         let mut last_split_time = Time::zero();
 
         let segments = self.segments.iter_mut().take(current_split_index);
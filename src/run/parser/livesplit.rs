@@ -2,6 +2,10 @@
 
 use super::super::ComparisonError;
 use crate::{
+    comparison::{
+        rolling::{RollingComparison, Statistic, Window},
+        GeneratorSettings, LatestRunsAverage, PastAttempt, PercentileSegments, RecentBestSegments,
+    },
     platform::{path::PathBuf, prelude::*},
     util::xml::{
         helper::{
@@ -9,7 +13,7 @@ use crate::{
             parse_attributes, parse_base, parse_children, reencode_children, text,
             text_as_escaped_string_err, text_parsed, Error as XmlError,
         },
-        Reader,
+        Attributes, Reader,
     },
     AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan,
 };
@@ -50,6 +54,8 @@ pub enum Error {
     },
     /// Failed to parse a boolean.
     ParseBool,
+    /// Failed to parse the settings of a comparison generator.
+    ParseGeneratorSettings,
 }
 
 impl From<XmlError> for Error {
@@ -336,6 +342,15 @@ fn parse_segment(
                 time_old(reader, |t| segment.segment_history_mut().insert(index, t))
             }
         }),
+        "PersonalBestVariables" => parse_children(reader, |reader, _, attributes| {
+            let mut name = String::new();
+            type_hint(attribute(attributes, "name", |t| name = t.into_owned()))?;
+            text(reader, |t| {
+                segment
+                    .personal_best_variables_mut()
+                    .insert(name, t.into_owned());
+            })
+        }),
         _ => end_tag(reader),
     })?;
 
@@ -420,6 +435,98 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
     }
 }
 
+fn parse_rolling_comparison(attributes: Attributes<'_>) -> Result<RollingComparison> {
+    let mut name = Cow::Borrowed("");
+    let (mut window, mut attempts, mut since) = (None, None, None);
+    let (mut statistic, mut value) = (None, None);
+    let mut completed_runs_only = false;
+
+    type_hint(parse_attributes(attributes, |k, v| {
+        match k {
+            "name" => name = v.unescape_cow(),
+            "window" => window = Some(v.escaped()),
+            "attempts" => attempts = Some(v.escaped().parse()?),
+            "since" => since = Some(parse_date_time(v.escaped())?),
+            "completedRunsOnly" => completed_runs_only = parse_bool(v.escaped())?,
+            "statistic" => statistic = Some(v.escaped()),
+            "value" => value = Some(v.escaped().parse()?),
+            _ => {}
+        }
+        Ok(true)
+    }))?;
+
+    let window = match window {
+        Some("AllAttempts") => Window::AllAttempts,
+        Some("LastAttempts") => {
+            Window::LastAttempts(attempts.ok_or(Error::ParseGeneratorSettings)?)
+        }
+        Some("Since") => Window::Since(since.ok_or(Error::ParseGeneratorSettings)?),
+        _ => return Err(Error::ParseGeneratorSettings),
+    };
+
+    let statistic = match statistic {
+        Some("Mean") => Statistic::Mean,
+        Some("Median") => Statistic::Median,
+        Some("Percentile") => Statistic::Percentile(value.ok_or(Error::ParseGeneratorSettings)?),
+        Some("TrimmedMean") => Statistic::TrimmedMean(value.ok_or(Error::ParseGeneratorSettings)?),
+        _ => return Err(Error::ParseGeneratorSettings),
+    };
+
+    Ok(RollingComparison::new(name, window, statistic)
+        .with_completed_runs_only(completed_runs_only))
+}
+
+fn parse_comparison_generators(reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
+    parse_children(reader, |reader, tag, attributes| {
+        let settings = match tag.name() {
+            "Rolling" => GeneratorSettings::Rolling(parse_rolling_comparison(attributes)?),
+            "Percentile" => {
+                let mut percentile = 0.0;
+                type_hint(attribute_escaped_err(attributes, "percentile", |t| {
+                    percentile = t.parse()?;
+                    Ok(())
+                }))?;
+                GeneratorSettings::Percentile(PercentileSegments::new(percentile))
+            }
+            "RecentBestSegments" => {
+                let mut attempts = 0;
+                type_hint(attribute_escaped_err(attributes, "attempts", |t| {
+                    attempts = t.parse()?;
+                    Ok(())
+                }))?;
+                GeneratorSettings::RecentBestSegments(RecentBestSegments::new(attempts))
+            }
+            "LatestRunsAverage" => {
+                let mut runs = 0;
+                type_hint(attribute_escaped_err(attributes, "runs", |t| {
+                    runs = t.parse()?;
+                    Ok(())
+                }))?;
+                GeneratorSettings::LatestRunsAverage(LatestRunsAverage::new(runs))
+            }
+            "PastAttempt" => {
+                let (mut name, mut index) = (Cow::Borrowed(""), 0);
+                type_hint(parse_attributes(attributes, |k, v| {
+                    match k {
+                        "name" => name = v.unescape_cow(),
+                        "id" => index = v.escaped().parse()?,
+                        _ => {}
+                    }
+                    Ok(true)
+                }))?;
+                GeneratorSettings::PastAttempt(PastAttempt::with_name(index, name))
+            }
+            _ => return end_tag(reader),
+        };
+
+        let generator = settings.into_generator();
+        run.validate_comparison_name(generator.name())?;
+        run.comparison_generators_mut().push(generator);
+
+        end_tag(reader)
+    })
+}
+
 /// Attempts to parse a LiveSplit splits file. In addition to the source to
 /// parse, you can provide a path to the splits file, which helps saving the
 /// splits file again later.
@@ -475,6 +582,7 @@ pub fn parse(source: &str, path: Option<PathBuf>) -> Result<Run> {
                     }
                 })
             }
+            "ComparisonGenerators" => parse_comparison_generators(reader, &mut run),
             "AutoSplitterSettings" => {
                 let settings = run.auto_splitter_settings_mut();
                 reencode_children(reader, settings).map_err(Into::into)
@@ -25,6 +25,10 @@
 //! ```
 
 use crate::{
+    comparison::{
+        rolling::{Statistic, Window},
+        ComparisonGenerator, GeneratorSettings,
+    },
     platform::prelude::*,
     settings::Image,
     timing::formatter::{Complete, TimeFormatter},
@@ -133,6 +137,56 @@ fn time<W: fmt::Write>(writer: AttributeWriter<'_, W>, time: Time) -> fmt::Resul
     }
 }
 
+fn generator_settings<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    settings: &GeneratorSettings,
+) -> fmt::Result {
+    match settings {
+        GeneratorSettings::Rolling(generator) => writer.tag("Rolling", |mut tag| {
+            tag.attribute("name", generator.name())?;
+            match generator.window() {
+                Window::AllAttempts => tag.attribute("window", Text::new_escaped("AllAttempts"))?,
+                Window::LastAttempts(count) => {
+                    tag.attribute("window", Text::new_escaped("LastAttempts"))?;
+                    tag.attribute("attempts", DisplayValue(count))?;
+                }
+                Window::Since(since) => {
+                    tag.attribute("window", Text::new_escaped("Since"))?;
+                    date(&mut tag, "since", since)?;
+                }
+            }
+            tag.attribute("completedRunsOnly", bool(generator.completed_runs_only()))?;
+            match generator.statistic() {
+                Statistic::Mean => tag.attribute("statistic", Text::new_escaped("Mean")),
+                Statistic::Median => tag.attribute("statistic", Text::new_escaped("Median")),
+                Statistic::Percentile(percentile) => {
+                    tag.attribute("statistic", Text::new_escaped("Percentile"))?;
+                    tag.attribute("value", DisplayValue(percentile))
+                }
+                Statistic::TrimmedMean(fraction) => {
+                    tag.attribute("statistic", Text::new_escaped("TrimmedMean"))?;
+                    tag.attribute("value", DisplayValue(fraction))
+                }
+            }
+        }),
+        GeneratorSettings::Percentile(generator) => writer.tag("Percentile", |mut tag| {
+            tag.attribute("percentile", DisplayValue(generator.percentile()))
+        }),
+        GeneratorSettings::RecentBestSegments(generator) => writer
+            .tag("RecentBestSegments", |mut tag| {
+                tag.attribute("attempts", DisplayValue(generator.attempts()))
+            }),
+        GeneratorSettings::LatestRunsAverage(generator) => writer
+            .tag("LatestRunsAverage", |mut tag| {
+                tag.attribute("runs", DisplayValue(generator.runs()))
+            }),
+        GeneratorSettings::PastAttempt(generator) => writer.tag("PastAttempt", |mut tag| {
+            tag.attribute("name", generator.name())?;
+            tag.attribute("id", DisplayValue(generator.attempt_index()))
+        }),
+    }
+}
+
 /// Wraps a type implementing `io::Write` to be used as a type implementing
 /// `fmt::Write` in order to write to it.
 #[cfg(feature = "std")]
@@ -285,10 +339,46 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                             time(tag, history_time)
                         })
                     },
-                )
+                )?;
+
+                let variables = segment.personal_best_variables();
+                if !variables.is_empty() {
+                    // Sorted to keep the output stable across saves.
+                    let mut variables: Vec<_> = variables.iter().collect();
+                    variables.sort_unstable();
+                    scoped_iter(
+                        writer,
+                        "PersonalBestVariables",
+                        variables,
+                        |writer, (name, value)| {
+                            writer.tag_with_text_content(
+                                "Variable",
+                                [("name", name.as_str())],
+                                value.as_str(),
+                            )
+                        },
+                    )?;
+                }
+
+                Ok(())
             })
         })?;
 
+        let mut generators = run
+            .comparison_generators()
+            .iter()
+            .filter_map(|generator| generator.settings())
+            .peekable();
+
+        if generators.peek().is_some() {
+            scoped_iter(
+                writer,
+                "ComparisonGenerators",
+                generators,
+                |writer, settings| generator_settings(writer, &settings),
+            )?;
+        }
+
         writer.tag_with_text_content(
             "AutoSplitterSettings",
             NO_ATTRIBUTES,
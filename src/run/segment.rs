@@ -29,6 +29,7 @@ pub struct Segment {
     segment_history: SegmentHistory,
     comparisons: Comparisons,
     variables: HashMap<String, String>,
+    personal_best_variables: HashMap<String, String>,
 }
 
 impl Segment {
@@ -191,6 +192,18 @@ impl Segment {
         &mut self.variables
     }
 
+    /// Accesses the values the variables had when this segment was split in
+    /// the Personal Best.
+    pub const fn personal_best_variables(&self) -> &HashMap<String, String> {
+        &self.personal_best_variables
+    }
+
+    /// Grants mutable access to the values the variables had when this segment
+    /// was split in the Personal Best.
+    pub fn personal_best_variables_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.personal_best_variables
+    }
+
     /// Clears the variables of the current attempt.
     pub fn clear_variables(&mut self) {
         self.variables.clear();
@@ -0,0 +1,33 @@
+use crate::{Run, Segment, Time};
+
+#[test]
+fn changes_whenever_the_histories_may_change() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let id = run.history_id();
+    run.set_game_name("Game");
+    assert_eq!(run.history_id(), id);
+
+    run.add_attempt(Time::new(), None, None, None);
+    let added = run.history_id();
+    assert_ne!(added, id);
+
+    run.segment_mut(0)
+        .segment_history_mut()
+        .insert(1, Time::new());
+    assert_ne!(run.history_id(), added);
+}
+
+#[test]
+fn is_unique_across_runs() {
+    let mut first = Run::new();
+    let mut second = Run::new();
+    first.push_segment(Segment::new("A"));
+    second.push_segment(Segment::new("A"));
+    assert_ne!(first.history_id(), second.history_id());
+    assert!(first == second);
+
+    let clone = first.clone();
+    assert_eq!(clone.history_id(), first.history_id());
+}
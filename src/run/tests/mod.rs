@@ -2,4 +2,5 @@ mod comparison;
 mod empty_run;
 mod extended_category_name;
 mod fixing;
+mod history_id;
 mod metadata;
@@ -52,18 +52,25 @@ impl Serialize for ImageData {
     where
         S: Serializer,
     {
-        if serializer.is_human_readable() {
-            if !self.0.is_empty() {
-                serializer.collect_str(&format_args!(
-                    "data:;base64,{}",
-                    Base64Display::with_config(&self.0, STANDARD)
-                ))
-            } else {
-                serializer.serialize_str("")
-            }
+        serialize_data(&self.0, serializer)
+    }
+}
+
+fn serialize_data<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        if !data.is_empty() {
+            serializer.collect_str(&format_args!(
+                "data:;base64,{}",
+                Base64Display::with_config(data, STANDARD)
+            ))
         } else {
-            serializer.serialize_bytes(&self.0)
+            serializer.serialize_str("")
         }
+    } else {
+        serializer.serialize_bytes(data)
     }
 }
 
@@ -88,6 +95,27 @@ impl<'de> Deserialize<'de> for ImageData {
     }
 }
 
+/// Images are serialized the same way as [`ImageData`]. Deserializing an image
+/// assigns it a new unique ID.
+impl Serialize for Image {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_data(&self.data, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Image {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = ImageData::deserialize(deserializer)?;
+        Ok(Image::new(&data))
+    }
+}
+
 impl PartialEq for Image {
     fn eq(&self, other: &Image) -> bool {
         self.id == other.id || self.data == other.data
@@ -1,7 +1,26 @@
-use super::ImageData;
+use super::{Image, ImageData};
 
 #[test]
 fn serializes_to_json_as_data_url() {
     let json = serde_json::to_string(&ImageData(vec![1, 2, 3].into_boxed_slice())).unwrap();
     assert_eq!(r#""data:;base64,AQID""#, json);
 }
+
+#[test]
+fn images_roundtrip_through_json() {
+    let image = Image::new(&[1, 2, 3]);
+    let json = serde_json::to_string(&image).unwrap();
+    assert_eq!(r#""data:;base64,AQID""#, json);
+    let deserialized: Image = serde_json::from_str(&json).unwrap();
+    assert_eq!(image.data(), deserialized.data());
+    assert_ne!(image.id(), deserialized.id());
+    assert!(image == deserialized);
+}
+
+#[test]
+fn modifying_an_image_changes_its_id() {
+    let mut image = Image::new(&[1, 2, 3]);
+    let id = image.id();
+    image.modify(&[1, 2, 3]);
+    assert_ne!(image.id(), id);
+}
@@ -1,14 +1,17 @@
 use crate::{
     component::{
-        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+        custom_image::ImageScaling,
+        pb_history::HistoryAxis,
+        segment_history::HistoryStyle,
+        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, ScrollMode},
         timer::DeltaGradient,
     },
     hotkey::KeyCode,
     layout::LayoutDirection,
     platform::prelude::*,
-    settings::{Alignment, Color, Font, Gradient, ListGradient},
+    settings::{Alignment, Color, Font, Gradient, Image, ListGradient},
     timing::formatter::{Accuracy, DigitsFormat},
-    TimingMethod,
+    TimeSpan, TimingMethod,
 };
 use core::result::Result as StdResult;
 use serde::{Deserialize, Serialize};
@@ -74,6 +77,21 @@ pub enum Value {
     /// A gradient that may or may not take one of it's colors from the current
     /// delta.
     DeltaGradient(DeltaGradient),
+    /// A value describing how the Segment History Component visualizes the
+    /// segment times.
+    HistoryStyle(HistoryStyle),
+    /// An image.
+    Image(Image),
+    /// A value describing how the Custom Image Component scales its image.
+    ImageScaling(ImageScaling),
+    /// A value describing how the Splits Component scrolls its window of
+    /// segments.
+    ScrollMode(ScrollMode),
+    /// A value describing what the horizontal axis of the PB History Component
+    /// represents.
+    HistoryAxis(HistoryAxis),
+    /// A time span.
+    TimeSpan(TimeSpan),
 }
 
 impl From<bool> for Value {
@@ -202,6 +220,42 @@ impl From<ColumnKind> for Value {
     }
 }
 
+impl From<HistoryStyle> for Value {
+    fn from(x: HistoryStyle) -> Self {
+        Value::HistoryStyle(x)
+    }
+}
+
+impl From<Image> for Value {
+    fn from(x: Image) -> Self {
+        Value::Image(x)
+    }
+}
+
+impl From<ImageScaling> for Value {
+    fn from(x: ImageScaling) -> Self {
+        Value::ImageScaling(x)
+    }
+}
+
+impl From<ScrollMode> for Value {
+    fn from(x: ScrollMode) -> Self {
+        Value::ScrollMode(x)
+    }
+}
+
+impl From<HistoryAxis> for Value {
+    fn from(x: HistoryAxis) -> Self {
+        Value::HistoryAxis(x)
+    }
+}
+
+impl From<TimeSpan> for Value {
+    fn from(x: TimeSpan) -> Self {
+        Value::TimeSpan(x)
+    }
+}
+
 /// The Error type for values that couldn't be converted.
 #[derive(Debug, snafu::Snafu)]
 pub enum Error {
@@ -392,6 +446,57 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    /// Tries to convert the value into a history style.
+    pub fn into_history_style(self) -> Result<HistoryStyle> {
+        match self {
+            Value::HistoryStyle(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an image.
+    pub fn into_image(self) -> Result<Image> {
+        match self {
+            Value::Image(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an image scaling.
+    pub fn into_image_scaling(self) -> Result<ImageScaling> {
+        match self {
+            Value::ImageScaling(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a scroll mode.
+    pub fn into_scroll_mode(self) -> Result<ScrollMode> {
+        match self {
+            Value::ScrollMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a history axis.
+    pub fn into_history_axis(self) -> Result<HistoryAxis> {
+        match self {
+            Value::HistoryAxis(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a time span. Strings are parsed as
+    /// times like `-5` or `1:30.5`, so they are only accepted if they contain
+    /// a valid time.
+    pub fn into_time_span(self) -> Result<TimeSpan> {
+        match self {
+            Value::TimeSpan(v) => Ok(v),
+            Value::String(v) => v.parse().map_err(|_| Error::WrongType),
+            _ => Err(Error::WrongType),
+        }
+    }
 }
 
 impl From<Value> for bool {
@@ -519,3 +624,39 @@ impl From<Value> for ColumnKind {
         value.into_column_kind().unwrap()
     }
 }
+
+impl From<Value> for HistoryStyle {
+    fn from(value: Value) -> Self {
+        value.into_history_style().unwrap()
+    }
+}
+
+impl From<Value> for Image {
+    fn from(value: Value) -> Self {
+        value.into_image().unwrap()
+    }
+}
+
+impl From<Value> for ImageScaling {
+    fn from(value: Value) -> Self {
+        value.into_image_scaling().unwrap()
+    }
+}
+
+impl From<Value> for ScrollMode {
+    fn from(value: Value) -> Self {
+        value.into_scroll_mode().unwrap()
+    }
+}
+
+impl From<Value> for HistoryAxis {
+    fn from(value: Value) -> Self {
+        value.into_history_axis().unwrap()
+    }
+}
+
+impl From<Value> for TimeSpan {
+    fn from(value: Value) -> Self {
+        value.into_time_span().unwrap()
+    }
+}
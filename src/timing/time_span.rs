@@ -163,7 +163,21 @@ impl Neg for TimeSpan {
 }
 
 use core::fmt;
-use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    Serialize, Serializer,
+};
+
+/// Time spans are serialized as their total amount of seconds in a string,
+/// which is a format they can be deserialized from again.
+impl Serialize for TimeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.total_seconds())
+    }
+}
 
 impl<'de> Deserialize<'de> for TimeSpan {
     fn deserialize<D>(deserializer: D) -> Result<TimeSpan, D::Error>
@@ -2,7 +2,7 @@ use crate::{
     platform::{Duration, Instant},
     TimeSpan,
 };
-use core::ops::Sub;
+use core::ops::{Add, Sub};
 
 /// A Time Stamp stores a point in time, that can be used to calculate Time
 /// Spans.
@@ -16,6 +16,14 @@ impl TimeStamp {
     }
 }
 
+impl Add<TimeSpan> for TimeStamp {
+    type Output = TimeStamp;
+
+    fn add(self, rhs: TimeSpan) -> TimeStamp {
+        TimeStamp(self.0 + Duration::from(rhs))
+    }
+}
+
 impl Sub for TimeStamp {
     type Output = TimeSpan;
 
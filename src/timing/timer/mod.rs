@@ -56,6 +56,9 @@ pub struct Timer {
     is_game_time_paused: bool,
     game_time_pause_time: Option<TimeSpan>,
     loading_times: Option<TimeSpan>,
+    // Replaces the current point in time and the current date when the timer
+    // is driven by a virtual clock rather than the system's clock.
+    virtual_now: Option<(TimeStamp, AtomicDateTime)>,
 }
 
 /// A snapshot represents a specific point in time that the timer was observed
@@ -123,6 +126,7 @@ impl Timer {
             is_game_time_paused: false,
             game_time_pause_time: None,
             loading_times: None,
+            virtual_now: None,
         })
     }
 
@@ -191,10 +195,29 @@ impl Timer {
         self.phase
     }
 
+    fn now(&self) -> TimeStamp {
+        self.virtual_now.map_or_else(TimeStamp::now, |(now, _)| now)
+    }
+
+    fn now_date(&self) -> AtomicDateTime {
+        self.virtual_now
+            .map_or_else(AtomicDateTime::now, |(_, date)| date)
+    }
+
+    /// Drives the timer with a virtual clock. All the calculations of the timer
+    /// treat the point in time provided as the current point in time and the
+    /// date provided as the current date, such as the dates the attempt started
+    /// and ended at, until they get replaced again. Providing `None` switches
+    /// back to the system's clock.
+    #[cfg(all(feature = "software-rendering", feature = "std"))]
+    pub(crate) fn set_virtual_now(&mut self, now: Option<(TimeStamp, AtomicDateTime)>) {
+        self.virtual_now = now;
+    }
+
     fn current_time(&self) -> Time {
         let real_time = match self.phase {
             NotRunning => Some(self.run.offset()),
-            Running => Some(TimeStamp::now() - self.adjusted_start_time),
+            Running => Some(self.now() - self.adjusted_start_time),
             Paused => Some(self.time_paused_at),
             Ended => self.run.segments().last().unwrap().split_time().real_time,
         };
@@ -298,8 +321,8 @@ impl Timer {
         if self.phase == NotRunning {
             self.phase = Running;
             self.current_split_index = Some(0);
-            self.attempt_started = Some(AtomicDateTime::now());
-            self.start_time = TimeStamp::now();
+            self.attempt_started = Some(self.now_date());
+            self.start_time = self.now();
             self.start_time_with_offset = self.start_time - self.run.offset();
             self.adjusted_start_time = self.start_time_with_offset;
             self.time_paused_at = self.run.offset();
@@ -334,7 +357,7 @@ impl Timer {
             *self.current_split_index.as_mut().unwrap() += 1;
             if Some(self.run.len()) == self.current_split_index {
                 self.phase = Ended;
-                self.attempt_ended = Some(AtomicDateTime::now());
+                self.attempt_ended = Some(self.now_date());
                 #[cfg(feature = "std")]
                 self.run.try_auto_save();
             }
@@ -411,7 +434,7 @@ impl Timer {
 
     fn reset_state(&mut self, update_times: bool) {
         if self.phase != Ended {
-            self.attempt_ended = Some(AtomicDateTime::now());
+            self.attempt_ended = Some(self.now_date());
         }
         self.resume_game_time();
         self.set_loading_times(TimeSpan::zero());
@@ -454,7 +477,7 @@ impl Timer {
     /// Resumes an attempt that is paused.
     pub fn resume(&mut self) {
         if self.phase == Paused {
-            self.adjusted_start_time = TimeStamp::now() - self.time_paused_at;
+            self.adjusted_start_time = self.now() - self.time_paused_at;
             self.phase = Running;
 
             // FIXME: OnResume
@@ -553,7 +576,7 @@ impl Timer {
     pub fn current_attempt_duration(&self) -> TimeSpan {
         match self.current_phase() {
             NotRunning => TimeSpan::zero(),
-            Paused | Running => TimeStamp::now() - self.start_time,
+            Paused | Running => self.now() - self.start_time,
             Ended => self.attempt_ended.unwrap() - self.attempt_started.unwrap(),
         }
     }
@@ -562,7 +585,7 @@ impl Timer {
     /// for. None is returned if there have not been any pauses.
     pub fn get_pause_time(&self) -> Option<TimeSpan> {
         match self.current_phase() {
-            Paused => Some(TimeStamp::now() - self.start_time_with_offset - self.time_paused_at),
+            Paused => Some(self.now() - self.start_time_with_offset - self.time_paused_at),
             Running | Ended if self.start_time_with_offset != self.adjusted_start_time => {
                 Some(self.adjusted_start_time - self.start_time_with_offset)
             }
@@ -735,6 +758,8 @@ impl Timer {
         for segment in self.run.segments_mut() {
             let split_time = segment.split_time();
             segment.set_personal_best_split_time(split_time);
+            let variables = segment.variables().clone();
+            *segment.personal_best_variables_mut() = variables;
         }
         self.run.clear_run_id();
     }
@@ -113,3 +113,26 @@ fn can_set_variable() {
             .is_permanent,
     );
 }
+
+#[test]
+fn stores_variables_of_the_personal_best() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    timer.start();
+    timer.set_custom_variable("Deaths", "3");
+    timer.split();
+    timer.set_custom_variable("Deaths", "5");
+    timer.split();
+    timer.reset(true);
+
+    let segments = timer.run().segments();
+    assert_eq!(segments[0].variables().get("Deaths"), None);
+    assert_eq!(
+        segments[0].personal_best_variables().get("Deaths").unwrap(),
+        "3"
+    );
+    assert_eq!(
+        segments[1].personal_best_variables().get("Deaths").unwrap(),
+        "5"
+    );
+}
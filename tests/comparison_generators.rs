@@ -0,0 +1,153 @@
+use livesplit_core::{
+    comparison::{
+        best_segments,
+        rolling::{self, RollingComparison, Statistic, Window},
+        GeneratorSettings, LatestRunsAverage, PastAttempt, PercentileSegments, RecentBestSegments,
+    },
+    run::{parser, saver},
+    DateTime, Run, Segment, Time, TimeSpan,
+};
+
+fn save_and_reload(run: &Run) -> Run {
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    parser::livesplit::parse(&buf, None).unwrap()
+}
+
+fn settings(run: &Run) -> Vec<GeneratorSettings> {
+    run.comparison_generators()
+        .iter()
+        .filter_map(|g| g.settings())
+        .collect()
+}
+
+#[test]
+fn default_generators_are_not_saved() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(!buf.contains("ComparisonGenerators"));
+}
+
+#[test]
+fn rolling_comparisons_survive_a_reload() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let generators = run.comparison_generators_mut();
+    generators.push(Box::new(RollingComparison::new(
+        "Last 20 Median",
+        Window::LastAttempts(20),
+        Statistic::Median,
+    )));
+    generators.push(Box::new(
+        RollingComparison::new(
+            "Optimistic",
+            Window::AllAttempts,
+            Statistic::Percentile(0.1),
+        )
+        .with_completed_runs_only(true),
+    ));
+    generators.push(Box::new(RollingComparison::new(
+        "Trimmed < 2022",
+        Window::Since(DateTime::from_unix_timestamp(1_646_397_296).unwrap()),
+        Statistic::TrimmedMean(0.25),
+    )));
+
+    let reloaded = save_and_reload(&run);
+
+    assert_eq!(settings(&reloaded), settings(&run));
+    assert!(reloaded.comparisons().any(|c| c == "Trimmed < 2022"));
+}
+
+#[test]
+fn percentile_comparisons_survive_a_reload() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    run.comparison_generators_mut()
+        .push(Box::new(PercentileSegments::new(0.9)));
+
+    let reloaded = save_and_reload(&run);
+
+    assert_eq!(settings(&reloaded), settings(&run));
+    assert!(reloaded.comparisons().any(|c| c == "Percentile 90"));
+}
+
+#[test]
+fn recent_comparisons_survive_a_reload() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    let generators = run.comparison_generators_mut();
+    generators.push(Box::new(RecentBestSegments::new(20)));
+    generators.push(Box::new(LatestRunsAverage::new(10)));
+
+    let reloaded = save_and_reload(&run);
+
+    assert_eq!(settings(&reloaded), settings(&run));
+}
+
+#[test]
+fn past_attempt_comparisons_survive_a_reload() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    run.comparison_generators_mut()
+        .push(Box::new(PastAttempt::with_name(7, "My <Best> Try")));
+
+    let reloaded = save_and_reload(&run);
+
+    assert_eq!(settings(&reloaded), settings(&run));
+}
+
+#[test]
+fn unnamed_rolling_comparisons_use_the_default_name() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    run.comparison_generators_mut()
+        .push(Box::new(RollingComparison::new(
+            "",
+            Window::AllAttempts,
+            Statistic::Mean,
+        )));
+    run.add_attempt_with_index(Time::new(), 1, None, None, None);
+    run.segments_mut()[0].segment_history_mut().insert(
+        1,
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(5.0))),
+    );
+    run.regenerate_comparisons();
+
+    assert_eq!(
+        run.segment(0).comparison(rolling::NAME).real_time,
+        Some(TimeSpan::from_seconds(5.0)),
+    );
+    assert_eq!(run.segment(0).comparison("").real_time, None);
+
+    assert!(save_and_reload(&run)
+        .comparisons()
+        .any(|c| c == rolling::NAME));
+}
+
+#[test]
+fn clashing_generator_names_fail_to_parse() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    run.comparison_generators_mut()
+        .push(Box::new(RollingComparison::new(
+            best_segments::NAME,
+            Window::AllAttempts,
+            Statistic::Mean,
+        )));
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(matches!(
+        parser::livesplit::parse(&buf, None),
+        Err(parser::livesplit::Error::InvalidComparisonName { .. }),
+    ));
+}
@@ -29,3 +29,25 @@ fn escaping_works_for_auto_splitter_settings() {
     run = parser::livesplit::parse(&buf, None).unwrap();
     assert_eq!(run.auto_splitter_settings(), "<Hi>A &lt; B</Hi>");
 }
+
+#[test]
+fn escaping_works_for_personal_best_variables() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.segment_mut(0)
+        .personal_best_variables_mut()
+        .insert("A < B".into(), "C & D".into());
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(buf.contains("A &lt; B"));
+
+    run = parser::livesplit::parse(&buf, None).unwrap();
+    assert_eq!(
+        run.segment(0)
+            .personal_best_variables()
+            .get("A < B")
+            .unwrap(),
+        "C & D"
+    );
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "software-rendering")]
+
+#[path = "../src/util/tests_helper.rs"]
+mod tests_helper;
+
+use livesplit_core::{
+    layout::Layout,
+    rendering::frame_export::{self, Action, FrameExporter, RecordedAction},
+    AtomicDateTime, Run, Segment, Time, TimeSpan, Timer, TimerPhase, TimingMethod,
+};
+
+fn action(seconds: f64, action: Action) -> RecordedAction {
+    RecordedAction {
+        time: TimeSpan::from_seconds(seconds),
+        action,
+    }
+}
+
+fn run_with_attempt() -> Run {
+    let mut run = tests_helper::create_run(&["A", "B", "C"]);
+    run.set_offset(TimeSpan::from_seconds(-2.0));
+    run.add_attempt_with_index(Time::new(), 1, None, None, None);
+    for (segment, time) in run.segments_mut().iter_mut().zip([Some(3.0), None]) {
+        segment.segment_history_mut().insert(
+            1,
+            Time::new().with_real_time(time.map(TimeSpan::from_seconds)),
+        );
+    }
+    run
+}
+
+#[test]
+fn replays_the_recorded_actions() {
+    let run = tests_helper::create_run(&["A", "B"]);
+    let actions = vec![
+        action(0.0, Action::Start),
+        action(1.5, Action::Split),
+        action(2.0, Action::Pause),
+        action(3.0, Action::Resume),
+        action(4.25, Action::Split),
+    ];
+    let mut exporter =
+        FrameExporter::new(run, Layout::default_layout(), actions, 4, [30, 50]).unwrap();
+
+    assert_eq!(exporter.frame_count(), 18);
+
+    let mut output = Vec::new();
+    exporter.write_raw(&mut output).unwrap();
+    assert_eq!(output.len(), 18 * 30 * 50 * 4);
+
+    let timer = exporter.timer();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    let split_times: Vec<_> = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time().real_time)
+        .collect();
+    assert_eq!(
+        split_times,
+        [
+            Some(TimeSpan::from_seconds(1.5)),
+            Some(TimeSpan::from_seconds(3.25)),
+        ]
+    );
+}
+
+#[test]
+fn is_deterministic() {
+    let render = || {
+        let run = tests_helper::create_run(&["A", "B"]);
+        let actions = vec![action(0.0, Action::Start), action(0.5, Action::Split)];
+        let mut exporter =
+            FrameExporter::new(run, Layout::default_layout(), actions, 30, [100, 100]).unwrap();
+        let mut output = Vec::new();
+        exporter.write_raw(&mut output).unwrap();
+        output
+    };
+
+    assert!(render() == render());
+}
+
+#[test]
+fn reconstructs_an_attempt_from_the_history() {
+    let run = run_with_attempt();
+
+    assert_eq!(
+        frame_export::actions_from_attempt(&run, 1).unwrap(),
+        [
+            action(0.0, Action::Start),
+            action(5.0, Action::Split),
+            action(5.0, Action::SkipSplit),
+        ]
+    );
+    assert!(frame_export::actions_from_attempt(&run, 2).is_none());
+
+    let mut exporter = FrameExporter::from_attempt(run, Layout::new(), 1, 2, [10, 10]).unwrap();
+    while exporter.render_next_frame().is_some() {}
+    let timer = exporter.timer();
+    assert_eq!(timer.current_split_index(), Some(2));
+    assert_eq!(
+        timer.run().segment(0).split_time().real_time,
+        Some(TimeSpan::from_seconds(3.0)),
+    );
+}
+
+#[test]
+fn keeps_the_final_time_of_an_attempt_that_got_reset() {
+    let mut run = run_with_attempt();
+    let started = AtomicDateTime::now();
+    let ended = AtomicDateTime::new(
+        started.time + time::Duration::seconds(8),
+        started.synced_with_atomic_clock,
+    );
+    run.add_attempt_with_index(Time::new(), 2, Some(started), Some(ended), None);
+    run.add_attempt_with_index(
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(10.0))),
+        3,
+        None,
+        None,
+        None,
+    );
+    for index in [2, 3] {
+        run.segments_mut()[0].segment_history_mut().insert(
+            index,
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(3.0))),
+        );
+    }
+
+    // The dates describe the attempt as measured by the clock, while the final
+    // time is measured by the timer, which starts at the offset.
+    for (index, end) in [(2, 8.0), (3, 12.0)] {
+        assert_eq!(
+            frame_export::actions_from_attempt(&run, index).unwrap(),
+            [
+                action(0.0, Action::Start),
+                action(5.0, Action::Split),
+                action(end, Action::Pause),
+            ]
+        );
+    }
+}
+
+#[test]
+fn dates_the_attempt_with_the_virtual_clock() {
+    let run = tests_helper::create_run(&["A"]);
+    let actions = vec![action(1.0, Action::Start), action(3.5, Action::Split)];
+    let mut exporter = FrameExporter::new(run, Layout::new(), actions, 2, [10, 10]).unwrap();
+    while exporter.render_next_frame().is_some() {}
+
+    let run = exporter.timer().clone().into_run(true);
+    let attempt = run.attempt_history().last().unwrap();
+    let epoch = time::OffsetDateTime::UNIX_EPOCH;
+    assert_eq!(
+        attempt.started().map(|d| d.time),
+        Some(epoch + time::Duration::seconds(1)),
+    );
+    assert_eq!(
+        attempt.ended().map(|d| d.time),
+        Some(epoch + time::Duration::milliseconds(3500)),
+    );
+}
@@ -0,0 +1,118 @@
+#[path = "../src/util/tests_helper.rs"]
+mod tests_helper;
+
+use livesplit_core::{
+    component::{blank_space, separator, splits, text},
+    layout::{
+        accessibility::{LiveRegion, Node, Role},
+        Layout, LayoutState,
+    },
+    Run, Segment, TimeSpan, Timer, TimingMethod,
+};
+
+fn timer() -> Timer {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    tests_helper::run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(4.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(12.0));
+    timer
+}
+
+fn roles(node: &Node) -> Vec<Role> {
+    node.children.iter().map(|c| c.role).collect()
+}
+
+#[test]
+fn has_a_node_for_each_component() {
+    let timer = timer();
+    let mut layout = Layout::default_layout();
+    layout.push(separator::Component::new());
+    layout.push(blank_space::Component::new());
+
+    let tree = layout.state(&timer.snapshot()).accessibility_tree();
+    assert_eq!(tree.role, Role::Layout);
+    assert_eq!(
+        roles(&tree),
+        [
+            Role::Heading,
+            Role::Table,
+            Role::Timer,
+            Role::Text,
+            Role::Separator,
+            Role::Presentation,
+        ],
+    );
+
+    let timer = &tree.children[2];
+    assert_eq!(timer.value.as_deref(), Some("12.00"));
+    assert_eq!(timer.description.as_deref(), Some("Behind, losing time"));
+
+    let previous_segment = &tree.children[3];
+    assert_eq!(previous_segment.name, "Live Segment");
+    assert_eq!(previous_segment.value.as_deref(), Some("+3.0"));
+}
+
+#[test]
+fn describes_the_splits_as_rows_read_from_left_to_right() {
+    let timer = timer();
+    let mut component = splits::Component::new();
+    component.settings_mut().show_column_labels = true;
+    let mut layout = Layout::new();
+    layout.push(component);
+
+    let tree = layout.state(&timer.snapshot()).accessibility_tree();
+    let splits = &tree.children[0];
+    assert_eq!(roles(splits), [Role::Row; 3]);
+
+    let names: Vec<_> = splits.children.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["A", "B", "C"]);
+    let current: Vec<_> = splits.children.iter().map(|r| r.is_current).collect();
+    assert_eq!(current, [false, true, false]);
+
+    let first_row = &splits.children[0];
+    let cells: Vec<_> = first_row
+        .children
+        .iter()
+        .map(|c| (c.name.as_str(), c.value.as_deref()))
+        .collect();
+    assert_eq!(cells, [("+/−", Some("−1.0")), ("Time", Some("0:04"))]);
+
+    let delta = &first_row.children[0];
+    assert_eq!(delta.description.as_deref(), Some("Best segment"));
+    assert_eq!(delta.live, LiveRegion::Polite);
+    assert_eq!(first_row.children[1].live, LiveRegion::Off);
+}
+
+#[test]
+fn announces_the_timer_only_once_it_stops_updating() {
+    let mut timer = timer();
+    let mut layout = Layout::default_layout();
+    let mut state = LayoutState::default();
+
+    layout.update_state(&mut state, &timer.snapshot());
+    assert_eq!(state.accessibility_tree().children[2].live, LiveRegion::Off);
+
+    timer.pause();
+    layout.update_state(&mut state, &timer.snapshot());
+    let tree = state.accessibility_tree();
+    assert_eq!(tree.children[2].live, LiveRegion::Polite);
+    assert_eq!(tree.children[2].description.as_deref(), Some("Paused"));
+}
+
+#[test]
+fn describes_text_components() {
+    let mut layout = Layout::new();
+    layout.push(text::Component::with_settings(text::Settings {
+        text: text::Text::Split("Left".into(), "Right".into()),
+        ..Default::default()
+    }));
+    let timer = tests_helper::create_timer(&["A"]);
+
+    let tree = layout.state(&timer.snapshot()).accessibility_tree();
+    let text = &tree.children[0];
+    assert_eq!(text.role, Role::Text);
+    assert_eq!(text.name, "Left");
+    assert_eq!(text.value.as_deref(), Some("Right"));
+    assert_eq!(text.live, LiveRegion::Off);
+}
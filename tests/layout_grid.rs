@@ -0,0 +1,149 @@
+use livesplit_core::{
+    component::{blank_space, separator, timer, title},
+    layout::{GridCell, GridColumn, Layout, LayoutDirection, LayoutGrid, LayoutSettings},
+    settings::Value,
+    LayoutEditor,
+};
+
+fn layout() -> Layout {
+    let mut layout = Layout::new();
+    layout.push(title::Component::new());
+    layout.push(timer::Component::new());
+    layout.push(separator::Component::new());
+    layout.push(blank_space::Component::new());
+    layout
+}
+
+fn cell_sizes(grid: &LayoutGrid) -> Vec<Vec<usize>> {
+    grid.columns
+        .iter()
+        .map(|c| c.cells.iter().map(|c| c.components).collect())
+        .collect()
+}
+
+fn component_names(layout: &Layout) -> Vec<String> {
+    layout
+        .components
+        .iter()
+        .map(|c| c.name().into_owned())
+        .collect()
+}
+
+#[test]
+fn moves_components_into_new_columns_and_rows() {
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+
+    editor.select(1);
+    editor.move_component_into_new_column();
+    let state = editor.state();
+    assert_eq!(cell_sizes(state.grid.as_ref().unwrap()), [vec![3], vec![1]]);
+    assert_eq!(state.selected_component, 3);
+    assert_eq!(state.components[3], "Timer");
+    assert!(!state.buttons.can_move_into_new_column);
+    assert!(!state.buttons.can_move_into_new_row);
+
+    editor.select(0);
+    editor.move_component_into_new_row();
+    let state = editor.state();
+    assert_eq!(
+        cell_sizes(state.grid.as_ref().unwrap()),
+        [vec![2, 1], vec![1]]
+    );
+    assert_eq!(state.selected_component, 2);
+    assert_eq!(state.components[2], "Title");
+
+    let layout = editor.close();
+    assert_eq!(
+        component_names(&layout),
+        ["Separator", "Blank Space", "Title", "Timer"],
+    );
+}
+
+#[test]
+fn removes_the_grid_once_only_a_single_cell_is_left() {
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+
+    editor.select(3);
+    editor.move_component_into_new_column();
+    editor.set_cell_settings_value(0, LayoutDirection::Horizontal.into());
+    assert!(editor.state().cell_settings.is_some());
+
+    editor.move_component_up();
+    let state = editor.state();
+    assert!(state.grid.is_none());
+    assert!(state.cell_settings.is_none());
+    assert_eq!(state.selected_component, 3);
+
+    let layout = editor.close();
+    assert!(layout.general_settings().direction == LayoutDirection::Vertical);
+}
+
+#[test]
+fn moving_components_across_cells_keeps_the_cells_in_sync() {
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+
+    editor.select(2);
+    editor.move_component_into_new_column();
+    editor.select(3);
+    editor.move_component_into_new_row();
+    assert_eq!(
+        cell_sizes(editor.state().grid.as_ref().unwrap()),
+        [vec![3], vec![1]],
+    );
+
+    editor.select(0);
+    editor.move_component(3);
+    assert_eq!(
+        cell_sizes(editor.state().grid.as_ref().unwrap()),
+        [vec![2], vec![2]],
+    );
+
+    editor.duplicate_component();
+    editor.remove_component();
+    editor.select(0);
+    editor.remove_component();
+    let state = editor.state();
+    assert_eq!(cell_sizes(state.grid.as_ref().unwrap()), [vec![1], vec![2]]);
+    assert_eq!(state.components, ["Blank Space", "Separator", "Title"]);
+}
+
+#[test]
+fn sets_the_sizes_of_the_selected_cell() {
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+
+    editor.select(1);
+    editor.move_component_into_new_row();
+    editor.set_cell_settings_value(1, Value::UInt(3));
+    editor.set_cell_settings_value(2, Value::UInt(0));
+
+    let grid = editor.close().grid().cloned().unwrap();
+    assert_eq!(grid.columns[0].size, 3);
+    assert_eq!(grid.columns[0].cells[1].size, 1);
+}
+
+#[test]
+fn fits_the_grid_to_the_components_when_loading_the_settings() {
+    let mut settings = layout().settings();
+    settings.grid = Some(LayoutGrid {
+        columns: vec![
+            GridColumn {
+                size: 2,
+                cells: vec![
+                    GridCell::new(LayoutDirection::Vertical, 3),
+                    GridCell::new(LayoutDirection::Horizontal, 0),
+                ],
+            },
+            GridColumn::new(LayoutDirection::Horizontal, 5),
+        ],
+    });
+
+    let mut json = Vec::new();
+    settings.write_json(&mut json).unwrap();
+    let settings = LayoutSettings::from_json(&*json).unwrap();
+
+    let mut layout = Layout::from_settings(settings);
+    assert_eq!(cell_sizes(layout.grid().unwrap()), [vec![3], vec![1]]);
+
+    layout.push(title::Component::new());
+    assert_eq!(cell_sizes(layout.grid().unwrap()), [vec![3], vec![2]]);
+}
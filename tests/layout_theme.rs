@@ -0,0 +1,171 @@
+use livesplit_core::{
+    component::timer,
+    layout::{Layout, LayoutSettings, Theme, ThemeOverrides},
+    settings::{Color, Font, Gradient, SemanticColor, Value},
+    LayoutEditor,
+};
+
+fn red() -> Color {
+    Color::rgba(1.0, 0.0, 0.0, 1.0)
+}
+
+fn green() -> Color {
+    Color::rgba(0.0, 1.0, 0.0, 1.0)
+}
+
+fn blue() -> Color {
+    Color::rgba(0.0, 0.0, 1.0, 1.0)
+}
+
+fn layout() -> Layout {
+    let mut layout = Layout::new();
+    layout.push(timer::Component::new());
+    layout
+}
+
+#[test]
+fn applying_a_theme_replaces_all_the_settings() {
+    let mut layout = layout();
+    layout.general_settings_mut().best_segment_color = red();
+
+    let mut theme = Theme::from_settings("Dark".into(), &Default::default());
+    theme.best_segment_color = green();
+    theme.text_color = blue();
+    layout.apply_theme(&theme);
+
+    let settings = layout.general_settings();
+    assert_eq!(settings.best_segment_color, green());
+    assert_eq!(settings.text_color, blue());
+    assert_eq!(layout.theme(), Some("Dark"));
+}
+
+#[test]
+fn applying_an_updated_theme_keeps_the_overrides() {
+    let mut layout = layout();
+    let mut theme = Theme::from_settings("Dark".into(), &Default::default());
+    layout.apply_theme(&theme);
+
+    layout.theme_overrides_mut().text_color = Some(red());
+
+    theme.text_color = green();
+    theme.paused_color = blue();
+    layout.apply_theme(&theme);
+
+    let settings = layout.general_settings();
+    assert_eq!(settings.text_color, red());
+    assert_eq!(settings.paused_color, blue());
+
+    layout.detach_theme();
+    assert!(layout.theme().is_none());
+    assert!(layout.theme_overrides().is_empty());
+    assert_eq!(layout.general_settings().paused_color, blue());
+}
+
+#[test]
+fn overrides_are_tracked_even_if_they_match_the_theme() {
+    let mut theme = Theme::from_settings("Dark".into(), &Default::default());
+    theme.text_color = green();
+
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+    editor.apply_theme(&theme);
+    // Text Color
+    editor.set_general_settings_value(15, green().into());
+
+    theme.text_color = blue();
+    editor.apply_theme(&theme);
+
+    let layout = editor.close();
+    assert_eq!(layout.general_settings().text_color, green());
+    assert_eq!(layout.theme_overrides().text_color, Some(green()));
+    assert!(layout.theme_overrides().paused_color.is_none());
+}
+
+#[test]
+fn settings_are_not_overridden_without_a_theme() {
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+    // Text Color
+    editor.set_general_settings_value(15, red().into());
+    // Timer Font
+    editor.set_general_settings_value(1, Value::Font(None));
+
+    let layout = editor.close();
+    assert_eq!(layout.general_settings().text_color, red());
+    assert!(layout.theme_overrides().is_empty());
+}
+
+#[test]
+fn round_trips_overrides_with_a_default_font() {
+    let overrides = ThemeOverrides {
+        timer_font: Some(None),
+        text_font: Some(Some(Font {
+            family: "Arial".into(),
+            ..Default::default()
+        })),
+        separators_color: Some(red()),
+        ..Default::default()
+    };
+
+    let mut layout = layout();
+    *layout.theme_overrides_mut() = overrides.clone();
+
+    let mut json = Vec::new();
+    layout.settings().write_json(&mut json).unwrap();
+    let settings = LayoutSettings::from_json(&*json).unwrap();
+    assert!(settings.theme_overrides == overrides);
+}
+
+#[test]
+fn round_trips_themes_through_json() {
+    let theme = Theme {
+        name: "Dark".into(),
+        personal_best_color: red(),
+        ..Default::default()
+    };
+
+    let mut json = Vec::new();
+    theme.write_json(&mut json).unwrap();
+    let parsed = Theme::from_json(&*json).unwrap();
+    assert!(parsed == theme);
+    assert_eq!(parsed.semantic_color(SemanticColor::PersonalBest), red());
+
+    let partial = Theme::from_json(&br#"{"name":"Partial"}"#[..]).unwrap();
+    assert_eq!(partial.name, "Partial");
+    assert_eq!(partial.text_color, Theme::default().text_color);
+}
+
+#[test]
+fn layouts_keep_referencing_their_theme_when_saved() {
+    let mut layout = layout();
+    let theme = Theme {
+        name: "Dark".into(),
+        ..Default::default()
+    };
+    layout.apply_theme(&theme);
+    layout.theme_overrides_mut().paused_color = Some(red());
+
+    let mut json = Vec::new();
+    layout.settings().write_json(&mut json).unwrap();
+    let layout = Layout::from_settings(LayoutSettings::from_json(&*json).unwrap());
+    assert_eq!(layout.theme(), Some("Dark"));
+    assert_eq!(layout.theme_overrides().paused_color, Some(red()));
+}
+
+#[test]
+fn applies_themes_in_the_layout_editor() {
+    let mut editor = LayoutEditor::new(layout()).unwrap();
+    assert!(editor.state().theme.is_none());
+
+    let theme = Theme {
+        name: "Dark".into(),
+        background: Gradient::Plain(red()),
+        ..Default::default()
+    };
+    editor.apply_theme(&theme);
+    assert_eq!(editor.state().theme.as_deref(), Some("Dark"));
+
+    editor.detach_theme();
+    assert!(editor.state().theme.is_none());
+
+    let layout = editor.close();
+    assert!(layout.general_settings().background == Gradient::Plain(red()));
+}
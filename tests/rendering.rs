@@ -9,8 +9,11 @@ use image::Rgba;
 use img_hash::{HasherConfig, ImageHash};
 use livesplit_core::{
     component::{self, timer},
-    layout::{self, Component, ComponentState, Layout, LayoutDirection, LayoutState},
-    rendering::software::Renderer,
+    layout::{
+        self, Component, ComponentState, GridCell, GridColumn, Layout, LayoutDirection, LayoutGrid,
+        LayoutState,
+    },
+    rendering::{software::Renderer, Hit, HitElement},
     run::parser::{livesplit, llanfair, wsplit},
     Run, Segment, TimeSpan, Timer, TimingMethod,
 };
@@ -41,6 +44,67 @@ fn default() {
     check(&state, "luoAAABANDM=", "default");
 }
 
+#[test]
+fn only_redraws_the_regions_that_changed() {
+    let run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let mut state = layout.state(&timer.snapshot());
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [300, 500]);
+    assert_eq!(renderer.dirty_regions().len(), 1);
+
+    layout.update_state(&mut state, &timer.snapshot());
+    renderer.render(&state, [300, 500]);
+    assert!(renderer.dirty_regions().is_empty());
+
+    timer.set_game_time(TimeSpan::from_seconds(12.5));
+    layout.update_state(&mut state, &timer.snapshot());
+    renderer.render(&state, [300, 500]);
+
+    let dirty_area: u32 = renderer
+        .dirty_regions()
+        .iter()
+        .map(|region| region.width * region.height)
+        .sum();
+    assert!(dirty_area > 0);
+    assert!(dirty_area < 300 * 500 / 2);
+    // Only the timer changed, which doesn't span the whole width of the layout.
+    assert!(renderer
+        .dirty_regions()
+        .iter()
+        .all(|region| region.width < 300));
+
+    let mut fresh_renderer = Renderer::new();
+    fresh_renderer.render(&state, [300, 500]);
+    assert!(renderer.image_data() == fresh_renderer.image_data());
+}
+
+#[test]
+fn animates_gold_splits_unless_animations_are_disabled() {
+    for animations in [true, false] {
+        let mut timer = tests_helper::create_timer(&["A", "B", "C", "D"]);
+        tests_helper::run_with_splits(&mut timer, &[5.0, 10.0, 15.0, 20.0]);
+        let mut layout = Layout::default_layout();
+        layout.general_settings_mut().animations = animations;
+
+        tests_helper::start_run(&mut timer);
+        let mut state = layout.state(&timer.snapshot());
+        let mut renderer = Renderer::new();
+        renderer.render(&state, [300, 500]);
+        assert!(!renderer.needs_next_frame());
+
+        tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(4.0)]);
+        layout.update_state(&mut state, &timer.snapshot());
+        renderer.render(&state, [300, 500]);
+        assert_eq!(renderer.needs_next_frame(), animations);
+    }
+}
+
 #[test]
 fn actual_split_file() {
     let run = lss(run_files::LIVESPLIT_1_0);
@@ -111,6 +175,183 @@ fn all_components() {
     check_dims(&state, [150, 800], "SXfHSWVpRlc=", "all_components_thin");
 }
 
+#[test]
+fn progress_bar() {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    let mut timer = Timer::new(run).unwrap();
+    tests_helper::run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let mut layout = Layout::default_layout();
+    layout.push(component::ProgressBar::new());
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(8.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(16.0));
+
+    let state = layout.state(&timer.snapshot());
+
+    check(&state, "luLAACBANrE=", "progress_bar");
+}
+
+#[test]
+fn segment_history() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    for splits in [
+        [10.0, 20.0, 30.0],
+        [14.0, 27.0, 40.0],
+        [12.0, 21.0, 33.0],
+        [11.0, 24.0, 35.0],
+        [13.0, 22.0, 31.0],
+    ] {
+        tests_helper::run_with_splits(&mut timer, &splits);
+    }
+
+    let mut layout = Layout::new();
+    layout.push(component::SegmentHistory::new());
+    let mut sparkline = component::SegmentHistory::new();
+    sparkline.settings_mut().style = component::segment_history::HistoryStyle::Sparkline;
+    layout.push(sparkline);
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(22.5));
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(&state, [300, 200], "mpqSkqI11Iw=", "segment_history");
+}
+
+#[test]
+fn custom_image() {
+    let run = lss(run_files::CELESTE);
+    let icon = run.segment(0).icon().clone();
+    let segment_icon = run.segment(1).icon().clone();
+    let segment_name = run.segment(1).name().to_owned();
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut layout = Layout::new();
+    for scaling in [
+        component::custom_image::ImageScaling::Fit,
+        component::custom_image::ImageScaling::Stretch,
+    ] {
+        layout.push(component::CustomImage::with_settings(
+            component::custom_image::Settings {
+                image: icon.clone(),
+                scaling,
+                height: 60,
+                segment_images: vec![component::custom_image::SegmentImage {
+                    segment_name: segment_name.clone(),
+                    image: segment_icon.clone(),
+                }],
+                ..Default::default()
+            },
+        ));
+    }
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300, 120],
+        "TExMyB8zNw4=",
+        "custom_image",
+    );
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0)]);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300, 120],
+        "DE1N8CsrD8g=",
+        "custom_image_segment",
+    );
+}
+
+#[test]
+fn counters() {
+    let run = lss(run_files::CELESTE);
+    let icon = run.segment(0).icon().clone();
+    let mut timer = Timer::new(run).unwrap();
+
+    timer.start();
+    timer.set_custom_variable("Deaths", "12");
+    timer.set_custom_variable("Strawberries", "3");
+    timer.split();
+    timer.set_custom_variable("Deaths", "30");
+    timer.set_custom_variable("Strawberries", "7");
+    timer.split();
+    timer.reset_and_set_attempt_as_pb();
+
+    let counter = |variable_name: &str, higher_is_better| component::counters::Counter {
+        variable_name: variable_name.into(),
+        higher_is_better,
+        ..Default::default()
+    };
+
+    let mut layout = Layout::new();
+    layout.push(component::Counters::with_settings(
+        component::counters::Settings {
+            counters: vec![
+                component::counters::Counter {
+                    icon,
+                    ..counter("Deaths", false)
+                },
+                counter("Strawberries", true),
+                counter("Route", false),
+            ],
+            ..Default::default()
+        },
+    ));
+
+    tests_helper::start_run(&mut timer);
+    timer.split();
+    timer.set_custom_variable("Deaths", "15");
+    timer.set_custom_variable("Strawberries", "5");
+    timer.set_custom_variable("Route", "Any%");
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300, 60],
+        "YJCRYAokJAc=",
+        "counters",
+    );
+}
+
+#[test]
+fn timeline() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C", "D"]);
+    tests_helper::run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let mut layout = Layout::new();
+    layout.push(component::Timeline::new());
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(8.0), Some(21.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(25.0));
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(&state, [300, 40], "VFRUc1xcXFw=", "timeline");
+}
+
+#[test]
+fn pb_history() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    tests_helper::run_with_splits(&mut timer, &[10.0, 25.0, 40.0]);
+    tests_helper::run_with_splits(&mut timer, &[8.0, 24.0, 42.0]);
+    tests_helper::run_with_splits(&mut timer, &[9.0, 20.0, 36.0]);
+    tests_helper::run_with_splits(&mut timer, &[10.0, 22.0, 38.0]);
+    tests_helper::run_with_splits(&mut timer, &[9.0, 20.0, 33.0]);
+
+    let mut layout = Layout::new();
+    layout.push(component::PbHistory::new());
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(&state, [300, 80], "ISsmGIpamIA=", "pb_history");
+}
+
 #[test]
 fn score_split() {
     let run = lss(run_files::LIVESPLIT_1_0);
@@ -243,6 +484,122 @@ fn horizontal() {
     );
 }
 
+#[test]
+fn grid() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    layout.push(component::separator::Component::new());
+    layout.push(component::graph::Component::new());
+    layout.push(component::sum_of_best::Component::new());
+    layout.push(component::pb_chance::Component::new());
+    layout.set_grid(Some(LayoutGrid {
+        columns: vec![
+            GridColumn::new(LayoutDirection::Vertical, 2),
+            GridColumn {
+                size: 2,
+                cells: vec![
+                    GridCell {
+                        size: 3,
+                        ..GridCell::new(LayoutDirection::Vertical, 4)
+                    },
+                    GridCell::new(LayoutDirection::Horizontal, 2),
+                ],
+            },
+        ],
+    }));
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(10.0), None, Some(20.0), Some(55.0)],
+    );
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [900, 500],
+        "MLJADtwcTtg=",
+        "grid",
+    );
+}
+
+#[test]
+fn hit_tests_components_and_split_rows() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), None]);
+
+    let state = layout.state(&timer.snapshot());
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [300, 500]);
+
+    assert_eq!(
+        renderer.hit_test(150.0, 5.0),
+        Some(Hit {
+            component_index: 0,
+            element: HitElement::Component,
+        }),
+    );
+    assert_eq!(renderer.hit_test(150.0, 495.0).unwrap().component_index, 3);
+    assert_eq!(renderer.hit_test(-1.0, 5.0), None);
+    assert_eq!(renderer.hit_test(150.0, 501.0), None);
+
+    let splits = match &state.components[1] {
+        ComponentState::Splits(splits) => splits,
+        _ => unreachable!("The second component is the splits component"),
+    };
+    let mut hit_rows = Vec::new();
+    for y in 0..500 {
+        if let Some(Hit {
+            component_index: 1,
+            element:
+                HitElement::Split {
+                    row_index,
+                    segment_index,
+                },
+        }) = renderer.hit_test(150.0, y as f32)
+        {
+            assert_eq!(splits.splits[row_index].index, segment_index);
+            if hit_rows.last() != Some(&row_index) {
+                hit_rows.push(row_index);
+            }
+        }
+    }
+    assert_eq!(hit_rows, (0..splits.splits.len()).collect::<Vec<_>>());
+}
+
+#[test]
+fn hit_tests_components_in_grid_cells() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    layout.set_grid(Some(LayoutGrid {
+        columns: vec![
+            GridColumn::new(LayoutDirection::Vertical, 1),
+            GridColumn::new(LayoutDirection::Horizontal, 3),
+        ],
+    }));
+
+    tests_helper::start_run(&mut timer);
+
+    let state = layout.state(&timer.snapshot());
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [600, 100]);
+
+    assert_eq!(renderer.hit_test(100.0, 50.0).unwrap().component_index, 0);
+    match renderer.hit_test(301.0, 50.0) {
+        Some(Hit {
+            component_index: 1,
+            element: HitElement::Split { row_index: 0, .. },
+        }) => {}
+        hit => panic!("Expected the first split row, got {hit:?}"),
+    }
+    assert_eq!(renderer.hit_test(599.0, 50.0).unwrap().component_index, 3);
+}
+
 fn get_comparison_tolerance() -> u32 {
     // Without MMX the floating point calculations don't follow IEEE 754, so the tests require a
     // tolerance that is greater than 0.
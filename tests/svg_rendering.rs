@@ -0,0 +1,580 @@
+#![cfg(feature = "svg-rendering")]
+
+mod layout_files;
+mod run_files;
+#[path = "../src/util/tests_helper.rs"]
+mod tests_helper;
+
+use livesplit_core::{
+    component::{self, timer},
+    layout::{
+        self, Component, ComponentState, GridCell, GridColumn, Layout, LayoutDirection, LayoutGrid,
+        LayoutState,
+    },
+    rendering::svg::Renderer,
+    run::parser::{livesplit, llanfair, wsplit},
+    Run, Segment, TimeSpan, Timer, TimingMethod,
+};
+use std::fs;
+
+fn lss(data: &str) -> Run {
+    livesplit::parse(data, None).unwrap()
+}
+
+fn lsl(data: &str) -> Layout {
+    layout::parser::parse(data).unwrap()
+}
+
+fn timer() -> Timer {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    let mut timer = Timer::new(run).unwrap();
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+    timer
+}
+
+#[test]
+fn renders_a_standalone_document() {
+    let timer = timer();
+    let mut layout = Layout::default_layout();
+    let state = layout.state(&timer.snapshot());
+
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [300.0, 500.0]);
+    let document = renderer.document();
+
+    assert!(document.starts_with(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="300" height="500" viewBox="0 0 300 500">"#
+    ));
+    assert!(document.ends_with("</svg>\n"));
+    // The default layout has a gradient as its title's background.
+    assert!(document.contains("<linearGradient"));
+    // The texts are turned into paths.
+    assert!(document.matches("<g fill=").count() > 10);
+}
+
+#[test]
+fn is_deterministic() {
+    let timer = timer();
+    let mut layout = Layout::default_layout();
+    let state = layout.state(&timer.snapshot());
+
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [300.0, 500.0]);
+    let first = renderer.document().to_owned();
+    renderer.render(&state, [300.0, 500.0]);
+
+    assert_eq!(first, renderer.document());
+    assert_eq!(first, {
+        let mut renderer = Renderer::new();
+        renderer.render(&state, [300.0, 500.0]);
+        renderer.into_document()
+    });
+}
+
+#[test]
+fn specifies_gradients_in_the_coordinate_space_of_the_paths() {
+    let timer = timer();
+    let mut layout = Layout::default_layout();
+    let state = layout.state(&timer.snapshot());
+
+    let mut renderer = Renderer::new();
+    renderer.render(&state, [300.0, 500.0]);
+
+    // The backgrounds of the components are unit squares that get transformed
+    // into place, so their gradients need to span the unit square too.
+    assert!(renderer
+        .document()
+        .contains(r#"gradientUnits="userSpaceOnUse" x1="0" y1="0" x2="0" y2="1""#));
+}
+
+#[test]
+fn default() {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    run.set_attempt_count(1337);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let state = layout.state(&timer.snapshot());
+
+    check(&state, "36510ebb5efd6562", "default");
+}
+
+#[test]
+fn actual_split_file() {
+    let run = lss(run_files::LIVESPLIT_1_0);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    check(
+        &layout.state(&timer.snapshot()),
+        "7ded31504084c68e",
+        "actual_split_file",
+    );
+}
+
+#[test]
+fn wsplit() {
+    let run = wsplit::parse(run_files::WSPLIT, false).unwrap();
+    let timer = Timer::new(run).unwrap();
+    let mut layout = lsl(layout_files::WSPLIT);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [250.0, 300.0],
+        "71aca31e9846ec4a",
+        "wsplit",
+    );
+}
+
+#[test]
+fn timer_delta_background() {
+    let run = lss(run_files::LIVESPLIT_1_0);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = lsl(layout_files::WITH_TIMER_DELTA_BACKGROUND);
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [250.0, 300.0],
+        "57e549e54e315a14",
+        "timer_delta_background_ahead",
+    );
+
+    timer.reset(true);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [250.0, 300.0],
+        "23ed8b6eb4f422ed",
+        "timer_delta_background_stopped",
+    );
+}
+
+#[test]
+fn all_components() {
+    let mut layout = lsl(layout_files::ALL);
+    let run = lss(run_files::LIVESPLIT_1_6_GAMETIME);
+    let mut timer = Timer::new(run).unwrap();
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(10.0), None, Some(20.0), Some(55.0)],
+    );
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(&state, [300.0, 800.0], "f678fba8d3f3943e", "all_components");
+
+    check_dims(
+        &state,
+        [150.0, 800.0],
+        "e99809ff041aba16",
+        "all_components_thin",
+    );
+}
+
+#[test]
+fn progress_bar() {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    let mut timer = Timer::new(run).unwrap();
+    tests_helper::run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let mut layout = Layout::default_layout();
+    layout.push(component::ProgressBar::new());
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(8.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(16.0));
+
+    let state = layout.state(&timer.snapshot());
+
+    check(&state, "c50e028a9cc798e2", "progress_bar");
+}
+
+#[test]
+fn segment_history() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    for splits in [
+        [10.0, 20.0, 30.0],
+        [14.0, 27.0, 40.0],
+        [12.0, 21.0, 33.0],
+        [11.0, 24.0, 35.0],
+        [13.0, 22.0, 31.0],
+    ] {
+        tests_helper::run_with_splits(&mut timer, &splits);
+    }
+
+    let mut layout = Layout::new();
+    layout.push(component::SegmentHistory::new());
+    let mut sparkline = component::SegmentHistory::new();
+    sparkline.settings_mut().style = component::segment_history::HistoryStyle::Sparkline;
+    layout.push(sparkline);
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(22.5));
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(
+        &state,
+        [300.0, 200.0],
+        "9cda73c7b7dcd74a",
+        "segment_history",
+    );
+}
+
+#[test]
+fn custom_image() {
+    let run = lss(run_files::CELESTE);
+    let icon = run.segment(0).icon().clone();
+    let segment_icon = run.segment(1).icon().clone();
+    let segment_name = run.segment(1).name().to_owned();
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut layout = Layout::new();
+    for scaling in [
+        component::custom_image::ImageScaling::Fit,
+        component::custom_image::ImageScaling::Stretch,
+    ] {
+        layout.push(component::CustomImage::with_settings(
+            component::custom_image::Settings {
+                image: icon.clone(),
+                scaling,
+                height: 60,
+                segment_images: vec![component::custom_image::SegmentImage {
+                    segment_name: segment_name.clone(),
+                    image: segment_icon.clone(),
+                }],
+                ..Default::default()
+            },
+        ));
+    }
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300.0, 120.0],
+        "97d0a29943725499",
+        "custom_image",
+    );
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0)]);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300.0, 120.0],
+        "cf17f9a95a6cb4bc",
+        "custom_image_segment",
+    );
+}
+
+#[test]
+fn counters() {
+    let run = lss(run_files::CELESTE);
+    let icon = run.segment(0).icon().clone();
+    let mut timer = Timer::new(run).unwrap();
+
+    timer.start();
+    timer.set_custom_variable("Deaths", "12");
+    timer.set_custom_variable("Strawberries", "3");
+    timer.split();
+    timer.set_custom_variable("Deaths", "30");
+    timer.set_custom_variable("Strawberries", "7");
+    timer.split();
+    timer.reset_and_set_attempt_as_pb();
+
+    let counter = |variable_name: &str, higher_is_better| component::counters::Counter {
+        variable_name: variable_name.into(),
+        higher_is_better,
+        ..Default::default()
+    };
+
+    let mut layout = Layout::new();
+    layout.push(component::Counters::with_settings(
+        component::counters::Settings {
+            counters: vec![
+                component::counters::Counter {
+                    icon,
+                    ..counter("Deaths", false)
+                },
+                counter("Strawberries", true),
+                counter("Route", false),
+            ],
+            ..Default::default()
+        },
+    ));
+
+    tests_helper::start_run(&mut timer);
+    timer.split();
+    timer.set_custom_variable("Deaths", "15");
+    timer.set_custom_variable("Strawberries", "5");
+    timer.set_custom_variable("Route", "Any%");
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300.0, 60.0],
+        "02c764be4b4e427e",
+        "counters",
+    );
+}
+
+#[test]
+fn timeline() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C", "D"]);
+    tests_helper::run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let mut layout = Layout::new();
+    layout.push(component::Timeline::new());
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(8.0), Some(21.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(25.0));
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(&state, [300.0, 40.0], "5c27641bad367a00", "timeline");
+}
+
+#[test]
+fn pb_history() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    tests_helper::run_with_splits(&mut timer, &[10.0, 25.0, 40.0]);
+    tests_helper::run_with_splits(&mut timer, &[8.0, 24.0, 42.0]);
+    tests_helper::run_with_splits(&mut timer, &[9.0, 20.0, 36.0]);
+    tests_helper::run_with_splits(&mut timer, &[10.0, 22.0, 38.0]);
+    tests_helper::run_with_splits(&mut timer, &[9.0, 20.0, 33.0]);
+
+    let mut layout = Layout::new();
+    layout.push(component::PbHistory::new());
+
+    let state = layout.state(&timer.snapshot());
+
+    check_dims(&state, [300.0, 80.0], "c34ab497020b8076", "pb_history");
+}
+
+#[test]
+fn score_split() {
+    let run = lss(run_files::LIVESPLIT_1_0);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    let mut state = layout.state(&timer.snapshot());
+    let prev_seg = state.components.pop().unwrap();
+    state.components.pop();
+    let mut timer_state =
+        timer::Component::new().state(&timer.snapshot(), layout.general_settings());
+    timer_state.time = "50346".into();
+    timer_state.fraction = "PTS".into();
+    state.components.push(ComponentState::Timer(timer_state));
+    state.components.push(prev_seg);
+
+    check_dims(&state, [300.0, 400.0], "58393557dc302c2a", "score_split");
+}
+
+#[test]
+fn dark_layout() {
+    let run = llanfair::parse(run_files::LLANFAIR).unwrap();
+    let timer = Timer::new(run).unwrap();
+    let mut layout = lsl(layout_files::DARK);
+
+    check(
+        &layout.state(&timer.snapshot()),
+        "ed4f409654aafb37",
+        "dark_layout",
+    );
+}
+
+#[test]
+fn subsplits_layout() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = lsl(layout_files::SUBSPLITS);
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(10.0), None, Some(20.0), Some(55.0)],
+    );
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300.0, 800.0],
+        "8748729da3c6b110",
+        "subsplits_layout",
+    );
+}
+
+#[test]
+fn display_two_rows() {
+    let timer = tests_helper::create_timer(&["A"]);
+    let mut layout = Layout::new();
+    let mut component = component::text::Component::new();
+    let settings = component.settings_mut();
+    settings.display_two_rows = true;
+    settings.text =
+        component::text::Text::Split(String::from("World Record"), String::from("Some Guy"));
+    layout.push(component);
+
+    let mut component = component::delta::Component::new();
+    component.settings_mut().display_two_rows = true;
+    layout.push(component);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [200.0, 100.0],
+        "1ed967cacd83e5ca",
+        "display_two_rows",
+    );
+}
+
+#[test]
+fn single_line_title() {
+    let mut run = tests_helper::create_run(&["A"]);
+    run.set_game_name("Some Game");
+    run.set_category_name("Some Category");
+    run.set_attempt_count(1337);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::new();
+    let mut component = component::title::Component::new();
+    let settings = component.settings_mut();
+    settings.display_as_single_line = true;
+    settings.show_attempt_count = true;
+    settings.show_finished_runs_count = true;
+    layout.push(component);
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [300.0, 60.0],
+        "2ae92ca838d57b00",
+        "single_line_title",
+    );
+}
+
+#[test]
+fn horizontal() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+    match &mut layout.components[1] {
+        Component::Splits(splits) => splits.settings_mut().visual_split_count = 4,
+        _ => unreachable!("We wanted to configure the splits"),
+    }
+    layout.push(component::separator::Component::new());
+    layout.push(component::graph::Component::new());
+    layout.push(component::separator::Component::new());
+    layout.push(Box::new(
+        component::detailed_timer::Component::with_settings(component::detailed_timer::Settings {
+            display_icon: true,
+            ..Default::default()
+        }),
+    ));
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(10.0), None, Some(20.0), Some(55.0)],
+    );
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [1500.0, 40.0],
+        "c8da1cea0fc2b02e",
+        "horizontal",
+    );
+}
+
+#[test]
+fn grid() {
+    let run = lss(run_files::CELESTE);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    layout.push(component::separator::Component::new());
+    layout.push(component::graph::Component::new());
+    layout.push(component::sum_of_best::Component::new());
+    layout.push(component::pb_chance::Component::new());
+    layout.set_grid(Some(LayoutGrid {
+        columns: vec![
+            GridColumn::new(LayoutDirection::Vertical, 2),
+            GridColumn {
+                size: 2,
+                cells: vec![
+                    GridCell {
+                        size: 3,
+                        ..GridCell::new(LayoutDirection::Vertical, 4)
+                    },
+                    GridCell::new(LayoutDirection::Horizontal, 2),
+                ],
+            },
+        ],
+    }));
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(10.0), None, Some(20.0), Some(55.0)],
+    );
+
+    check_dims(
+        &layout.state(&timer.snapshot()),
+        [900.0, 500.0],
+        "cd167879016b6fb0",
+        "grid",
+    );
+}
+
+/// Hashes the document with the 64-bit FNV-1a hash function, which, unlike the
+/// hashers of the standard library, is guaranteed to be stable.
+fn hash_document(document: &str) -> String {
+    let hash = document
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[track_caller]
+fn check(state: &LayoutState, expected_hash: &str, name: &str) {
+    check_dims(state, [300.0, 500.0], expected_hash, name);
+}
+
+#[track_caller]
+fn check_dims(state: &LayoutState, dims: [f32; 2], expected_hash: &str, name: &str) {
+    let mut renderer = Renderer::new();
+    renderer.render(state, dims);
+    let document = renderer.into_document();
+    let calculated_hash = hash_document(&document);
+
+    fs::create_dir_all("target/renders/svg").ok();
+
+    let path = format!("target/renders/svg/{name}_{calculated_hash}.svg");
+    fs::write(&path, &document).ok();
+
+    if calculated_hash != expected_hash {
+        let expected_path = format!("target/renders/svg/{name}_{expected_hash}.svg");
+        let expected_path = if fs::metadata(&expected_path).is_ok() {
+            expected_path
+        } else {
+            String::from("Not found")
+        };
+
+        panic!(
+            "Render mismatch for {name}
+expected: {expected_hash} {expected_path}
+actual: {calculated_hash} {path}",
+        );
+    }
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "path-based-text-engine")]
+
+use livesplit_core::rendering::{
+    path_based_text_engine::{Font, Label, TextEngine},
+    FontKind, Label as _, PathBuilder,
+};
+use std::rc::Rc;
+
+/// Counts the segments of the paths, which is enough to tell glyphs apart.
+struct SegmentCounter(usize);
+
+impl PathBuilder for SegmentCounter {
+    type Path = Rc<usize>;
+
+    fn move_to(&mut self, _: f32, _: f32) {
+        self.0 += 1;
+    }
+
+    fn line_to(&mut self, _: f32, _: f32) {
+        self.0 += 1;
+    }
+
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {
+        self.0 += 1;
+    }
+
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+        self.0 += 1;
+    }
+
+    fn close(&mut self) {
+        self.0 += 1;
+    }
+
+    fn finish(self) -> Self::Path {
+        Rc::new(self.0)
+    }
+}
+
+fn label(engine: &mut TextEngine, font: &mut Font<Rc<usize>>, text: &str) -> Label<Rc<usize>> {
+    engine.create_label(|| SegmentCounter(0), text, font, None)
+}
+
+fn segments(label: &Label<Rc<usize>>) -> Vec<usize> {
+    label
+        .read()
+        .unwrap()
+        .glyphs()
+        .iter()
+        .map(|g| *g.path)
+        .collect()
+}
+
+#[test]
+fn falls_back_to_the_text_font_for_characters_missing_in_the_timer_font() {
+    let mut engine = TextEngine::new();
+    let mut timer_font = engine.create_font(None, FontKind::Timer);
+    let mut times_font = engine.create_font(None, FontKind::Times);
+
+    let timer_label = label(&mut engine, &mut timer_font, "→");
+    let times_label = label(&mut engine, &mut times_font, "→");
+
+    assert_eq!(segments(&timer_label), segments(&times_label));
+    assert!(segments(&timer_label)[0] > 0);
+    assert!((timer_label.width(1.0) - times_label.width(1.0)).abs() < 1e-4);
+}
+
+#[test]
+fn keeps_the_timer_font_for_the_characters_it_supports() {
+    let mut engine = TextEngine::new();
+    let mut timer_font = engine.create_font(None, FontKind::Timer);
+    let mut times_font = engine.create_font(None, FontKind::Times);
+
+    let timer_label = label(&mut engine, &mut timer_font, "1→2");
+    let times_label = label(&mut engine, &mut times_font, "1→2");
+
+    let (timer_segments, times_segments) = (segments(&timer_label), segments(&times_label));
+    assert_ne!(timer_segments[0], times_segments[0]);
+    assert_eq!(timer_segments[1], times_segments[1]);
+    assert_ne!(timer_segments[2], times_segments[2]);
+}
+
+#[test]
+fn lays_out_right_to_left_text_in_visual_order() {
+    let mut engine = TextEngine::new();
+    let mut font = engine.create_font(None, FontKind::Text);
+
+    let digits = segments(&label(&mut engine, &mut font, "1234"));
+    let mixed = segments(&label(&mut engine, &mut font, "12 א 34"));
+
+    // The Hebrew letter makes it a right-to-left paragraph, so the numbers
+    // swap places, while staying left-to-right themselves.
+    assert_eq!(mixed.len(), 7);
+    assert_eq!(mixed[..2], digits[2..]);
+    assert_eq!(mixed[5..], digits[..2]);
+}
+
+#[test]
+fn cuts_off_right_to_left_text_at_its_logical_end() {
+    let mut engine = TextEngine::new();
+    let mut font = engine.create_font(None, FontKind::Text);
+
+    let ellipsis = segments(&label(&mut engine, &mut font, "…"));
+    let hebrew = segments(&label(&mut engine, &mut font, "א"));
+    let digits = segments(&label(&mut engine, &mut font, "123456789"));
+    let max_width = label(&mut engine, &mut font, "א 1234").width(1.0);
+
+    let label = engine.create_label(
+        || SegmentCounter(0),
+        "א 123456789",
+        &mut font,
+        Some(max_width),
+    );
+    let shortened = segments(&label);
+
+    assert!(label.width(1.0) <= max_width);
+    // The ellipsis ends up on the left, as that is where the right-to-left
+    // text ends, while the Hebrew letter at its start is kept on the right.
+    let (first, rest) = shortened.split_first().unwrap();
+    let (last, rest) = rest.split_last().unwrap();
+    let kept_digits = &rest[..rest.len() - 1];
+    assert_eq!(*first, ellipsis[0]);
+    assert_eq!(*last, hebrew[0]);
+    assert!(!kept_digits.is_empty());
+    assert_eq!(kept_digits, &digits[..kept_digits.len()]);
+}
+
+#[test]
+fn cuts_off_right_to_left_text_between_grapheme_clusters() {
+    let mut engine = TextEngine::new();
+    let mut font = engine.create_font(None, FontKind::Text);
+
+    // A flag consists of two regional indicators, which the font renders as
+    // separate glyphs. Cutting off the text between the regional indicators of
+    // the second flag would fit exactly, but would separate them.
+    let max_width = label(&mut engine, &mut font, "א 🇩🇪🇩…").width(1.0);
+    let expected = segments(&label(&mut engine, &mut font, "א 🇩🇪…"));
+
+    let label = engine.create_label(|| SegmentCounter(0), "א 🇩🇪🇩🇪🇩🇪", &mut font, Some(max_width));
+
+    assert!(label.width(1.0) <= max_width);
+    assert_eq!(segments(&label), expected);
+}